@@ -1,72 +1,368 @@
-#![feature(array_chunks)]
-
 use ark_experimentation::arithmetic::double_precision;
+use ark_experimentation::arithmetic::half_precision;
 use ark_experimentation::arithmetic::single_precision;
+use ark_experimentation::baby_bear;
+use ark_experimentation::bench_support::bench_acc_scaled_fused;
+use ark_experimentation::bench_support::bench_conversion;
+use ark_experimentation::bench_support::bench_horner;
+use ark_experimentation::bench_support::bench_le_bits;
+use ark_experimentation::bench_support::bench_operand_distributions;
+use ark_experimentation::bench_support::Registry;
+use ark_experimentation::bigint;
+use ark_experimentation::dual_modulus::DualFp;
+use ark_experimentation::extension_slice;
+use ark_experimentation::f65537;
+use ark_experimentation::fma;
 use ark_experimentation::fp20;
+use ark_experimentation::fp_f32::FpF32;
+use ark_experimentation::fp_f64::FpF64;
+use ark_experimentation::m31;
+use ark_experimentation::slice;
 use criterion::black_box;
 use criterion::criterion_group;
 use criterion::criterion_main;
 use criterion::Criterion;
 use p3_mersenne_31::Mersenne31;
-use rand::distributions::Standard;
-use rand::prelude::Distribution;
 use rand::rngs::StdRng;
 use rand::Rng;
 use rand::SeedableRng;
-use std::ops::Add;
-use std::ops::Mul;
 
-fn bench_multiplication<T: Mul<Output = T> + Copy>(c: &mut Criterion, id: &str)
-where
-    Standard: Distribution<T>,
-{
+/// Bench id of a `dyn_field` field, keeping the names the suite used before
+/// the fields were registered from there so saved baselines still apply
+fn bench_id(field: &'static str) -> &'static str {
+    match field {
+        "fp20-u32" => "u32_pseudo_mersene_21",
+        "fp20-f32" => "f32_pseudo_mersene_21",
+        "fp20-f64" => "f64_pseudo_mersene_21",
+        "fermat65537-f32" => "f32_fermat_65537",
+        "fp251-f16" => "f16_fp_251",
+        "fp1048573-f32" => "f32_fp_1048573",
+        "m31-f64" => "f64_mersenne_31",
+        "baby-bear-f64" => "f64_baby_bear",
+        "goldilocks-f64x2" => "f64x2_goldilocks",
+        "numeric-u32-f16" => "f16_numeric_4294967291",
+        "numeric-f32x2" => "f32x2_numeric_4294967291",
+        "numeric-f64" => "f64_numeric_4294967291",
+        _ => panic!("no bench id for field `{field}`"),
+    }
+}
+
+/// Every field of `dyn_field::REGISTRY` under its [`bench_id`], then the
+/// simulated integers, native baselines and fields the tools don't select
+fn registry() -> Registry {
+    let mut registry = Registry::default();
+    registry
+        .register_fields(bench_id)
+        .register_reducers::<fp20::single_precision::Fp>("f32_pseudo_mersene_21")
+        .register_reducers::<fp20::double_precision::Fp>("f64_pseudo_mersene_21")
+        .register_reducers::<f65537::Fp>("f32_fermat_65537")
+        .register_reducers::<FpF32<1048573>>("f32_fp_1048573")
+        .register_reducers::<m31::Fp>("f64_mersenne_31")
+        .register_reducers::<baby_bear::Fp>("f64_baby_bear")
+        .register::<u32>("native_u32")
+        .register::<Mersenne31>("plonky3_mersenne_31")
+        .register::<half_precision::U8>("f16_sim_u8")
+        .register::<half_precision::U16>("f16_sim_u16")
+        .register::<half_precision::U24>("f16_sim_u24")
+        .register::<half_precision::U32>("f16_sim_u32")
+        .register::<single_precision::U16>("f32_sim_u16")
+        .register::<single_precision::U31>("f32_sim_u31")
+        .register::<single_precision::U32>("f32_sim_u32")
+        .register::<single_precision::U64>("f32_sim_u64")
+        .register::<double_precision::U32>("f64_sim_u32")
+        .register::<double_precision::I32>("f64_sim_i32")
+        .register::<u64>("native_u64")
+        .register::<double_precision::U64>("f64_sim_u64")
+        .register::<u128>("native_u128")
+        .register::<double_precision::U128>("f64_sim_u128")
+        .register_reducible::<FpF32<262139>>("f32_fp_262139")
+        .register_reducible::<FpF32<524287>>("f32_fp_524287")
+        .register_reducible::<FpF32<2097143>>("f32_fp_2097143")
+        .register_reducible::<FpF64<2147483647>>("f64_fp_2147483647")
+        .register_reducible::<FpF64<1099511627689>>("f64_fp_1099511627689")
+        .register_reducible::<FpF64<1125899906842597>>("f64_fp_1125899906842597")
+        .register::<fp20::extension::Fp2>("u32_pseudo_mersene_21_ext2")
+        .register::<DualFp>("f64_dual_65537_pseudo_mersene_21");
+    registry
+}
+
+fn multiplication_benches(c: &mut Criterion) {
+    fma::assert_fused_mul_add();
+    registry().multiplication(c);
+}
+
+fn addition_benches(c: &mut Criterion) {
+    registry().addition(c);
+}
+
+fn slice_benches(c: &mut Criterion) {
+    registry().hadamard_acc(c);
+    registry().acc_scaled(c);
+    acc_scaled_fused_benches(c);
+    linear_combination_benches(c);
+    extension_slice_benches(c);
+}
+
+/// Single-reduction `acc_scaled` for the fields with a fused `mul_add`, next
+/// to their `acc_scaled` from the registry
+fn acc_scaled_fused_benches(c: &mut Criterion) {
+    bench_acc_scaled_fused::<fp20::integer::Fp>(c, "u32_pseudo_mersene_21");
+    bench_acc_scaled_fused::<fp20::single_precision::Fp>(c, "f32_pseudo_mersene_21");
+    bench_acc_scaled_fused::<fp20::double_precision::Fp>(c, "f64_pseudo_mersene_21");
+    bench_acc_scaled_fused::<f65537::Fp>(c, "f32_fermat_65537");
+    bench_acc_scaled_fused::<FpF32<1048573>>(c, "f32_fp_1048573");
+    bench_acc_scaled_fused::<m31::Fp>(c, "f64_mersenne_31");
+    bench_acc_scaled_fused::<baby_bear::Fp>(c, "f64_baby_bear");
+}
+
+/// 32 columns of 2^14 elements: repeated `acc_scaled` against the fused pass
+fn linear_combination_benches(c: &mut Criterion) {
     let mut rng = StdRng::from_seed([1; 32]);
-    let values = (0..256).map(|_| rng.gen()).collect::<Vec<T>>();
-    c.bench_function(&format!("{id}/multiplication"), |b| {
-        b.iter(|| {
-            for &[a, b, c, d, e, f, g, h] in values.array_chunks() {
-                black_box([a * b, c * d, e * f, g * h]);
+    let n = 1 << 14;
+    let columns = (0..32)
+        .map(|_| {
+            (0..n)
+                .map(|_| rng.gen())
+                .collect::<Vec<fp20::integer::Fp>>()
+        })
+        .collect::<Vec<_>>();
+    let terms = columns
+        .iter()
+        .map(|column| (rng.gen(), column.as_slice()))
+        .collect::<Vec<_>>();
+    let mut dst = vec![fp20::integer::Fp::new(0); n];
+    let id = "u32_pseudo_mersene_21/linear_combination/32x2^14";
+    c.bench_function(&format!("{id}/acc_scaled"), |bencher| {
+        bencher.iter(|| {
+            for &(k, column) in black_box(&terms) {
+                slice::acc_scaled(&mut dst, column, k);
             }
         });
     });
+    c.bench_function(&format!("{id}/blocked"), |bencher| {
+        bencher.iter(|| slice::acc_linear_combination(&mut dst, black_box(&terms)));
+    });
+    c.bench_function(&format!("{id}/lazy"), |bencher| {
+        bencher.iter(|| fp20::integer::acc_linear_combination(&mut dst, black_box(&terms)));
+    });
 }
 
-fn bench_addition<T: Add<Output = T> + Copy>(c: &mut Criterion, id: &str)
-where
-    Standard: Distribution<T>,
-{
+/// Fp2 Hadamard product on interleaved elements against the de-interleaved
+/// columns over the integer and f64 base field kernels
+fn extension_slice_benches(c: &mut Criterion) {
     let mut rng = StdRng::from_seed([1; 32]);
-    let values = (0..256).map(|_| rng.gen()).collect::<Vec<T>>();
-    c.bench_function(&format!("{id}/addition"), |b| {
-        b.iter(|| {
-            for &[a, b, c, d, e, f, g, h] in values.array_chunks() {
-                black_box([a + b, c + d, e + f, g + h]);
-            }
+    let n = 1 << 12;
+    let a = (0..n)
+        .map(|_| rng.gen())
+        .collect::<Vec<fp20::extension::Fp2>>();
+    let b = (0..n)
+        .map(|_| rng.gen())
+        .collect::<Vec<fp20::extension::Fp2>>();
+    let non_residue = -fp20::integer::Fp::new(1);
+    let id = "u32_pseudo_mersene_21_ext2/hadamard/2^12";
+
+    let mut dst = a.clone();
+    c.bench_function(&format!("{id}/interleaved"), |bencher| {
+        bencher.iter(|| slice::hadamard(&mut dst, black_box(&b)));
+    });
+
+    let [mut c0, mut c1] = extension_slice::deinterleave(&a);
+    let [b0, b1] = extension_slice::deinterleave(&b);
+    c.bench_function(&format!("{id}/columns"), |bencher| {
+        bencher.iter(|| {
+            extension_slice::quadratic_hadamard([&mut c0, &mut c1], [&b0, &b1], non_residue)
+        });
+    });
+
+    let to_double = |column: &[fp20::integer::Fp]| {
+        column
+            .iter()
+            .map(|&v| fp20::double_precision::Fp::from(v))
+            .collect::<Vec<_>>()
+    };
+    let (mut c0, mut c1) = (to_double(&c0), to_double(&c1));
+    let (b0, b1) = (to_double(&b0), to_double(&b1));
+    c.bench_function(&format!("{id}/columns_f64"), |bencher| {
+        bencher.iter(|| {
+            extension_slice::quadratic_hadamard([&mut c0, &mut c1], [&b0, &b1], non_residue.into())
         });
     });
 }
 
-fn multiplication_benches(c: &mut Criterion) {
-    bench_multiplication::<u32>(c, "native_u32");
-    bench_multiplication::<Mersenne31>(c, "plonky3_mersenne_31");
-    bench_multiplication::<single_precision::U31>(c, "f32_sim_u31");
-    bench_multiplication::<single_precision::U32>(c, "f32_sim_u32");
-    bench_multiplication::<double_precision::U32>(c, "f64_sim_u32");
-    bench_multiplication::<fp20::single_precision::Fp>(c, "f32_pseudo_mersene_21");
-    bench_multiplication::<fp20::double_precision::Fp>(c, "f64_pseudo_mersene_21");
-    bench_multiplication::<fp20::integer::Fp>(c, "u32_pseudo_mersene_21");
+/// Fused `mul_add` against `a * b + c` in a dependent chain
+fn mul_add_benches(c: &mut Criterion) {
+    bench_horner::<fp20::single_precision::Fp>(c, "f32_pseudo_mersene_21");
+    bench_horner::<fp20::double_precision::Fp>(c, "f64_pseudo_mersene_21");
+    bench_horner::<fp20::integer::Fp>(c, "u32_pseudo_mersene_21");
+    bench_horner::<fp20::extension::Fp2>(c, "u32_pseudo_mersene_21_ext2");
+    bench_horner::<FpF32<2097143>>(c, "f32_fp_2097143");
+    bench_horner::<FpF64<1099511627689>>(c, "f64_fp_1099511627689");
 }
 
-fn addition_benches(c: &mut Criterion) {
-    bench_addition::<u32>(c, "native_u32");
-    bench_addition::<Mersenne31>(c, "plonky3_mersenne_31");
-    bench_addition::<single_precision::U31>(c, "f32_sim_u31");
-    bench_addition::<single_precision::U32>(c, "f32_sim_u32");
-    bench_addition::<double_precision::U32>(c, "f64_sim_u32");
-    bench_addition::<fp20::single_precision::Fp>(c, "f32_pseudo_mersene_21");
-    bench_addition::<fp20::double_precision::Fp>(c, "f64_pseudo_mersene_21");
-    bench_addition::<fp20::integer::Fp>(c, "u32_pseudo_mersene_21");
+fn operand_distribution_benches(c: &mut Criterion) {
+    let p = fp20::integer::MODULUS;
+    bench_operand_distributions::<fp20::single_precision::Fp>(c, "f32_pseudo_mersene_21", p);
+    bench_operand_distributions::<fp20::double_precision::Fp>(c, "f64_pseudo_mersene_21", p);
+    bench_operand_distributions::<fp20::integer::Fp>(c, "u32_pseudo_mersene_21", p);
+}
+
+/// Interleaved fp20 (f64) and 65537 (f32) Hadamard products against two
+/// separate passes
+#[cfg(feature = "mixed-pipeline")]
+fn mixed_pipeline_benches(c: &mut Criterion) {
+    use ark_experimentation::mixed_pipeline;
+
+    let mut rng = StdRng::from_seed([1; 32]);
+    let n = 1 << 14;
+    let fp20_rhs = (0..n)
+        .map(|_| rng.gen())
+        .collect::<Vec<fp20::double_precision::Fp>>();
+    let f65537_rhs = (0..n)
+        .map(|_| rng.gen_range(0..65537) as f32)
+        .collect::<Vec<f32>>();
+    let mut fp20 = (0..n)
+        .map(|_| rng.gen())
+        .collect::<Vec<fp20::double_precision::Fp>>();
+    let mut f65537 = (0..n)
+        .map(|_| rng.gen_range(0..65537) as f32)
+        .collect::<Vec<f32>>();
+    c.bench_function("mixed_pipeline/hadamard/2^14/interleaved", |bencher| {
+        bencher.iter(|| {
+            mixed_pipeline::hadamard(
+                &mut fp20,
+                black_box(&fp20_rhs),
+                &mut f65537,
+                black_box(&f65537_rhs),
+            )
+        });
+    });
+    c.bench_function("mixed_pipeline/hadamard/2^14/separate", |bencher| {
+        bencher.iter(|| {
+            mixed_pipeline::hadamard_separate(
+                &mut fp20,
+                black_box(&fp20_rhs),
+                &mut f65537,
+                black_box(&f65537_rhs),
+            )
+        });
+    });
+}
+
+#[cfg(not(feature = "mixed-pipeline"))]
+fn mixed_pipeline_benches(_: &mut Criterion) {}
+
+fn convolution_benches(c: &mut Criterion) {
+    let mut rng = StdRng::from_seed([1; 32]);
+    for log_n in [10, 12, 14, 16] {
+        let n = 1 << log_n;
+        let a = (0..n)
+            .map(|_| (rng.gen::<u32>() % 65537) as f32)
+            .collect::<Vec<f32>>();
+        let b = (0..n)
+            .map(|_| (rng.gen::<u32>() % 65537) as f32)
+            .collect::<Vec<f32>>();
+        c.bench_function(&format!("f32_65537/convolve/2^{log_n}"), |bencher| {
+            bencher.iter(|| black_box(bigint::convolve(black_box(&a), black_box(&b))));
+        });
+    }
+}
+
+fn conversion_benches(c: &mut Criterion) {
+    let p = fp20::integer::MODULUS;
+    bench_conversion(
+        c,
+        "f16_sim_u8",
+        1 << 8,
+        |v| half_precision::U8::new(v as u8),
+        |v| u8::from(v).into(),
+    );
+    bench_conversion(
+        c,
+        "f32_sim_u31",
+        1 << 31,
+        single_precision::U31::new,
+        u32::from,
+    );
+    bench_conversion(
+        c,
+        "f32_sim_u32",
+        u32::MAX,
+        single_precision::U32::new,
+        u32::from,
+    );
+    bench_conversion(
+        c,
+        "f64_sim_u32",
+        u32::MAX,
+        double_precision::U32::new,
+        u32::from,
+    );
+    bench_conversion(
+        c,
+        "f32_pseudo_mersene_21",
+        p,
+        fp20::single_precision::Fp::new,
+        u32::from,
+    );
+    bench_conversion(
+        c,
+        "f64_pseudo_mersene_21",
+        p,
+        fp20::double_precision::Fp::new,
+        u32::from,
+    );
+    bench_conversion(
+        c,
+        "u32_pseudo_mersene_21",
+        p,
+        fp20::integer::Fp::new,
+        u32::from,
+    );
+}
+
+fn packing_benches(c: &mut Criterion) {
+    bench_le_bits(
+        c,
+        "f32_pseudo_mersene_21",
+        fp20::single_precision::Fp::to_le_bits,
+        fp20::single_precision::Fp::from_le_bits,
+    );
+    bench_le_bits(
+        c,
+        "f64_pseudo_mersene_21",
+        fp20::double_precision::Fp::to_le_bits,
+        fp20::double_precision::Fp::from_le_bits,
+    );
+    bench_le_bits(
+        c,
+        "u32_pseudo_mersene_21",
+        fp20::integer::Fp::to_le_bits,
+        fp20::integer::Fp::from_le_bits,
+    );
+
+    let mut rng = StdRng::from_seed([1; 32]);
+    let values = (0..1 << 12)
+        .map(|_| rng.gen())
+        .collect::<Vec<fp20::integer::Fp>>();
+    let packed = fp20::extension::pack(&values);
+    c.bench_function("u32_pseudo_mersene_21_ext2/pack/2^12", |bencher| {
+        bencher.iter(|| black_box(fp20::extension::pack(black_box(&values))));
+    });
+    c.bench_function("u32_pseudo_mersene_21_ext2/unpack/2^12", |bencher| {
+        bencher.iter(|| black_box(fp20::extension::unpack(black_box(&packed))));
+    });
 }
 
-criterion_group!(benches, multiplication_benches, addition_benches);
+criterion_group!(
+    benches,
+    multiplication_benches,
+    addition_benches,
+    mul_add_benches,
+    operand_distribution_benches,
+    slice_benches,
+    mixed_pipeline_benches,
+    convolution_benches,
+    conversion_benches,
+    packing_benches
+);
 criterion_main!(benches);
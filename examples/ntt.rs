@@ -0,0 +1,72 @@
+//! Evaluates random polynomials over every fp20 extension domain with the NTT,
+//! checks them against naive evaluation, round trips through the inverse and
+//! multiplies polynomials through the transform.
+//!
+//! ```text
+//! cargo run --release --example ntt
+//! ```
+use ark_experimentation::fp20::extension;
+use ark_experimentation::fp20::extension::Fp2;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 10_000;
+
+fn evaluate(coeffs: &[Fp2], x: Fp2) -> Fp2 {
+    coeffs
+        .iter()
+        .rev()
+        .fold(Fp2::from(0), |acc, &c| acc * x + c)
+}
+
+fn main() {
+    let mut rng = StdRng::from_seed([1; 32]);
+    let mut n = 1;
+    while n <= extension::MAX_NTT_SIZE {
+        let shift = Fp2::from(rng.gen_range(1..1 << 20));
+        for domain in [Fp2::domain(n), Fp2::coset(n, shift)] {
+            let coeffs = (0..n).map(|_| rng.gen()).collect::<Vec<Fp2>>();
+            let mut values = coeffs.clone();
+            extension::ntt(&mut values, &domain);
+            for (x, &v) in domain.elements().zip(&values) {
+                assert_eq!(evaluate(&coeffs, x), v, "evaluation at {x:?}");
+            }
+            extension::intt(&mut values, &domain);
+            assert_eq!(coeffs, values, "round trip over {domain:?}");
+        }
+
+        // product of two polynomials of degree < n / 2 fits in n coefficients
+        let domain = Fp2::domain(n);
+        let half = n.div_ceil(2);
+        let mut a = (0..n)
+            .map(|i| if i < half { rng.gen() } else { Fp2::from(0) })
+            .collect::<Vec<Fp2>>();
+        let mut b = (0..n)
+            .map(|i| if i < n / 2 { rng.gen() } else { Fp2::from(0) })
+            .collect::<Vec<Fp2>>();
+        let mut expected = vec![Fp2::from(0); n];
+        for i in 0..n {
+            for j in 0..n - i {
+                expected[i + j] += a[i] * b[j];
+            }
+        }
+        extension::ntt(&mut a, &domain);
+        extension::ntt(&mut b, &domain);
+        for (a, b) in a.iter_mut().zip(&b) {
+            *a *= *b;
+        }
+        extension::intt(&mut a, &domain);
+        assert_eq!(expected, a, "product of size {n}");
+
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            extension::ntt(&mut a, &domain);
+            extension::intt(&mut a, &domain);
+        }
+        let elapsed = start.elapsed() / (2 * ITERATIONS);
+        println!("n = {n:>2}: evaluation, round trip and product ok ({elapsed:?} per transform)");
+        n *= 2;
+    }
+}
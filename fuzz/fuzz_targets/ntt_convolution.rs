@@ -0,0 +1,92 @@
+//! Differential fuzzing of the f32 NTT against integer references:
+//! [`convolve`] against a quadratic cyclic convolution over `u64` and
+//! [`mul`] against schoolbook multiplication.
+//!
+//! Usage: `cargo +nightly fuzz run ntt_convolution` from the repository root
+#![no_main]
+
+use ark_experimentation::arithmetic::single_precision::U16;
+use ark_experimentation::bigint::convolve;
+use ark_experimentation::bigint::mul;
+use ark_experimentation::bigint::MAX_LIMBS;
+use libfuzzer_sys::fuzz_target;
+
+const P: u64 = 65537;
+
+/// Largest transform size tried, keeps the quadratic reference fast
+const MAX_LOG_N: u8 = 10;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, data)) = data.split_first() else {
+        return;
+    };
+    if selector & 0x80 == 0 {
+        check_convolve(selector % (MAX_LOG_N + 1), data);
+    } else {
+        check_mul(data);
+    }
+});
+
+/// Reads canonical residues from 4 byte chunks, zero padded to `n`
+fn residues(data: &[u8], n: usize) -> Vec<u64> {
+    let mut values = data
+        .chunks_exact(4)
+        .take(n)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()) as u64 % P)
+        .collect::<Vec<u64>>();
+    values.resize(n, 0);
+    values
+}
+
+fn check_convolve(log_n: u8, data: &[u8]) {
+    let n = 1 << log_n;
+    let (a, b) = data.split_at(data.len() / 2);
+    let a = residues(a, n);
+    let b = residues(b, n);
+
+    let mut expected = vec![0; n];
+    for (i, &a) in a.iter().enumerate() {
+        for (j, &b) in b.iter().enumerate() {
+            let k = (i + j) % n;
+            expected[k] = (expected[k] + a * b) % P;
+        }
+    }
+
+    let to_f32 = |v: &[u64]| v.iter().map(|&v| v as f32).collect::<Vec<f32>>();
+    let actual = convolve(&to_f32(&a), &to_f32(&b));
+    assert_eq!(to_f32(&expected), actual, "convolution of size {n}");
+}
+
+fn check_mul(data: &[u8]) {
+    let limbs = data
+        .chunks_exact(2)
+        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+        .collect::<Vec<u16>>();
+    let (a, b) = limbs.split_at(limbs.len() / 2);
+    let a = &a[..a.len().min(MAX_LIMBS)];
+    let b = &b[..b.len().min(MAX_LIMBS)];
+
+    let mut expected = vec![0u16; a.len() + b.len()];
+    for (i, &a) in a.iter().enumerate() {
+        let mut carry = 0u32;
+        for (j, &b) in b.iter().enumerate() {
+            let v = a as u32 * b as u32 + expected[i + j] as u32 + carry;
+            expected[i + j] = v as u16;
+            carry = v >> 16;
+        }
+        expected[i + b.len()] = carry as u16;
+    }
+
+    let to_u16s = |v: &[U16]| v.iter().map(|&v| u16::from(v)).collect::<Vec<u16>>();
+    let actual = mul(
+        &a.iter().map(|&v| U16::new(v)).collect::<Vec<U16>>(),
+        &b.iter().map(|&v| U16::new(v)).collect::<Vec<U16>>(),
+    );
+    assert_eq!(
+        expected,
+        to_u16s(&actual),
+        "{} x {} limbs",
+        a.len(),
+        b.len()
+    );
+}
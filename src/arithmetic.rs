@@ -1,17 +1,266 @@
+/// Implements `From` for the native integers that always fit in `$t` and
+/// `TryFrom` for the rest by first converting to `$repr`
+macro_rules! impl_native_conversions {
+    ($t:ty, $repr:ty, from: [$($from:ty),*], try_from: [$($try_from:ty),*]) => {
+        $(
+            impl From<$from> for $t {
+                #[inline]
+                fn from(value: $from) -> Self {
+                    Self::new(value.into())
+                }
+            }
+        )*
+
+        $(
+            impl TryFrom<$try_from> for $t {
+                type Error = std::num::TryFromIntError;
+
+                #[inline]
+                fn try_from(value: $try_from) -> Result<Self, Self::Error> {
+                    <$repr>::try_from(value).map(|v| Self::new(v as _))
+                }
+            }
+        )*
+    };
+}
+
+/// Implements `Eq`, `PartialOrd` and `Ord` by the represented integer. Deriving
+/// them would compare limb arrays least significant limb first.
+macro_rules! impl_ord {
+    ($t:ty, $repr:ty) => {
+        impl Eq for $t {}
+
+        impl PartialOrd for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $t {
+            #[inline]
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                <$repr>::from(*self).cmp(&<$repr>::from(*other))
+            }
+        }
+    };
+}
+
+/// Implements `Display` as the represented integer
+macro_rules! impl_display {
+    ($t:ty, $repr:ty) => {
+        impl std::fmt::Display for $t {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                <$repr>::from(*self).fmt(f)
+            }
+        }
+    };
+}
+
+/// Implements `num_traits::{Zero, One, Pow<u64>, CheckedAdd, CheckedMul}` for
+/// a simulated integer. The fields use `impl_num_traits!` from `crate::macros`.
+/// `Pow` forwards to the wrapping `pow` of `impl_pow!` and takes a `u64`
+/// exponent like the fields'. The checked ops detect overflow past `$max` on
+/// the represented integer and otherwise use the simulated op.
+macro_rules! impl_int_num_traits {
+    ($t:ty, $repr:ty,max: $max:expr) => {
+        impl num_traits::Zero for $t {
+            #[inline]
+            fn zero() -> Self {
+                Self::new(0)
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                <$repr>::from(*self) == 0
+            }
+        }
+
+        impl num_traits::One for $t {
+            #[inline]
+            fn one() -> Self {
+                Self::new(1)
+            }
+        }
+
+        impl num_traits::Pow<u64> for $t {
+            type Output = Self;
+
+            #[inline]
+            fn pow(self, exp: u64) -> Self {
+                <$t>::pow(self, exp)
+            }
+        }
+
+        impl num_traits::CheckedAdd for $t {
+            #[inline]
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                <$repr>::from(*self)
+                    .checked_add(<$repr>::from(*v))
+                    .filter(|&r| r <= $max)
+                    .map(|_| *self + *v)
+            }
+        }
+
+        impl num_traits::CheckedMul for $t {
+            #[inline]
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                <$repr>::from(*self)
+                    .checked_mul(<$repr>::from(*v))
+                    .filter(|&r| r <= $max)
+                    .map(|_| *self * *v)
+            }
+        }
+    };
+}
+
+/// Implements a wrapping `pow` by square-and-multiply over the simulated ops
+macro_rules! impl_pow {
+    ($t:ty) => {
+        impl $t {
+            /// Computes `self^exp` by square-and-multiply, wrapping like the
+            /// native `wrapping_pow`
+            #[inline]
+            #[must_use]
+            pub fn pow(self, mut exp: u64) -> Self {
+                let mut base = self;
+                let mut res = Self::new(1);
+                while exp != 0 {
+                    if exp & 1 == 1 {
+                        res *= base;
+                    }
+                    base *= base;
+                    exp >>= 1;
+                }
+                res
+            }
+        }
+    };
+}
+
+/// Checks `Ord` agrees with the native order over every pair
+#[cfg(test)]
+fn assert_native_order<T: Ord + std::fmt::Debug, N: Ord + Copy + std::fmt::Debug>(
+    values: &[N],
+    new: impl Fn(N) -> T,
+) {
+    for &a in values {
+        for &b in values {
+            assert_eq!(
+                a.cmp(&b),
+                new(a).cmp(&new(b)),
+                "mismatch: `{a:?} <=> {b:?}`"
+            );
+        }
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort();
+    let mut actual = values.iter().map(|&v| new(v)).collect::<Vec<T>>();
+    actual.sort();
+    assert_eq!(sorted.into_iter().map(new).collect::<Vec<T>>(), actual);
+}
+
+/// Checks `pow` against square-and-multiply on `u128` truncated to `bits`.
+/// `new` takes the low `bits` of a `u128`.
+#[cfg(test)]
+fn assert_pow<T: Copy + PartialEq + std::fmt::Debug>(
+    bits: u32,
+    new: impl Fn(u128) -> T,
+    pow: impl Fn(T, u64) -> T,
+) {
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    let mask = u128::MAX >> (128 - bits);
+    let reference = |mut base: u128, mut exp: u64| {
+        let mut res = 1u128;
+        while exp != 0 {
+            if exp & 1 == 1 {
+                res = res.wrapping_mul(base);
+            }
+            base = base.wrapping_mul(base);
+            exp >>= 1;
+        }
+        res & mask
+    };
+    let mut rng = StdRng::from_seed([1; 32]);
+    let values = [0, 1, 2, 3, mask - 1, mask]
+        .into_iter()
+        .chain((0..64).map(|_| rng.gen::<u128>() & mask))
+        .collect::<Vec<u128>>();
+    for a in values {
+        for exp in [0, 1, 2, 3, 64, u64::MAX, rng.gen()] {
+            assert_eq!(
+                new(reference(a, exp)),
+                pow(new(a), exp),
+                "mismatch: `{a}^{exp}`"
+            );
+        }
+    }
+}
+
+/// Checks the `num_traits` impls agree with the native integers over every
+/// pair
+#[cfg(test)]
+fn assert_num_traits<T, N>(values: &[N], max: N, new: impl Fn(N) -> T)
+where
+    T: num_traits::Zero
+        + num_traits::One
+        + num_traits::Pow<u64, Output = T>
+        + num_traits::CheckedAdd
+        + num_traits::CheckedMul
+        + Copy
+        + PartialEq
+        + std::fmt::Debug,
+    N: num_traits::PrimInt + std::fmt::Debug,
+{
+    assert!(T::zero().is_zero());
+    assert!(!T::one().is_zero());
+    assert_eq!(new(N::one()), T::one());
+    let fits = |v: Option<N>| v.filter(|&v| v <= max);
+    for &a in values {
+        if let Some(square) = fits(a.checked_mul(&a)) {
+            assert_eq!(new(square), new(a).pow(2), "mismatch: `{a:?}^2`");
+        }
+        assert_eq!(new(N::one()), new(a).pow(0));
+        for &b in values {
+            let (x, y) = (new(a), new(b));
+            assert_eq!(
+                fits(a.checked_add(&b)).map(&new),
+                x.checked_add(&y),
+                "mismatch: `{a:?} + {b:?}`"
+            );
+            assert_eq!(
+                fits(a.checked_mul(&b)).map(&new),
+                x.checked_mul(&y),
+                "mismatch: `{a:?} * {b:?}`"
+            );
+        }
+    }
+}
+
 pub mod half_precision {
+    use crate::macros::impl_assign_ops;
+    use crate::macros::impl_pod;
     use half::f16;
     use num_traits::Float;
+    use rand::distributions::Standard;
+    use rand::prelude::Distribution;
     use std::ops::Add;
     use std::ops::Mul;
+    use std::ops::Neg;
+    use std::ops::Sub;
 
-    // u8 arithmetic simulated with half precision
+    /// u8 arithmetic simulated with half precision
     #[repr(transparent)]
-    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
-    struct U8(f16);
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct U8(f16);
 
     impl U8 {
         const MODULUS: f16 = f16::from_f32_const(256.0);
         const MODULUS_INV: f16 = f16::from_f32_const(1.0 / 256.0);
+        const MAX: f16 = f16::from_f32_const(255.0);
         const ONE: f16 = f16::ONE;
 
         #[inline]
@@ -19,6 +268,62 @@ pub mod half_precision {
         pub const fn new(v: u8) -> Self {
             Self(f16::from_f32_const(v as f32))
         }
+
+        /// Computes `self + rhs mod m` for `self, rhs < m`
+        pub fn add_mod(self, rhs: Self, m: Modulus) -> Self {
+            debug_assert!(self.0 < m.m && rhs.0 < m.m);
+            let a = self.0 + rhs.0;
+            Self(if a >= m.m { a - m.m } else { a })
+        }
+
+        /// Computes `self * rhs mod m` for `self, rhs < m` with the same
+        /// FMA reduction as [`Mul`] but a runtime modulus
+        pub fn mul_mod(self, rhs: Self, m: Modulus) -> Self {
+            debug_assert!(self.0 < m.m && rhs.0 < m.m);
+            let h = self.0 * rhs.0;
+            let l = mad_f16(self.0, rhs.0, -h);
+            let b = h * m.m_inv;
+            let c = b.trunc();
+            let d = mad_f16(-c, m.m, h);
+            let e = d + l;
+            Self(if e >= m.m {
+                e - m.m
+            } else if e < f16::ZERO {
+                e + m.m
+            } else {
+                e
+            })
+        }
+
+        pub fn saturating_add(self, rhs: Self) -> Self {
+            Self((self.0 + rhs.0).min(Self::MAX))
+        }
+
+        /// The rounded product is compared against `255` which is exact so the
+        /// rounding can't cross the saturation boundary
+        pub fn saturating_mul(self, rhs: Self) -> Self {
+            Self((self.0 * rhs.0).min(Self::MAX))
+        }
+
+        /// Computes the full product `self * rhs` as `(lo, hi)`. The rounded
+        /// product keeps the high half and the FMA error term (at most `16`,
+        /// the product's half ulp) corrects the low half, borrowing or
+        /// carrying into the high half.
+        #[inline]
+        fn widening_mul(self, rhs: Self) -> (f16, f16) {
+            let h = self.0 * rhs.0;
+            let l = mad_f16(self.0, rhs.0, -h);
+            let mut hi = (h * Self::MODULUS_INV).trunc();
+            let mut lo = mad_f16(-hi, Self::MODULUS, h) + l;
+            if lo < f16::ZERO {
+                lo += Self::MODULUS;
+                hi -= Self::ONE;
+            } else if lo >= Self::MODULUS {
+                lo -= Self::MODULUS;
+                hi += Self::ONE;
+            }
+            (lo, hi)
+        }
     }
 
     impl From<U8> for u8 {
@@ -61,19 +366,311 @@ pub mod half_precision {
         }
     }
 
+    impl_assign_ops!(
+        U8;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_pod!(U8);
+
+    /// Runtime modulus `m <= 256` for [`U8::mul_mod`] and [`U8::add_mod`] with
+    /// its precomputed reciprocal
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Modulus {
+        m: f16,
+        m_inv: f16,
+    }
+
+    impl Modulus {
+        pub fn new(m: u16) -> Self {
+            assert!(m != 0 && m <= 256, "modulus must be in [1, 256]");
+            let m = f16::from_f32(m.into());
+            Self {
+                m,
+                m_inv: f16::ONE / m,
+            }
+        }
+    }
+
+    impl Sub for U8 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            let a = self.0 - rhs.0;
+            Self(if a < f16::ZERO { a + Self::MODULUS } else { a })
+        }
+    }
+
+    impl Neg for U8 {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            Self(if self.0 == f16::ZERO {
+                self.0
+            } else {
+                Self::MODULUS - self.0
+            })
+        }
+    }
+
+    impl Distribution<U8> for Standard {
+        fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U8 {
+            U8::new(self.sample(rng))
+        }
+    }
+
+    impl_native_conversions!(U8, u8, from: [u8], try_from: [u16, u32, u64, usize]);
+    impl_ord!(U8, u8);
+    impl_display!(U8, u8);
+    impl_int_num_traits!(U8, u8, max: u8::MAX);
+    impl_pow!(U8);
+
     /// Calculates the fused multiply add: `a * b + c`
     #[inline]
     #[must_use]
-    fn mad_f16(a: f16, b: f16, c: f16) -> f16 {
+    pub(crate) fn mad_f16(a: f16, b: f16, c: f16) -> f16 {
         let a = f16::to_f32(a);
         let b = f16::to_f32(b);
         let c = f16::to_f32(c);
         f16::from_f32(a.mul_add(b, c))
     }
 
+    /// Wrapping schoolbook product of little endian limbs, dropping the
+    /// partial products of weight `2^(8N)` and above. For `N <= 4` a column
+    /// plus its carry stays below `2^11` so the columns are exact in f16 and
+    /// the carries are propagated with fract/trunc at the end.
+    #[inline]
+    fn mul_limbs<const N: usize>(a: [U8; N], b: [U8; N]) -> [U8; N] {
+        let mut columns = [f16::ZERO; N];
+        for i in 0..N {
+            for j in 0..N - i {
+                let (lo, hi) = a[i].widening_mul(b[j]);
+                columns[i + j] += lo;
+                if i + j + 1 < N {
+                    columns[i + j + 1] += hi;
+                }
+            }
+        }
+
+        let mut carry = f16::ZERO;
+        columns.map(|column| {
+            let v = (column + carry) * U8::MODULUS_INV;
+            carry = v.trunc();
+            U8(v.fract() * U8::MODULUS)
+        })
+    }
+
+    /// Wrapping sum of little endian limbs
+    #[inline]
+    fn add_limbs<const N: usize>(a: [U8; N], b: [U8; N]) -> [U8; N] {
+        let mut carry = f16::ZERO;
+        std::array::from_fn(|i| {
+            let mut l = a[i].0 + b[i].0 + carry;
+            carry = f16::ZERO;
+            if l >= U8::MODULUS {
+                l -= U8::MODULUS;
+                carry = U8::ONE;
+            }
+            U8(l)
+        })
+    }
+
+    /// Wrapping difference of little endian limbs
+    #[inline]
+    fn sub_limbs<const N: usize>(a: [U8; N], b: [U8; N]) -> [U8; N] {
+        let mut borrow = f16::ZERO;
+        std::array::from_fn(|i| {
+            let mut l = a[i].0 - b[i].0 - borrow;
+            borrow = f16::ZERO;
+            if l < f16::ZERO {
+                l += U8::MODULUS;
+                borrow = U8::ONE;
+            }
+            U8(l)
+        })
+    }
+
+    /// u16 arithmetic simulated with half precision as two u8 limbs
+    #[repr(transparent)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct U16([U8; 2]);
+
+    impl U16 {
+        #[inline]
+        #[must_use]
+        pub const fn new(v: u16) -> Self {
+            Self([U8::new(v as u8), U8::new((v >> 8) as u8)])
+        }
+    }
+
+    impl Add for U16 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            Self(add_limbs(self.0, rhs.0))
+        }
+    }
+
+    impl Sub for U16 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            Self(sub_limbs(self.0, rhs.0))
+        }
+    }
+
+    impl Mul for U16 {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self {
+            Self(mul_limbs(self.0, rhs.0))
+        }
+    }
+
+    impl_assign_ops!(
+        U16;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_pod!(U16);
+
+    impl Distribution<U16> for Standard {
+        fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U16 {
+            U16::new(self.sample(rng))
+        }
+    }
+
+    impl From<U16> for u16 {
+        #[inline]
+        fn from(v: U16) -> u16 {
+            u16::from(u8::from(v.0[1])) << 8 | u16::from(u8::from(v.0[0]))
+        }
+    }
+
+    impl_native_conversions!(U16, u16, from: [u8, u16], try_from: [u32, u64, usize]);
+    impl_ord!(U16, u16);
+    impl_display!(U16, u16);
+    impl_int_num_traits!(U16, u16, max: u16::MAX);
+    impl_pow!(U16);
+
+    impl From<U8> for U16 {
+        #[inline]
+        fn from(value: U8) -> Self {
+            Self([value, U8::new(0)])
+        }
+    }
+
+    /// u24 arithmetic simulated with half precision as three u8 limbs. There's
+    /// no native u24 so values are `u32`s below `2^24` and the arithmetic wraps
+    /// modulo `2^24`.
+    #[repr(transparent)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct U24([U8; 3]);
+
+    impl U24 {
+        pub const MAX: u32 = (1 << 24) - 1;
+
+        #[inline]
+        #[must_use]
+        pub const fn new(v: u32) -> Self {
+            debug_assert!(v <= Self::MAX);
+            Self([
+                U8::new(v as u8),
+                U8::new((v >> 8) as u8),
+                U8::new((v >> 16) as u8),
+            ])
+        }
+    }
+
+    impl Add for U24 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            Self(add_limbs(self.0, rhs.0))
+        }
+    }
+
+    impl Sub for U24 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            Self(sub_limbs(self.0, rhs.0))
+        }
+    }
+
+    impl Mul for U24 {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self {
+            Self(mul_limbs(self.0, rhs.0))
+        }
+    }
+
+    impl_assign_ops!(
+        U24;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_pod!(U24);
+
+    impl Distribution<U24> for Standard {
+        fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U24 {
+            U24::new(rng.gen_range(0..=U24::MAX))
+        }
+    }
+
+    impl From<U24> for u32 {
+        #[inline]
+        fn from(v: U24) -> u32 {
+            v.0.iter()
+                .rev()
+                .fold(0, |acc, &limb| acc << 8 | u8::from(limb) as u32)
+        }
+    }
+
+    impl_native_conversions!(U24, u32, from: [u8, u16], try_from: []);
+
+    // u32 is wider than 24 bits so it can't go through `impl_native_conversions!`
+    impl TryFrom<u32> for U24 {
+        type Error = std::num::TryFromIntError;
+
+        #[inline]
+        fn try_from(value: u32) -> Result<Self, Self::Error> {
+            // the bits above the low 16 fit in a byte iff `value <= U24::MAX`
+            u8::try_from(value >> 16).map(|_| Self::new(value))
+        }
+    }
+
+    impl_ord!(U24, u32);
+    impl_display!(U24, u32);
+    impl_int_num_traits!(U24, u32, max: U24::MAX);
+    impl_pow!(U24);
+
+    impl From<U8> for U24 {
+        #[inline]
+        fn from(value: U8) -> Self {
+            let zero = U8::new(0);
+            Self([value, zero, zero])
+        }
+    }
+
+    impl From<U16> for U24 {
+        #[inline]
+        fn from(value: U16) -> Self {
+            let [l0, l1] = value.0;
+            Self([l0, l1, U8::new(0)])
+        }
+    }
+
     /// u32 arithmetic simulated with half precision
     #[repr(transparent)]
-    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct U32([U8; 4]);
 
     impl U32 {
@@ -125,41 +722,248 @@ pub mod half_precision {
         type Output = Self;
 
         fn mul(self, rhs: Self) -> Self::Output {
-            todo!()
+            Self(mul_limbs(self.0, rhs.0))
         }
     }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-
-        const U32_EDGE_CASES: [u32; 10] =
-            [0, 1, 2, 3, 5, 0xFF, 0xFF00, 0xFF0000, 0xFF000000, u32::MAX];
-
-        #[test]
-        fn simulated_u8_multiplication() {
-            for a in 0..=u8::MAX {
-                for b in 0..=u8::MAX {
-                    let expected = U8::new(a * b);
-                    let actual = U8::new(a) * U8::new(b);
-                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+    impl U32 {
+        /// Computes the full product `self * rhs` as `(lo, hi)` with the
+        /// columns of [`mul_limbs`] over eight limbs. A column sums at most
+        /// eight half products and a carry below `8`, which stays below `2^11`.
+        pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
+            let mut columns = [f16::ZERO; 8];
+            for i in 0..4 {
+                for j in 0..4 {
+                    let (lo, hi) = self.0[i].widening_mul(rhs.0[j]);
+                    columns[i + j] += lo;
+                    columns[i + j + 1] += hi;
                 }
             }
+
+            let mut carry = f16::ZERO;
+            let limbs = columns.map(|column| {
+                let v = (column + carry) * U8::MODULUS_INV;
+                carry = v.trunc();
+                U8(v.fract() * U8::MODULUS)
+            });
+            (
+                Self([limbs[0], limbs[1], limbs[2], limbs[3]]),
+                Self([limbs[4], limbs[5], limbs[6], limbs[7]]),
+            )
         }
+    }
 
-        #[test]
-        #[ignore = "unimplemented"]
-        fn simulated_u32_multiplication() {
-            for a in U32_EDGE_CASES {
-                for b in U32_EDGE_CASES {
-                    let expected = U32::new(a * b);
-                    let actual = U32::new(a) * U32::new(b);
-                    assert_eq!(expected, actual, "mismatch: `{a} + {b}`");
-                }
+    impl Sub for U32 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            let mut l0 = self.0[0].0 - rhs.0[0].0;
+            let mut l1 = self.0[1].0 - rhs.0[1].0;
+            let mut l2 = self.0[2].0 - rhs.0[2].0;
+            let mut l3 = self.0[3].0 - rhs.0[3].0;
+
+            if l0 < f16::ZERO {
+                l0 += U8::MODULUS;
+                l1 -= U8::ONE;
             }
-        }
 
-        #[test]
+            if l1 < f16::ZERO {
+                l1 += U8::MODULUS;
+                l2 -= U8::ONE;
+            }
+
+            if l2 < f16::ZERO {
+                l2 += U8::MODULUS;
+                l3 -= U8::ONE;
+            }
+
+            if l3 < f16::ZERO {
+                l3 += U8::MODULUS;
+            }
+
+            Self([U8(l0), U8(l1), U8(l2), U8(l3)])
+        }
+    }
+
+    impl_assign_ops!(
+        U32;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_pod!(U32);
+
+    impl Distribution<U32> for Standard {
+        fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U32 {
+            U32::new(self.sample(rng))
+        }
+    }
+
+    impl From<U32> for u32 {
+        #[inline]
+        fn from(v: U32) -> u32 {
+            v.0.iter()
+                .rev()
+                .fold(0, |acc, &limb| acc << 8 | u8::from(limb) as u32)
+        }
+    }
+
+    impl_native_conversions!(U32, u32, from: [u8, u16, u32], try_from: [u64, usize]);
+    impl_ord!(U32, u32);
+    impl_display!(U32, u32);
+    impl_int_num_traits!(U32, u32, max: u32::MAX);
+    impl_pow!(U32);
+
+    impl From<U8> for U32 {
+        #[inline]
+        fn from(value: U8) -> Self {
+            let zero = U8::new(0);
+            Self([value, zero, zero, zero])
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::arithmetic::assert_native_order;
+        use crate::arithmetic::assert_num_traits;
+        use crate::arithmetic::assert_pow;
+        use rand::rngs::StdRng;
+        use rand::Rng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn ordering() {
+            assert_native_order(&[0, 1, 0x7F, 0x80, 0xFE, 0xFF], U8::new);
+            // 0x100 has a larger high limb but a smaller low limb than 0xFF
+            let u32s = [
+                0,
+                1,
+                0xFF,
+                0x100,
+                0x1FF,
+                0xFF00,
+                0x10000,
+                0xFF000000,
+                u32::MAX,
+            ];
+            assert_native_order(&u32s, U32::new);
+            assert_native_order(&[0, 1, 0xFF, 0x100, 0x1FF, 0xFF00, u16::MAX], U16::new);
+            assert_native_order(&[0, 0xFF, 0x100, 0xFF00, 0x10000, U24::MAX], U24::new);
+        }
+
+        #[test]
+        fn num_traits() {
+            let u8s = [0, 1, 2, 0x0F, 0x10, 0x7F, 0x80, u8::MAX];
+            assert_num_traits(&u8s, u8::MAX, U8::new);
+            let u16s = [0, 1, 2, 0xFF, 0x100, 0x7FFF, 0x8000, u16::MAX];
+            assert_num_traits(&u16s, u16::MAX, U16::new);
+            let u24s = [0, 1, 2, 0xFFF, 0x1000, 0x7FFFFF, 0x800000, U24::MAX];
+            assert_num_traits(&u24s, U24::MAX, U24::new);
+            let u32s = [0, 1, 2, 0xFFFF, 0x10000, 0x7FFF_FFFF, 0x8000_0000, u32::MAX];
+            assert_num_traits(&u32s, u32::MAX, U32::new);
+        }
+
+        #[test]
+        fn pow() {
+            assert_pow(8, |v| U8::new(v as u8), U8::pow);
+            assert_pow(16, |v| U16::new(v as u16), U16::pow);
+            assert_pow(24, |v| U24::new(v as u32), U24::pow);
+            assert_pow(32, |v| U32::new(v as u32), U32::pow);
+        }
+
+        #[test]
+        fn display() {
+            assert_eq!("255", U8::new(u8::MAX).to_string());
+            assert_eq!("65535", U16::new(u16::MAX).to_string());
+            assert_eq!("16777215", U24::new(U24::MAX).to_string());
+            assert_eq!("4294967295", U32::new(u32::MAX).to_string());
+        }
+
+        const U32_EDGE_CASES: [u32; 10] =
+            [0, 1, 2, 3, 5, 0xFF, 0xFF00, 0xFF0000, 0xFF000000, u32::MAX];
+
+        #[test]
+        fn simulated_u8_multiplication() {
+            for a in 0..=u8::MAX {
+                for b in 0..=u8::MAX {
+                    let expected = U8::new(a * b);
+                    let actual = U8::new(a) * U8::new(b);
+                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u32_multiplication() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..64)
+                .map(|_| rng.gen())
+                .chain(U32_EDGE_CASES)
+                .collect::<Vec<u32>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let expected = U32::new(a.wrapping_mul(b));
+                    let actual = U32::new(a) * U32::new(b);
+                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u32_widening_multiplication() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..64)
+                .map(|_| rng.gen())
+                .chain(U32_EDGE_CASES)
+                .collect::<Vec<u32>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let expected = a as u64 * b as u64;
+                    let (lo, hi) = U32::new(a).widening_mul(U32::new(b));
+                    let actual = (u32::from(hi) as u64) << 32 | u32::from(lo) as u64;
+                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u16_arithmetic() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let values = (0..1 << 16).map(|_| (rng.gen(), rng.gen())).chain([
+                (0xFFFF, 0xFFFF),
+                (0xFF, 0xFF),
+                (0x100, 0xFF),
+                (0xFFFF, 1),
+            ]);
+            for (a, b) in values {
+                let (x, y) = (U16::new(a), U16::new(b));
+                assert_eq!(a.wrapping_mul(b), u16::from(x * y), "mismatch: `{a} * {b}`");
+                assert_eq!(a.wrapping_add(b), u16::from(x + y), "mismatch: `{a} + {b}`");
+                assert_eq!(a.wrapping_sub(b), u16::from(x - y), "mismatch: `{a} - {b}`");
+            }
+        }
+
+        #[test]
+        fn simulated_u24_arithmetic() {
+            let mask = U24::MAX;
+            let mut rng = StdRng::from_seed([1; 32]);
+            let values = (0..1 << 16)
+                .map(|_| (rng.gen_range(0..=mask), rng.gen_range(0..=mask)))
+                .chain([(mask, mask), (0xFF, 0xFFFF), (0x10000, 0xFF), (mask, 1)]);
+            for (a, b) in values {
+                let (x, y) = (U24::new(a), U24::new(b));
+                let expected = a.wrapping_mul(b) & mask;
+                assert_eq!(expected, u32::from(x * y), "mismatch: `{a} * {b}`");
+                let expected = a.wrapping_add(b) & mask;
+                assert_eq!(expected, u32::from(x + y), "mismatch: `{a} + {b}`");
+                let expected = a.wrapping_sub(b) & mask;
+                assert_eq!(expected, u32::from(x - y), "mismatch: `{a} - {b}`");
+            }
+        }
+
+        #[test]
         fn simulated_u8_addition() {
             for a in 0..=u8::MAX {
                 for b in 0..=u8::MAX {
@@ -170,6 +974,43 @@ pub mod half_precision {
             }
         }
 
+        #[test]
+        fn simulated_u8_subtraction() {
+            for a in 0..=u8::MAX {
+                for b in 0..=u8::MAX {
+                    let expected = U8::new(a.wrapping_sub(b));
+                    let actual = U8::new(a) - U8::new(b);
+                    assert_eq!(expected, actual, "mismatch: `{a} - {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u32_subtraction() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..64)
+                .map(|_| rng.gen())
+                .chain(U32_EDGE_CASES)
+                .chain([0x100, 0x10000, 0x1000000, 0x01010101])
+                .collect::<Vec<u32>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let expected = U32::new(a.wrapping_sub(b));
+                    let actual = U32::new(a) - U32::new(b);
+                    assert_eq!(expected, actual, "mismatch: `{a} - {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u8_negation() {
+            for a in 0..=u8::MAX {
+                let expected = U8::new(a.wrapping_neg());
+                let actual = -U8::new(a);
+                assert_eq!(expected, actual, "mismatch: `-{a}`");
+            }
+        }
+
         #[test]
         fn simulated_u32_addition() {
             for a in U32_EDGE_CASES {
@@ -180,17 +1021,244 @@ pub mod half_precision {
                 }
             }
         }
+
+        #[test]
+        fn simulated_u8_runtime_modulus() {
+            for m in [1, 2, 3, 7, 128, 251, 255, 256] {
+                let modulus = Modulus::new(m);
+                for a in 0..m {
+                    for b in 0..m {
+                        let (a, b) = (a as u8, b as u8);
+                        let expected = U8::new((a as u16 * b as u16 % m) as u8);
+                        let actual = U8::new(a).mul_mod(U8::new(b), modulus);
+                        assert_eq!(expected, actual, "mismatch: `{a} * {b} mod {m}`");
+                        let expected = U8::new(((a as u16 + b as u16) % m) as u8);
+                        let actual = U8::new(a).add_mod(U8::new(b), modulus);
+                        assert_eq!(expected, actual, "mismatch: `{a} + {b} mod {m}`");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u8_saturating() {
+            for a in 0..=u8::MAX {
+                for b in 0..=u8::MAX {
+                    let expected = U8::new(a.saturating_mul(b));
+                    let actual = U8::new(a).saturating_mul(U8::new(b));
+                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+                    let expected = U8::new(a.saturating_add(b));
+                    let actual = U8::new(a).saturating_add(U8::new(b));
+                    assert_eq!(expected, actual, "mismatch: `{a} + {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn native_conversions() {
+            assert_eq!(U8::new(0xAB), U8::from(0xABu8));
+            assert_eq!(Ok(U8::new(0xFF)), U8::try_from(0xFFu64));
+            assert!(U8::try_from(0x100u32).is_err());
+            assert!(U8::try_from(usize::MAX).is_err());
+
+            assert_eq!(U32::new(0xABCD), U32::from(0xABCDu16));
+            assert_eq!(Ok(U32::new(u32::MAX)), U32::try_from(u32::MAX as u64));
+            assert!(U32::try_from(1u64 << 32).is_err());
+
+            assert_eq!(Ok(U24::new(U24::MAX)), U24::try_from(U24::MAX));
+            assert!(U24::try_from(U24::MAX + 1).is_err());
+            assert!(U24::try_from(u32::MAX).is_err());
+        }
+
+        #[test]
+        fn widening_conversions() {
+            for a in 0..=u8::MAX {
+                assert_eq!(U32::new(a as u32), U32::from(U8::new(a)));
+                assert_eq!(U16::new(a as u16), U16::from(U8::new(a)));
+                assert_eq!(U24::new(a as u32), U24::from(U8::new(a)));
+            }
+            assert_eq!(U24::new(0xABCD), U24::from(U16::new(0xABCD)));
+        }
     }
 }
 
 pub mod single_precision {
+    use super::half_precision;
+    use crate::macros::impl_assign_ops;
+    use crate::macros::impl_pod;
     use rand::distributions::Standard;
     use rand::prelude::Distribution;
     use std::ops::Add;
     use std::ops::Mul;
+    use std::ops::Sub;
+
+    /// Describes a u32 stored across two f32 limbs as `hi * 2^LO + lo`
+    /// Where `lo ∈ [0, 2^LO)` and `hi ∈ [0, 2^HI)`
+    ///
+    /// Widths are checked at compile time: both limbs must be non-empty, fit in
+    /// a u32 together and be exactly representable by an f32.
+    ///
+    /// ```compile_fail
+    /// use ark_experimentation::arithmetic::single_precision::LimbSplit;
+    ///
+    /// // 40 bits don't fit in a u32
+    /// let _ = LimbSplit::<20, 20>::LO_MASK;
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub struct LimbSplit<const LO: u32, const HI: u32>;
+
+    impl<const LO: u32, const HI: u32> LimbSplit<LO, HI> {
+        const VALID: () = {
+            assert!(LO > 0 && HI > 0, "limbs must be non-empty");
+            assert!(LO + HI <= u32::BITS, "limbs must fit in a u32");
+            assert!(
+                LO <= f32::MANTISSA_DIGITS && HI <= f32::MANTISSA_DIGITS,
+                "limbs must be exact in an f32"
+            );
+        };
+
+        pub const LO_MASK: u32 = {
+            let () = Self::VALID;
+            ((1u64 << LO) - 1) as u32
+        };
+        pub const HI_MASK: u32 = {
+            let () = Self::VALID;
+            ((1u64 << HI) - 1) as u32
+        };
+
+        /// `2^LO` i.e. the weight of the high limb
+        pub const LO_RADIX: f32 = Self::LO_MASK as f32 + 1.0;
+        pub const LO_RADIX_INV: f32 = 1.0 / Self::LO_RADIX;
+        /// `2^HI` i.e. the modulus of the high limb
+        pub const HI_RADIX: f32 = Self::HI_MASK as f32 + 1.0;
+
+        #[inline]
+        #[must_use]
+        pub const fn split(v: u32) -> [f32; 2] {
+            [
+                (v & Self::LO_MASK) as f32,
+                ((v >> LO) & Self::HI_MASK) as f32,
+            ]
+        }
+
+        #[inline]
+        #[must_use]
+        pub const fn join(limbs: [f32; 2]) -> u32 {
+            limbs[0] as u32 | (limbs[1] as u32) << LO
+        }
+
+        /// `limbs << s` wrapped to `LO + HI` bits. Scaling a limb by a power of
+        /// two is exact, so fract/trunc split it at the limb boundary.
+        fn shl(limbs: [f32; 2], s: u32) -> [f32; 2] {
+            assert!(s < LO + HI, "shift overflow");
+            let [l0, l1] = limbs;
+            let v0 = l0 * pow2(s as i32 - LO as i32);
+            let carry = v0.trunc() * Self::HI_RADIX.recip();
+            let v1 = l1 * pow2(s as i32 - HI as i32);
+            let mut hi = (v1.fract() + carry.fract()) * Self::HI_RADIX;
+            if hi >= Self::HI_RADIX {
+                hi -= Self::HI_RADIX;
+            }
+            [v0.fract() * Self::LO_RADIX, hi]
+        }
+
+        /// `limbs >> s`. Bits of the high limb shifted below its boundary land
+        /// in its fractional part and move to the low limb.
+        fn shr(limbs: [f32; 2], s: u32) -> [f32; 2] {
+            assert!(s < LO + HI, "shift overflow");
+            let [l0, l1] = limbs;
+            let v1 = l1 * pow2(-(s as i32));
+            let lo = (v1.fract() * Self::LO_RADIX).trunc() + (l0 * pow2(-(s as i32))).trunc();
+            [lo, v1.trunc()]
+        }
+
+        /// Applies `op` bit by bit with fract/trunc, `LO + HI` iterations of a
+        /// few flops each. `op` maps bits in `{0, 1}` to a bit.
+        fn bitwise(a: [f32; 2], b: [f32; 2], op: impl Fn(f32, f32) -> f32) -> [f32; 2] {
+            let limb = |a: f32, b: f32, bits: u32| {
+                let (mut a, mut b, mut r, mut weight) = (a, b, 0.0, 1.0);
+                for _ in 0..bits {
+                    let (a_half, b_half) = (a * 0.5, b * 0.5);
+                    let bit = op(a_half.fract() * 2.0, b_half.fract() * 2.0);
+                    r = bit.mul_add(weight, r);
+                    weight *= 2.0;
+                    a = a_half.trunc();
+                    b = b_half.trunc();
+                }
+                r
+            };
+            [limb(a[0], b[0], LO), limb(a[1], b[1], HI)]
+        }
+    }
+
+    /// `2^e` for `e ∈ [-126, 127]`, built from the exponent bits so it is exact
+    #[inline]
+    fn pow2(e: i32) -> f32 {
+        f32::from_bits(((127 + e) as u32) << 23)
+    }
+
+    // bitwise operators over `{0, 1}`-valued floats for `LimbSplit::bitwise`
+    fn and(a: f32, b: f32) -> f32 {
+        a * b
+    }
+
+    fn or(a: f32, b: f32) -> f32 {
+        a + b - a * b
+    }
+
+    fn xor(a: f32, b: f32) -> f32 {
+        a + b - 2.0 * a * b
+    }
+
+    /// Implements `Shl<u32>`, `Shr<u32>`, `BitAnd`, `BitOr` and `BitXor` over
+    /// the limbs of `$t` as described by the [`LimbSplit`] `$split`. `$t` must
+    /// have `limbs` and `from_limbs`.
+    macro_rules! impl_bit_ops {
+        ($t:ty, $split:ty) => {
+            impl std::ops::Shl<u32> for $t {
+                type Output = Self;
+
+                fn shl(self, s: u32) -> Self {
+                    Self::from_limbs(<$split>::shl(self.limbs(), s))
+                }
+            }
+
+            impl std::ops::Shr<u32> for $t {
+                type Output = Self;
+
+                fn shr(self, s: u32) -> Self {
+                    Self::from_limbs(<$split>::shr(self.limbs(), s))
+                }
+            }
+
+            impl std::ops::BitAnd for $t {
+                type Output = Self;
+
+                fn bitand(self, rhs: Self) -> Self {
+                    Self::from_limbs(<$split>::bitwise(self.limbs(), rhs.limbs(), and))
+                }
+            }
+
+            impl std::ops::BitOr for $t {
+                type Output = Self;
+
+                fn bitor(self, rhs: Self) -> Self {
+                    Self::from_limbs(<$split>::bitwise(self.limbs(), rhs.limbs(), or))
+                }
+            }
+
+            impl std::ops::BitXor for $t {
+                type Output = Self;
+
+                fn bitxor(self, rhs: Self) -> Self {
+                    Self::from_limbs(<$split>::bitwise(self.limbs(), rhs.limbs(), xor))
+                }
+            }
+        };
+    }
 
     #[repr(transparent)]
-    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct U16(f32);
 
     impl U16 {
@@ -239,17 +1307,113 @@ pub mod single_precision {
         }
     }
 
+    impl_assign_ops!(U16; AddAssign::add_assign => +, MulAssign::mul_assign => *);
+
+    impl_pod!(U16);
+
+    /// Runtime modulus `m <= 2^16` for [`U16::mul_mod`] and [`U16::add_mod`]
+    /// with its precomputed reciprocal
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Modulus {
+        m: f32,
+        m_inv: f32,
+    }
+
+    impl Modulus {
+        pub fn new(m: u32) -> Self {
+            assert!(m != 0 && m <= 1 << 16, "modulus must be in [1, 2^16]");
+            let m = m as f32;
+            Self { m, m_inv: 1.0 / m }
+        }
+    }
+
+    impl U16 {
+        const MAX: f32 = u16::MAX as f32;
+
+        /// Computes `self + rhs mod m` for `self, rhs < m`
+        pub fn add_mod(self, rhs: Self, m: Modulus) -> Self {
+            debug_assert!(self.0 < m.m && rhs.0 < m.m);
+            let a = self.0 + rhs.0;
+            Self(if a >= m.m { a - m.m } else { a })
+        }
+
+        /// Computes `self * rhs mod m` for `self, rhs < m` with the same
+        /// FMA reduction as [`Mul`] but a runtime modulus
+        pub fn mul_mod(self, rhs: Self, m: Modulus) -> Self {
+            debug_assert!(self.0 < m.m && rhs.0 < m.m);
+            let h = self.0 * rhs.0;
+            let l = self.0.mul_add(rhs.0, -h);
+            let b = h * m.m_inv;
+            let c = b.trunc();
+            let d = (-c).mul_add(m.m, h);
+            let e = d + l;
+            Self(if e >= m.m {
+                e - m.m
+            } else if e < 0.0 {
+                e + m.m
+            } else {
+                e
+            })
+        }
+
+        pub fn saturating_add(self, rhs: Self) -> Self {
+            Self((self.0 + rhs.0).min(Self::MAX))
+        }
+
+        /// The rounded product is compared against `2^16 - 1` which is exact
+        /// so the rounding can't cross the saturation boundary
+        pub fn saturating_mul(self, rhs: Self) -> Self {
+            Self((self.0 * rhs.0).min(Self::MAX))
+        }
+    }
+
+    impl From<U16> for u16 {
+        #[inline]
+        fn from(v: U16) -> u16 {
+            v.0 as u16
+        }
+    }
+
+    impl Distribution<U16> for Standard {
+        fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U16 {
+            U16::new(self.sample(rng))
+        }
+    }
+
+    impl_native_conversions!(U16, u16, from: [u8, u16], try_from: [u32, u64, usize]);
+    impl_ord!(U16, u16);
+    impl_display!(U16, u16);
+    impl_int_num_traits!(U16, u16, max: u16::MAX);
+    impl_pow!(U16);
+
+    impl From<half_precision::U8> for U16 {
+        #[inline]
+        fn from(value: half_precision::U8) -> Self {
+            Self::new(u8::from(value).into())
+        }
+    }
+
     /// Stores a u32 across two f32s as `x1 * 2^16 + x0`
     /// Where `x0 ∈ [0, 2^16)` and `x1 ∈ [0, 2^16)`
     #[repr(transparent)]
-    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct U32([U16; 2]);
 
+    type U32Split = LimbSplit<16, 16>;
+
     impl U32 {
         #[inline]
         #[must_use]
         pub const fn new(v: u32) -> Self {
-            Self([U16::new(v as u16), U16::new((v >> 16) as u16)])
+            let [lo, hi] = U32Split::split(v);
+            Self([U16(lo), U16(hi)])
+        }
+    }
+
+    impl From<U32> for u32 {
+        #[inline]
+        fn from(v: U32) -> u32 {
+            U32Split::join([v.0[0].0, v.0[1].0])
         }
     }
 
@@ -273,6 +1437,36 @@ pub mod single_precision {
         }
     }
 
+    impl U32 {
+        /// Computes `self - rhs` borrowing across the limbs. Returns the
+        /// wrapped result and whether the subtraction underflowed, like
+        /// [`u32::overflowing_sub`].
+        pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+            let mut l0 = self.0[0].0 - rhs.0[0].0;
+            let mut l1 = self.0[1].0 - rhs.0[1].0;
+
+            if l0 < 0.0 {
+                l0 += U16::MODULUS;
+                l1 -= 1.0;
+            }
+
+            let borrow = l1 < 0.0;
+            if borrow {
+                l1 += U16::MODULUS;
+            }
+
+            (Self([U16(l0), U16(l1)]), borrow)
+        }
+    }
+
+    impl Sub for U32 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            self.overflowing_sub(rhs).0
+        }
+    }
+
     impl Mul for U32 {
         type Output = Self;
         /// Adapted from: https://github.com/calccrypto/uint128_t/blob/master/uint128_t.cpp
@@ -395,14 +1589,14 @@ pub mod single_precision {
             // fourth row
             first32 = prod[3][3].fract().mul_add(256.0, first32);
 
-            let v0 = third32.mul_add(256.0, fourth32) / 65536.0;
-            let v1 = first32.mul_add(256.0, second32) / 65536.0;
+            let v0 = third32.mul_add(256.0, fourth32) * U32Split::LO_RADIX_INV;
+            let v1 = first32.mul_add(256.0, second32) * U32Split::LO_RADIX_INV;
 
-            let l0 = v0.fract() * 65536.0;
-            let mut l1 = v1.fract().mul_add(65536.0, v0.trunc());
+            let l0 = v0.fract() * U32Split::LO_RADIX;
+            let mut l1 = v1.fract().mul_add(U32Split::LO_RADIX, v0.trunc());
 
-            if l1 >= 65536.0 {
-                l1 -= 65536.0;
+            if l1 >= U32Split::HI_RADIX {
+                l1 -= U32Split::HI_RADIX;
             }
 
             // combine components
@@ -410,32 +1604,239 @@ pub mod single_precision {
         }
     }
 
+    // The bitwise ops cost 32 iterations of fract/trunc bit extraction, the
+    // shifts a handful of flops
+    impl U32 {
+        #[inline]
+        fn limbs(self) -> [f32; 2] {
+            [self.0[0].0, self.0[1].0]
+        }
+
+        #[inline]
+        fn from_limbs(limbs: [f32; 2]) -> Self {
+            Self(limbs.map(U16))
+        }
+    }
+
+    impl_bit_ops!(U32, U32Split);
+
+    impl_assign_ops!(
+        U32;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_pod!(U32);
+
     impl Distribution<U32> for Standard {
         fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U32 {
             U32::new(self.sample(rng))
         }
     }
 
-    /// Stores a u32 across two f32s as `x1 * 2^11 + x0`
-    /// Where `x0 ∈ [0, 2^11)` and `x1 ∈ [0, 2^21)`
-    #[repr(transparent)]
-    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
-    pub struct U31([f32; 2]);
-
-    impl U31 {
-        const MASK_11_BITS: f32 = 0b11111111111 as f32;
-        const MASK_20_BITS: f32 = 0b11111111111111111111 as f32;
+    impl_native_conversions!(U32, u32, from: [u8, u16, u32], try_from: [u64, usize]);
+    impl_ord!(U32, u32);
+    impl_display!(U32, u32);
+    impl_int_num_traits!(U32, u32, max: u32::MAX);
+    impl_pow!(U32);
 
+    impl From<U16> for U32 {
+        #[inline]
+        fn from(value: U16) -> Self {
+            Self([value, U16::ZERO])
+        }
+    }
+
+    impl From<half_precision::U8> for U32 {
+        #[inline]
+        fn from(value: half_precision::U8) -> Self {
+            U16::from(value).into()
+        }
+    }
+
+    /// Stores a u64 across four f32s as `x3 * 2^48 + x2 * 2^32 + x1 * 2^16 +
+    /// x0` Where `xi ∈ [0, 2^16)`
+    #[repr(transparent)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct U64([U16; 4]);
+
+    impl U64 {
         #[inline]
         #[must_use]
-        pub const fn new(v: u32) -> Self {
+        pub const fn new(v: u64) -> Self {
             Self([
-                (v & Self::MASK_11_BITS as u32) as f32,
-                ((v >> 11) & Self::MASK_20_BITS as u32) as f32,
+                U16::new(v as u16),
+                U16::new((v >> 16) as u16),
+                U16::new((v >> 32) as u16),
+                U16::new((v >> 48) as u16),
             ])
         }
     }
 
+    impl From<U64> for u64 {
+        #[inline]
+        fn from(v: U64) -> u64 {
+            v.0.iter()
+                .rev()
+                .fold(0, |acc, limb| acc << 16 | limb.0 as u64)
+        }
+    }
+
+    impl U16 {
+        /// Computes the full product `self * rhs` as `(lo, hi)`. The rounded
+        /// product keeps the high half and the FMA error term corrects the low
+        /// half, borrowing or carrying into the high half.
+        #[inline]
+        fn widening_mul(self, rhs: Self) -> (f32, f32) {
+            let h = self.0 * rhs.0;
+            let l = self.0.mul_add(rhs.0, -h);
+            let mut hi = (h * Self::MODULUS_INV).trunc();
+            let mut lo = (-hi).mul_add(Self::MODULUS, h) + l;
+            if lo < 0.0 {
+                lo += Self::MODULUS;
+                hi -= 1.0;
+            } else if lo >= Self::MODULUS {
+                lo -= Self::MODULUS;
+                hi += 1.0;
+            }
+            (lo, hi)
+        }
+    }
+
+    impl Add for U64 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let mut carry = 0.0;
+            Self(std::array::from_fn(|i| {
+                let mut l = self.0[i].0 + rhs.0[i].0 + carry;
+                carry = 0.0;
+                if l >= U16::MODULUS {
+                    l -= U16::MODULUS;
+                    carry = 1.0;
+                }
+                U16(l)
+            }))
+        }
+    }
+
+    impl U64 {
+        /// Computes `self - rhs` borrowing across the limbs. Returns the
+        /// wrapped result and whether the subtraction underflowed, like
+        /// [`u64::overflowing_sub`].
+        pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+            let mut borrow = 0.0;
+            let limbs = std::array::from_fn(|i| {
+                let mut l = self.0[i].0 - rhs.0[i].0 - borrow;
+                borrow = 0.0;
+                if l < 0.0 {
+                    l += U16::MODULUS;
+                    borrow = 1.0;
+                }
+                U16(l)
+            });
+            (Self(limbs), borrow != 0.0)
+        }
+    }
+
+    impl Sub for U64 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            self.overflowing_sub(rhs).0
+        }
+    }
+
+    impl Mul for U64 {
+        type Output = Self;
+
+        /// Schoolbook over the limbs, dropping the partial products of weight
+        /// `2^64` and above. Each column stays below `2^20` so it is exact and
+        /// the carries are propagated with fract/trunc at the end.
+        fn mul(self, rhs: Self) -> Self::Output {
+            let mut columns = [0.0; 4];
+            for i in 0..4 {
+                for j in 0..4 - i {
+                    let (lo, hi) = self.0[i].widening_mul(rhs.0[j]);
+                    columns[i + j] += lo;
+                    if i + j < 3 {
+                        columns[i + j + 1] += hi;
+                    }
+                }
+            }
+
+            let mut carry = 0.0;
+            Self(columns.map(|column| {
+                let v = (column + carry) * U16::MODULUS_INV;
+                carry = v.trunc();
+                U16(v.fract() * U16::MODULUS)
+            }))
+        }
+    }
+
+    impl_assign_ops!(
+        U64;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_pod!(U64);
+
+    impl Distribution<U64> for Standard {
+        fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U64 {
+            U64::new(self.sample(rng))
+        }
+    }
+
+    impl_native_conversions!(U64, u64, from: [u8, u16, u32, u64], try_from: [u128, usize]);
+    impl_ord!(U64, u64);
+    impl_display!(U64, u64);
+    impl_int_num_traits!(U64, u64, max: u64::MAX);
+    impl_pow!(U64);
+
+    impl From<U32> for U64 {
+        #[inline]
+        fn from(value: U32) -> Self {
+            let [lo, hi] = value.0;
+            Self([lo, hi, U16::ZERO, U16::ZERO])
+        }
+    }
+
+    /// Stores a u31 across two f32s as `x1 * 2^11 + x0`
+    /// Where `x0 ∈ [0, 2^11)` and `x1 ∈ [0, 2^20)`
+    #[repr(transparent)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct U31([f32; 2]);
+
+    type U31Split = LimbSplit<11, 20>;
+
+    // `U31::HI_HI_SCALE` must multiply to `2^LO`
+    const _: () = assert!(64 * 32 == U31Split::LO_MASK + 1);
+
+    impl U31 {
+        /// Only the low `HI - LO` bits of each high limb survive the `x1 * y1`
+        /// term, which carries a weight of `2^(2 * LO)`
+        const HI_HI_RADIX: f32 = U31Split::HI_RADIX * U31Split::LO_RADIX_INV;
+        /// Splits `2^LO` across both operands of the `x1 * y1` term so each
+        /// factor stays below `2^HI`
+        const HI_HI_SCALE: [f32; 2] = [64.0, 32.0];
+
+        #[inline]
+        #[must_use]
+        pub const fn new(v: u32) -> Self {
+            Self(U31Split::split(v))
+        }
+    }
+
+    impl From<U31> for u32 {
+        #[inline]
+        fn from(v: U31) -> u32 {
+            U31Split::join(v.0)
+        }
+    }
+
     impl Add for U31 {
         type Output = Self;
 
@@ -443,27 +1844,56 @@ pub mod single_precision {
             let mut l0 = self.0[0] + rhs.0[0];
             let mut l1 = self.0[1] + rhs.0[1];
 
-            if l0 >= (Self::MASK_11_BITS + 1.0) {
-                l0 -= Self::MASK_11_BITS + 1.0;
+            if l0 >= U31Split::LO_RADIX {
+                l0 -= U31Split::LO_RADIX;
                 l1 += 1.0;
             }
 
-            if l1 >= (Self::MASK_20_BITS + 1.0) {
-                l1 -= Self::MASK_20_BITS + 1.0;
+            if l1 >= U31Split::HI_RADIX {
+                l1 -= U31Split::HI_RADIX;
             }
 
             Self([l0, l1])
         }
     }
 
+    impl U31 {
+        /// Computes `self - rhs` borrowing across the limbs. Returns the result
+        /// wrapped to 31 bits and whether the subtraction underflowed.
+        pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+            let mut l0 = self.0[0] - rhs.0[0];
+            let mut l1 = self.0[1] - rhs.0[1];
+
+            if l0 < 0.0 {
+                l0 += U31Split::LO_RADIX;
+                l1 -= 1.0;
+            }
+
+            let borrow = l1 < 0.0;
+            if borrow {
+                l1 += U31Split::HI_RADIX;
+            }
+
+            (Self([l0, l1]), borrow)
+        }
+    }
+
+    impl Sub for U31 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            self.overflowing_sub(rhs).0
+        }
+    }
+
     impl Mul for U31 {
         type Output = Self;
 
         /// Adapted from: https://github.com/calccrypto/uint128_t/blob/master/uint128_t.cpp
         fn mul(self, rhs: Self) -> Self {
-            let tmp0 = (self.0[0] * rhs.0[0]) / (Self::MASK_11_BITS + 1.0);
+            let tmp0 = (self.0[0] * rhs.0[0]) / U31Split::LO_RADIX;
 
-            let l0 = tmp0.fract() * (Self::MASK_11_BITS + 1.0);
+            let l0 = tmp0.fract() * U31Split::LO_RADIX;
             let mut l1 = tmp0.trunc();
 
             /// Multiplication `a * b % 2^20`
@@ -478,38 +1908,138 @@ pub mod single_precision {
                 d + l
             }
 
-            l1 += mul::<{ Self::MASK_20_BITS as u32 + 1 }>(self.0[0], rhs.0[1]);
-            l1 += mul::<{ Self::MASK_20_BITS as u32 + 1 }>(self.0[1], rhs.0[0]);
+            l1 += mul::<{ U31Split::HI_MASK + 1 }>(self.0[0], rhs.0[1]);
+            l1 += mul::<{ U31Split::HI_MASK + 1 }>(self.0[1], rhs.0[0]);
 
-            if l1 >= (Self::MASK_20_BITS + 1.0) {
-                l1 -= Self::MASK_20_BITS + 1.0;
+            if l1 >= U31Split::HI_RADIX {
+                l1 -= U31Split::HI_RADIX;
             }
 
-            let tmp_lha = (self.0[1] / 512.0).fract() * (512.0 * 64.0);
-            let tmp_rhs = (rhs.0[1] / 512.0).fract() * (512.0 * 32.0);
-            l1 += mul::<{ Self::MASK_20_BITS as u32 + 1 }>(tmp_lha, tmp_rhs);
+            let tmp_lha = (self.0[1] / Self::HI_HI_RADIX).fract()
+                * (Self::HI_HI_RADIX * Self::HI_HI_SCALE[0]);
+            let tmp_rhs =
+                (rhs.0[1] / Self::HI_HI_RADIX).fract() * (Self::HI_HI_RADIX * Self::HI_HI_SCALE[1]);
+            l1 += mul::<{ U31Split::HI_MASK + 1 }>(tmp_lha, tmp_rhs);
 
-            if l1 >= (Self::MASK_20_BITS + 1.0) {
-                l1 -= Self::MASK_20_BITS + 1.0;
+            if l1 >= U31Split::HI_RADIX {
+                l1 -= U31Split::HI_RADIX;
             }
 
             U31([l0, l1])
         }
     }
 
+    impl_assign_ops!(
+        U31;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_pod!(U31);
+
+    impl U31 {
+        #[inline]
+        fn limbs(self) -> [f32; 2] {
+            self.0
+        }
+
+        #[inline]
+        fn from_limbs(limbs: [f32; 2]) -> Self {
+            Self(limbs)
+        }
+    }
+
+    impl_bit_ops!(U31, U31Split);
+
     impl Distribution<U31> for Standard {
         fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U31 {
             U31::new(self.sample(rng))
         }
     }
 
+    // i32 has the same non-negative range as U31
+    impl_native_conversions!(U31, i32, from: [u8, u16], try_from: [u32, u64, usize]);
+    impl_ord!(U31, u32);
+    impl_display!(U31, u32);
+    impl_int_num_traits!(U31, u32, max: i32::MAX as u32);
+    impl_pow!(U31);
+
+    impl From<U16> for U31 {
+        #[inline]
+        fn from(value: U16) -> Self {
+            Self::new(u16::from(value).into())
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
+        use crate::arithmetic::assert_native_order;
+        use crate::arithmetic::assert_num_traits;
+        use crate::arithmetic::assert_pow;
         use rand::rngs::StdRng;
         use rand::Rng;
         use rand::SeedableRng;
 
+        #[test]
+        fn ordering() {
+            assert_native_order(&[0, 1, 0xFF, 0x100, 0xFFFF], U16::new);
+            let u32s = [0, 1, 0xFFFF, 0x10000, 0x1FFFF, 0xFFFF0000, u32::MAX];
+            assert_native_order(&u32s, U32::new);
+            let u31s = [0, 1, 0x7FF, 0x800, 0xFFF, 0x7FFFF800, MASK_31_BITS];
+            assert_native_order(&u31s, U31::new);
+            let u64s = [
+                0,
+                1,
+                0xFFFF,
+                0x1_0000,
+                0xFFFF_FFFF,
+                0x1_0000_0000,
+                1 << 48,
+                u64::MAX,
+            ];
+            assert_native_order(&u64s, U64::new);
+        }
+
+        #[test]
+        fn num_traits() {
+            let u16s = [0, 1, 2, 0xFF, 0x100, 0x7FFF, 0x8000, u16::MAX];
+            assert_num_traits(&u16s, u16::MAX, U16::new);
+            let u32s = [0, 1, 2, 0xFFFF, 0x10000, 0x7FFF_FFFF, 0x8000_0000, u32::MAX];
+            assert_num_traits(&u32s, u32::MAX, U32::new);
+            let u31s = [
+                0,
+                1,
+                2,
+                0x7FF,
+                0x800,
+                0xFFFF,
+                0x10000,
+                0x4000_0000,
+                MASK_31_BITS,
+            ];
+            assert_num_traits(&u31s, MASK_31_BITS, U31::new);
+            let u64s = [0, 1, 2, 0xFFFF_FFFF, 1 << 32, 1 << 63, u64::MAX];
+            assert_num_traits(&u64s, u64::MAX, U64::new);
+        }
+
+        #[test]
+        fn pow() {
+            assert_pow(16, |v| U16::new(v as u16), U16::pow);
+            assert_pow(32, |v| U32::new(v as u32), U32::pow);
+            assert_pow(31, |v| U31::new(v as u32), U31::pow);
+            assert_pow(64, |v| U64::new(v as u64), U64::pow);
+        }
+
+        #[test]
+        fn display() {
+            assert_eq!("65535", U16::new(u16::MAX).to_string());
+            assert_eq!("4294967295", U32::new(u32::MAX).to_string());
+            assert_eq!("2147483647", U31::new(MASK_31_BITS).to_string());
+            assert_eq!(u64::MAX.to_string(), U64::new(u64::MAX).to_string());
+        }
+
         const MASK_31_BITS: u32 = 0b1111111111111111111111111111111;
 
         const U16_EDGE_CASES: [u16; 10] = [0, 1, 2, 3, 5, 7, 8, 0xFF, 0xFF00, 0xFFFF];
@@ -530,6 +2060,59 @@ pub mod single_precision {
             u32::MAX >> 1,
         ];
 
+        const U64_EDGE_CASES: [u64; 10] = [
+            0,
+            1,
+            0xFFFF,
+            0x1_0000,
+            0xFFFF_FFFF,
+            0x1_0000_0001,
+            0xFFFF_0000_FFFF_0000,
+            1 << 63,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+
+        #[test]
+        fn simulated_u64_multiplication() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..1024)
+                .map(|_| rng.gen())
+                .chain(U64_EDGE_CASES)
+                .collect::<Vec<u64>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let expected = U64::new(a.wrapping_mul(b));
+                    let actual = U64::new(a) * U64::new(b);
+                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u64_addition_and_subtraction() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..256)
+                .map(|_| rng.gen())
+                .chain(U64_EDGE_CASES)
+                .collect::<Vec<u64>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let expected = U64::new(a.wrapping_add(b));
+                    let actual = U64::new(a) + U64::new(b);
+                    assert_eq!(expected, actual, "mismatch: `{a} + {b}`");
+                    let (expected, borrow) = a.overflowing_sub(b);
+                    let actual = U64::new(a).overflowing_sub(U64::new(b));
+                    assert_eq!(
+                        (U64::new(expected), borrow),
+                        actual,
+                        "mismatch: `{a} - {b}`"
+                    );
+                }
+                assert_eq!(a, u64::from(U64::new(a)));
+            }
+        }
+
         #[test]
         fn simulated_u16_multiplication() {
             for a in U16_EDGE_CASES {
@@ -601,17 +2184,210 @@ pub mod single_precision {
                 }
             }
         }
+
+        #[test]
+        fn simulated_u32_subtraction() {
+            for a in U32_EDGE_CASES {
+                for b in U32_EDGE_CASES {
+                    let (expected, borrow) = a.overflowing_sub(b);
+                    let actual = U32::new(a).overflowing_sub(U32::new(b));
+                    assert_eq!(
+                        (U32::new(expected), borrow),
+                        actual,
+                        "mismatch: `{a} - {b}`"
+                    );
+                    assert_eq!(U32::new(expected), U32::new(a) - U32::new(b));
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u31_subtraction() {
+            for a in U31_EDGE_CASES {
+                for b in U31_EDGE_CASES {
+                    let (expected, borrow) = a.overflowing_sub(b);
+                    let expected = U31::new(expected & MASK_31_BITS);
+                    let actual = U31::new(a).overflowing_sub(U31::new(b));
+                    assert_eq!((expected, borrow), actual, "mismatch: `{a} - {b}`");
+                    assert_eq!(expected, U31::new(a) - U31::new(b));
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u32_bit_ops() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..64)
+                .map(|_| rng.gen())
+                .chain(U32_EDGE_CASES)
+                .collect::<Vec<u32>>();
+            for &a in &edge_cases {
+                for s in 0..32 {
+                    assert_eq!(U32::new(a << s), U32::new(a) << s, "mismatch: `{a} << {s}`");
+                    assert_eq!(U32::new(a >> s), U32::new(a) >> s, "mismatch: `{a} >> {s}`");
+                }
+                for &b in &edge_cases {
+                    let (x, y) = (U32::new(a), U32::new(b));
+                    assert_eq!(U32::new(a & b), x & y, "mismatch: `{a} & {b}`");
+                    assert_eq!(U32::new(a | b), x | y, "mismatch: `{a} | {b}`");
+                    assert_eq!(U32::new(a ^ b), x ^ y, "mismatch: `{a} ^ {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u31_bit_ops() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..64)
+                .map(|_| rng.gen::<u32>() & MASK_31_BITS)
+                .chain(U31_EDGE_CASES)
+                .collect::<Vec<u32>>();
+            for &a in &edge_cases {
+                for s in 0..31 {
+                    let expected = U31::new((a << s) & MASK_31_BITS);
+                    assert_eq!(expected, U31::new(a) << s, "mismatch: `{a} << {s}`");
+                    assert_eq!(U31::new(a >> s), U31::new(a) >> s, "mismatch: `{a} >> {s}`");
+                }
+                for &b in &edge_cases {
+                    let (x, y) = (U31::new(a), U31::new(b));
+                    assert_eq!(U31::new(a & b), x & y, "mismatch: `{a} & {b}`");
+                    assert_eq!(U31::new(a | b), x | y, "mismatch: `{a} | {b}`");
+                    assert_eq!(U31::new(a ^ b), x ^ y, "mismatch: `{a} ^ {b}`");
+                }
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "shift overflow")]
+        fn simulated_u32_shift_overflow() {
+            let _ = U32::new(1) << 32;
+        }
+
+        #[test]
+        fn limb_split_constants() {
+            assert_eq!(0x7FF, U31Split::LO_MASK);
+            assert_eq!(0xFFFFF, U31Split::HI_MASK);
+            assert_eq!(2048.0, U31Split::LO_RADIX);
+            assert_eq!(1048576.0, U31Split::HI_RADIX);
+            assert_eq!(512.0, U31::HI_HI_RADIX);
+            assert_eq!(0xFFFF, U32Split::LO_MASK);
+            assert_eq!(0xFFFF, U32Split::HI_MASK);
+            assert_eq!(U16::MODULUS, U32Split::LO_RADIX);
+            assert_eq!(U16::MODULUS, U32Split::HI_RADIX);
+            assert_eq!(u32::MAX, LimbSplit::<8, 24>::join([255.0, 16777215.0]));
+        }
+
+        #[test]
+        fn limb_split_round_trip() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let limb_boundaries = [0x7FF, 0x800, 0x7FFFF800, 0xFFFF, 0x10000, 0xFFFF0000];
+            let values = (0..1024)
+                .map(|_| rng.gen::<u32>())
+                .chain(U32_EDGE_CASES)
+                .chain(limb_boundaries)
+                .collect::<Vec<u32>>();
+            for v in values {
+                assert_eq!(v, u32::from(U32::new(v)), "mismatch: `{v}`");
+                assert_eq!(v & MASK_31_BITS, u32::from(U31::new(v)), "mismatch: `{v}`");
+            }
+        }
+
+        #[test]
+        fn limb_split_boundaries() {
+            assert_eq!([2047.0, 0.0], U31Split::split(0x7FF));
+            assert_eq!([0.0, 1.0], U31Split::split(0x800));
+            assert_eq!([0.0, 1048575.0], U31Split::split(0x7FFFF800));
+            assert_eq!([2047.0, 1048575.0], U31Split::split(u32::MAX));
+            assert_eq!([65535.0, 0.0], U32Split::split(0xFFFF));
+            assert_eq!([0.0, 1.0], U32Split::split(0x10000));
+            assert_eq!([65535.0, 65535.0], U32Split::split(u32::MAX));
+        }
+
+        #[test]
+        fn simulated_u16_runtime_modulus() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            for m in [1, 3, 257, 4093, 65521, 65535, 65536] {
+                let modulus = Modulus::new(m);
+                let edge_cases = (0..256)
+                    .map(|_| rng.gen::<u32>() % m)
+                    .chain([0, m / 2, m - 1])
+                    .collect::<Vec<u32>>();
+                for &a in &edge_cases {
+                    for &b in &edge_cases {
+                        let (x, y) = (U16::new(a as u16), U16::new(b as u16));
+                        let expected = U16::new((a as u64 * b as u64 % m as u64) as u16);
+                        let actual = x.mul_mod(y, modulus);
+                        assert_eq!(expected, actual, "mismatch: `{a} * {b} mod {m}`");
+                        let expected = U16::new(((a + b) % m) as u16);
+                        let actual = x.add_mod(y, modulus);
+                        assert_eq!(expected, actual, "mismatch: `{a} + {b} mod {m}`");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u16_saturating() {
+            for a in U16_EDGE_CASES.into_iter().chain([0x100, 0x101, 0xFFFE]) {
+                for b in U16_EDGE_CASES.into_iter().chain([0xFF, 0x100, 0x101]) {
+                    let expected = U16::new(a.saturating_mul(b));
+                    let actual = U16::new(a).saturating_mul(U16::new(b));
+                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+                    let expected = U16::new(a.saturating_add(b));
+                    let actual = U16::new(a).saturating_add(U16::new(b));
+                    assert_eq!(expected, actual, "mismatch: `{a} + {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn native_conversions() {
+            assert_eq!(U16::new(0xAB), U16::from(0xABu8));
+            assert_eq!(Ok(U16::new(u16::MAX)), U16::try_from(u16::MAX as usize));
+            assert!(U16::try_from(1u32 << 16).is_err());
+
+            for a in U32_EDGE_CASES {
+                assert_eq!(Ok(U32::new(a)), U32::try_from(a as u64));
+                assert_eq!(U32::new(a), U32::from(a));
+            }
+            assert!(U32::try_from(1u64 << 32).is_err());
+
+            for a in U31_EDGE_CASES {
+                assert_eq!(Ok(U31::new(a)), U31::try_from(a));
+                assert_eq!(Ok(U31::new(a)), U31::try_from(a as u64));
+            }
+            assert!(U31::try_from(1u32 << 31).is_err());
+            assert!(U31::try_from(u64::MAX).is_err());
+        }
+
+        #[test]
+        fn widening_conversions() {
+            for a in 0..=u8::MAX {
+                let v = half_precision::U8::new(a);
+                assert_eq!(U16::new(a as u16), U16::from(v));
+                assert_eq!(U32::new(a as u32), U32::from(v));
+            }
+            for a in U16_EDGE_CASES {
+                assert_eq!(U32::new(a as u32), U32::from(U16::new(a)));
+                assert_eq!(U31::new(a as u32), U31::from(U16::new(a)));
+            }
+        }
     }
 }
 
 pub mod double_precision {
+    use super::single_precision;
+    use crate::macros::impl_assign_ops;
+    use crate::macros::impl_pod;
     use rand::distributions::Standard;
     use rand::prelude::Distribution;
     use std::ops::Add;
     use std::ops::Mul;
+    use std::ops::Neg;
+    use std::ops::Sub;
 
     #[repr(transparent)]
-    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct U32(f64);
 
     impl U32 {
@@ -660,9 +2436,615 @@ pub mod double_precision {
         }
     }
 
+    impl_assign_ops!(U32; AddAssign::add_assign => +, MulAssign::mul_assign => *);
+
+    impl_pod!(U32);
+
+    impl From<U32> for u32 {
+        #[inline]
+        fn from(v: U32) -> u32 {
+            v.0 as u32
+        }
+    }
+
+    /// Runtime modulus `m <= 2^32` for [`U32::mul_mod`] and [`U32::add_mod`]
+    /// with its precomputed reciprocal
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Modulus {
+        m: f64,
+        m_inv: f64,
+    }
+
+    impl Modulus {
+        pub fn new(m: u64) -> Self {
+            assert!(m != 0 && m <= 1 << 32, "modulus must be in [1, 2^32]");
+            let m = m as f64;
+            Self { m, m_inv: 1.0 / m }
+        }
+    }
+
+    impl U32 {
+        const MAX: f64 = u32::MAX as f64;
+
+        /// Computes `self + rhs mod m` for `self, rhs < m`
+        pub fn add_mod(self, rhs: Self, m: Modulus) -> Self {
+            debug_assert!(self.0 < m.m && rhs.0 < m.m);
+            let a = self.0 + rhs.0;
+            Self(if a >= m.m { a - m.m } else { a })
+        }
+
+        /// Computes `self * rhs mod m` for `self, rhs < m` with the same
+        /// FMA reduction as [`Mul`] but a runtime modulus
+        pub fn mul_mod(self, rhs: Self, m: Modulus) -> Self {
+            debug_assert!(self.0 < m.m && rhs.0 < m.m);
+            let h = self.0 * rhs.0;
+            let l = self.0.mul_add(rhs.0, -h);
+            let b = h * m.m_inv;
+            let c = b.trunc();
+            let d = (-c).mul_add(m.m, h);
+            let e = d + l;
+            Self(if e >= m.m {
+                e - m.m
+            } else if e < 0.0 {
+                e + m.m
+            } else {
+                e
+            })
+        }
+
+        pub fn saturating_add(self, rhs: Self) -> Self {
+            Self((self.0 + rhs.0).min(Self::MAX))
+        }
+
+        /// The rounded product is compared against `2^32 - 1` which is exact
+        /// so the rounding can't cross the saturation boundary
+        pub fn saturating_mul(self, rhs: Self) -> Self {
+            Self((self.0 * rhs.0).min(Self::MAX))
+        }
+    }
+
     impl Distribution<U32> for Standard {
         fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U32 {
             U32::new(self.sample(rng))
         }
     }
+
+    impl_native_conversions!(U32, u32, from: [u8, u16, u32], try_from: [u64, usize]);
+    impl_ord!(U32, u32);
+    impl_display!(U32, u32);
+    impl_int_num_traits!(U32, u32, max: u32::MAX);
+    impl_pow!(U32);
+
+    impl From<single_precision::U16> for U32 {
+        #[inline]
+        fn from(value: single_precision::U16) -> Self {
+            Self::new(u16::from(value).into())
+        }
+    }
+
+    /// i32 with two's-complement wrapping semantics, stored as the bits of a
+    /// [`U32`] so it reuses the unsigned limb arithmetic
+    #[repr(transparent)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct I32(U32);
+
+    impl I32 {
+        const SIGN: f64 = 2147483648.0;
+
+        #[inline]
+        #[must_use]
+        pub const fn new(v: i32) -> Self {
+            Self(U32::new(v as u32))
+        }
+
+        /// Value in `[-2^31, 2^31)`
+        #[inline]
+        fn signed(self) -> f64 {
+            let v = self.0 .0;
+            if v >= Self::SIGN {
+                v - U32::MODULUS
+            } else {
+                v
+            }
+        }
+
+        #[inline]
+        pub fn is_negative(self) -> bool {
+            self.0 .0 >= Self::SIGN
+        }
+    }
+
+    impl From<I32> for i32 {
+        #[inline]
+        fn from(v: I32) -> i32 {
+            v.signed() as i32
+        }
+    }
+
+    impl Add for I32 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            Self(self.0 + rhs.0)
+        }
+    }
+
+    impl Neg for I32 {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            let v = self.0 .0;
+            Self(U32(if v == 0.0 { 0.0 } else { U32::MODULUS - v }))
+        }
+    }
+
+    impl Sub for I32 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            self + -rhs
+        }
+    }
+
+    impl Mul for I32 {
+        type Output = Self;
+
+        /// The low 32 bits of a product don't depend on the signedness of its
+        /// factors
+        fn mul(self, rhs: Self) -> Self {
+            Self(self.0 * rhs.0)
+        }
+    }
+
+    impl_ord!(I32, i32);
+    impl_display!(I32, i32);
+    impl_int_num_traits!(I32, i32, max: i32::MAX);
+    impl_pow!(I32);
+
+    impl_assign_ops!(
+        I32;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_pod!(I32);
+
+    impl Distribution<I32> for Standard {
+        fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> I32 {
+            I32::new(self.sample(rng))
+        }
+    }
+
+    impl_native_conversions!(I32, i32, from: [i8, i16, i32, u8, u16], try_from: [i64, isize, u32]);
+
+    /// Stores a u64 across two f64s as `x1 * 2^32 + x0`
+    /// Where `x0 ∈ [0, 2^32)` and `x1 ∈ [0, 2^32)`
+    #[repr(transparent)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct U64([U32; 2]);
+
+    impl U64 {
+        #[inline]
+        #[must_use]
+        pub const fn new(v: u64) -> Self {
+            Self([U32(v as u32 as f64), U32((v >> 32) as f64)])
+        }
+    }
+
+    impl From<U64> for u64 {
+        #[inline]
+        fn from(v: U64) -> u64 {
+            v.0[0].0 as u64 | (v.0[1].0 as u64) << 32
+        }
+    }
+
+    impl U32 {
+        /// Computes the full product `self * rhs` as `(lo, hi)`. The rounded
+        /// product keeps the high half and the FMA error term corrects the low
+        /// half, borrowing or carrying into the high half.
+        fn widening_mul(self, rhs: Self) -> (Self, Self) {
+            let h = self.0 * rhs.0;
+            let l = self.0.mul_add(rhs.0, -h);
+            let mut hi = (h * Self::MODULUS_INV).trunc();
+            let mut lo = (-hi).mul_add(Self::MODULUS, h) + l;
+            if lo < 0.0 {
+                lo += Self::MODULUS;
+                hi -= 1.0;
+            } else if lo >= Self::MODULUS {
+                lo -= Self::MODULUS;
+                hi += 1.0;
+            }
+            (Self(lo), Self(hi))
+        }
+    }
+
+    impl Add for U64 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let mut l0 = self.0[0].0 + rhs.0[0].0;
+            let mut l1 = self.0[1].0 + rhs.0[1].0;
+
+            if l0 >= U32::MODULUS {
+                l0 -= U32::MODULUS;
+                l1 += 1.0;
+            }
+
+            if l1 >= U32::MODULUS {
+                l1 -= U32::MODULUS;
+            }
+
+            Self([U32(l0), U32(l1)])
+        }
+    }
+
+    impl Mul for U64 {
+        type Output = Self;
+
+        /// Schoolbook over the limbs. Only the low half of the cross terms
+        /// survives and `x1 * y1` wraps out entirely.
+        fn mul(self, rhs: Self) -> Self {
+            let [x0, x1] = self.0;
+            let [y0, y1] = rhs.0;
+            let (l0, carry) = x0.widening_mul(y0);
+            let l1 = carry + x0 * y1 + x1 * y0;
+            Self([l0, l1])
+        }
+    }
+
+    impl_assign_ops!(U64; AddAssign::add_assign => +, MulAssign::mul_assign => *);
+
+    impl_pod!(U64);
+
+    impl Distribution<U64> for Standard {
+        fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U64 {
+            U64::new(self.sample(rng))
+        }
+    }
+
+    impl_native_conversions!(U64, u64, from: [u8, u16, u32, u64], try_from: [u128, usize]);
+    impl_ord!(U64, u64);
+    impl_display!(U64, u64);
+    impl_int_num_traits!(U64, u64, max: u64::MAX);
+    impl_pow!(U64);
+
+    impl From<U32> for U64 {
+        #[inline]
+        fn from(value: U32) -> Self {
+            Self([value, U32::ZERO])
+        }
+    }
+
+    /// Stores a u128 across four f64s as `x3 * 2^96 + x2 * 2^64 + x1 * 2^32 +
+    /// x0` Where `xi ∈ [0, 2^32)`
+    #[repr(transparent)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct U128([U32; 4]);
+
+    impl U128 {
+        #[inline]
+        #[must_use]
+        pub const fn new(v: u128) -> Self {
+            Self([
+                U32::new(v as u32),
+                U32::new((v >> 32) as u32),
+                U32::new((v >> 64) as u32),
+                U32::new((v >> 96) as u32),
+            ])
+        }
+    }
+
+    impl From<U128> for u128 {
+        #[inline]
+        fn from(v: U128) -> u128 {
+            v.0.iter()
+                .rev()
+                .fold(0, |acc, limb| acc << 32 | limb.0 as u128)
+        }
+    }
+
+    impl Add for U128 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let mut carry = 0.0;
+            Self(std::array::from_fn(|i| {
+                let mut l = self.0[i].0 + rhs.0[i].0 + carry;
+                carry = 0.0;
+                if l >= U32::MODULUS {
+                    l -= U32::MODULUS;
+                    carry = 1.0;
+                }
+                U32(l)
+            }))
+        }
+    }
+
+    impl Mul for U128 {
+        type Output = Self;
+
+        /// Schoolbook over the limbs, dropping the partial products of weight
+        /// `2^128` and above. Each column stays below `2^36` so it is exact and
+        /// the carries are propagated with fract/trunc at the end.
+        fn mul(self, rhs: Self) -> Self::Output {
+            let mut columns = [0.0; 4];
+            for i in 0..4 {
+                for j in 0..4 - i {
+                    let (lo, hi) = self.0[i].widening_mul(rhs.0[j]);
+                    columns[i + j] += lo.0;
+                    if i + j < 3 {
+                        columns[i + j + 1] += hi.0;
+                    }
+                }
+            }
+
+            let mut carry = 0.0;
+            Self(columns.map(|column| {
+                let v = (column + carry) * U32::MODULUS_INV;
+                carry = v.trunc();
+                U32(v.fract() * U32::MODULUS)
+            }))
+        }
+    }
+
+    impl_assign_ops!(U128; AddAssign::add_assign => +, MulAssign::mul_assign => *);
+
+    impl_pod!(U128);
+
+    impl Distribution<U128> for Standard {
+        fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U128 {
+            U128::new(self.sample(rng))
+        }
+    }
+
+    impl_native_conversions!(U128, u128, from: [u8, u16, u32, u64, u128], try_from: []);
+    impl_ord!(U128, u128);
+    impl_display!(U128, u128);
+    impl_int_num_traits!(U128, u128, max: u128::MAX);
+    impl_pow!(U128);
+
+    impl From<U64> for U128 {
+        #[inline]
+        fn from(value: U64) -> Self {
+            let [lo, hi] = value.0;
+            Self([lo, hi, U32::ZERO, U32::ZERO])
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::arithmetic::assert_native_order;
+        use crate::arithmetic::assert_num_traits;
+        use crate::arithmetic::assert_pow;
+        use rand::rngs::StdRng;
+        use rand::Rng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn ordering() {
+            assert_native_order(&[0, 1, 1 << 31, u32::MAX], U32::new);
+            assert_native_order(&[i32::MIN, -2, -1, 0, 1, i32::MAX], I32::new);
+            let u64s = [0, 1, 0xFFFF_FFFF, 1 << 32, 0x1_FFFF_FFFF, u64::MAX];
+            assert_native_order(&u64s, U64::new);
+            let u128s = [0, 1, u64::MAX as u128, 1 << 64, 1 << 96, u128::MAX];
+            assert_native_order(&u128s, U128::new);
+        }
+
+        #[test]
+        fn num_traits() {
+            let u32s = [0, 1, 2, 0xFFFF, 0x10000, 0x7FFF_FFFF, 0x8000_0000, u32::MAX];
+            assert_num_traits(&u32s, u32::MAX, U32::new);
+            let i32s = [
+                i32::MIN,
+                -0x10000,
+                -2,
+                -1,
+                0,
+                1,
+                2,
+                0xFFFF,
+                0x10000,
+                i32::MAX,
+            ];
+            assert_num_traits(&i32s, i32::MAX, I32::new);
+            let u64s = [0, 1, 2, 0xFFFF_FFFF, 1 << 32, 1 << 63, u64::MAX];
+            assert_num_traits(&u64s, u64::MAX, U64::new);
+            let u128s = [0, 1, 2, u64::MAX as u128, 1 << 64, 1 << 127, u128::MAX];
+            assert_num_traits(&u128s, u128::MAX, U128::new);
+        }
+
+        #[test]
+        fn pow() {
+            assert_pow(32, |v| U32::new(v as u32), U32::pow);
+            assert_pow(32, |v| I32::new(v as u32 as i32), I32::pow);
+            assert_pow(64, |v| U64::new(v as u64), U64::pow);
+            assert_pow(128, U128::new, U128::pow);
+        }
+
+        #[test]
+        fn display() {
+            assert_eq!("4294967295", U32::new(u32::MAX).to_string());
+            assert_eq!("-2147483648", I32::new(i32::MIN).to_string());
+            assert_eq!(u64::MAX.to_string(), U64::new(u64::MAX).to_string());
+            assert_eq!(u128::MAX.to_string(), U128::new(u128::MAX).to_string());
+        }
+
+        const U64_EDGE_CASES: [u64; 10] = [
+            0,
+            1,
+            2,
+            0xFFFF_FFFF,
+            1 << 32,
+            0x1_0000_0001,
+            0xFFFF_FFFF_0000_0001,
+            1 << 63,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+
+        #[test]
+        fn simulated_u64_multiplication() {
+            for a in U64_EDGE_CASES {
+                for b in U64_EDGE_CASES {
+                    let expected = U64::new(a.wrapping_mul(b));
+                    let actual = U64::new(a) * U64::new(b);
+                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+                }
+            }
+
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1 << 16 {
+                let (a, b) = (rng.gen::<u64>(), rng.gen::<u64>());
+                let expected = U64::new(a.wrapping_mul(b));
+                let actual = U64::new(a) * U64::new(b);
+                assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+            }
+        }
+
+        #[test]
+        fn simulated_u64_addition() {
+            for a in U64_EDGE_CASES {
+                for b in U64_EDGE_CASES {
+                    let expected = U64::new(a.wrapping_add(b));
+                    let actual = U64::new(a) + U64::new(b);
+                    assert_eq!(expected, actual, "mismatch: `{a} + {b}`");
+                }
+            }
+
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1 << 16 {
+                let (a, b) = (rng.gen::<u64>(), rng.gen::<u64>());
+                let expected = U64::new(a.wrapping_add(b));
+                let actual = U64::new(a) + U64::new(b);
+                assert_eq!(expected, actual, "mismatch: `{a} + {b}`");
+                assert_eq!(a, u64::from(U64::new(a)));
+            }
+        }
+
+        const U128_EDGE_CASES: [u128; 10] = [
+            0,
+            1,
+            u32::MAX as u128,
+            1 << 32,
+            u64::MAX as u128,
+            1 << 64,
+            (1 << 96) + 1,
+            1 << 127,
+            u128::MAX - 1,
+            u128::MAX,
+        ];
+
+        #[test]
+        fn simulated_u128_arithmetic() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..512)
+                .map(|_| rng.gen())
+                .chain(U128_EDGE_CASES)
+                .collect::<Vec<u128>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let expected = U128::new(a.wrapping_mul(b));
+                    let actual = U128::new(a) * U128::new(b);
+                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+                    let expected = U128::new(a.wrapping_add(b));
+                    let actual = U128::new(a) + U128::new(b);
+                    assert_eq!(expected, actual, "mismatch: `{a} + {b}`");
+                }
+                assert_eq!(a, u128::from(U128::new(a)));
+            }
+        }
+
+        const I32_EDGE_CASES: [i32; 10] = [
+            0,
+            1,
+            -1,
+            2,
+            -2,
+            0xFFFF,
+            -0x10000,
+            i32::MAX - 1,
+            i32::MAX,
+            i32::MIN,
+        ];
+
+        #[test]
+        fn simulated_i32_arithmetic() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..512)
+                .map(|_| rng.gen())
+                .chain(I32_EDGE_CASES)
+                .collect::<Vec<i32>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let (x, y) = (I32::new(a), I32::new(b));
+                    assert_eq!(I32::new(a.wrapping_add(b)), x + y, "mismatch: `{a} + {b}`");
+                    assert_eq!(I32::new(a.wrapping_sub(b)), x - y, "mismatch: `{a} - {b}`");
+                    assert_eq!(I32::new(a.wrapping_mul(b)), x * y, "mismatch: `{a} * {b}`");
+                    assert_eq!(
+                        a.partial_cmp(&b),
+                        x.partial_cmp(&y),
+                        "mismatch: `{a} <=> {b}`"
+                    );
+                }
+                assert_eq!(I32::new(a.wrapping_neg()), -I32::new(a), "mismatch: `-{a}`");
+                assert_eq!(a, i32::from(I32::new(a)));
+                assert_eq!(a < 0, I32::new(a).is_negative());
+            }
+        }
+
+        #[test]
+        fn simulated_u32_runtime_modulus() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            for m in [1, 3, 65537, (1 << 31) - 1, u32::MAX as u64 - 4, 1 << 32] {
+                let modulus = Modulus::new(m);
+                let edge_cases = (0..256)
+                    .map(|_| rng.gen::<u64>() % m)
+                    .chain([0, m / 2, m - 1])
+                    .collect::<Vec<u64>>();
+                for &a in &edge_cases {
+                    for &b in &edge_cases {
+                        let (x, y) = (U32::new(a as u32), U32::new(b as u32));
+                        let expected = U32::new((a as u128 * b as u128 % m as u128) as u32);
+                        let actual = x.mul_mod(y, modulus);
+                        assert_eq!(expected, actual, "mismatch: `{a} * {b} mod {m}`");
+                        let expected = U32::new(((a + b) % m) as u32);
+                        let actual = x.add_mod(y, modulus);
+                        assert_eq!(expected, actual, "mismatch: `{a} + {b} mod {m}`");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn simulated_u32_saturating() {
+            let edge_cases = [0, 1, 2, 0xFFFF, 0x10000, 0x10001, u32::MAX - 1, u32::MAX];
+            for a in edge_cases {
+                for b in edge_cases {
+                    let expected = U32::new(a.saturating_mul(b));
+                    let actual = U32::new(a).saturating_mul(U32::new(b));
+                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+                    let expected = U32::new(a.saturating_add(b));
+                    let actual = U32::new(a).saturating_add(U32::new(b));
+                    assert_eq!(expected, actual, "mismatch: `{a} + {b}`");
+                    assert_eq!(a, u32::from(U32::new(a)));
+                }
+            }
+        }
+
+        #[test]
+        fn native_conversions() {
+            assert_eq!(U32::new(0xABCD), U32::from(0xABCDu16));
+            assert_eq!(Ok(U32::new(u32::MAX)), U32::try_from(u32::MAX as u64));
+            assert!(U32::try_from(1u64 << 32).is_err());
+        }
+
+        #[test]
+        fn widening_conversions() {
+            for a in [0, 1, 0xFF, 0xFF00, u16::MAX] {
+                assert_eq!(U32::new(a as u32), U32::from(single_precision::U16::new(a)));
+            }
+        }
+    }
 }
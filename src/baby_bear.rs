@@ -0,0 +1,72 @@
+//! BabyBear (`p = 2^31 - 2^27 + 1`) over f64 with the FMA reduction, to see
+//! whether the float approach is competitive for the field STARK provers
+//! use. Like [`crate::m31`] the products reach `2^62` so this is [`FpF64`]
+//! with the FMA low part carried into the reduction.
+use crate::fp_f64::FpF64;
+
+pub const MODULUS: u32 = (1 << 31) - (1 << 27) + 1;
+
+pub type Fp = FpF64<{ MODULUS as u64 }>;
+
+/// Generator of the multiplicative group (the one Plonky3 uses)
+pub const GENERATOR: Fp = Fp::new(31);
+
+/// `p - 1 = 2^27 * 15`
+pub const TWO_ADICITY: u32 = 27;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::Field;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    const P: u64 = MODULUS as u64;
+
+    fn pow_ref(mut base: u64, mut exp: u64) -> u64 {
+        let mut res = 1;
+        while exp != 0 {
+            if exp & 1 == 1 {
+                res = res * base % P;
+            }
+            base = base * base % P;
+            exp >>= 1;
+        }
+        res
+    }
+
+    #[test]
+    fn matches_u64_reference() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let values = (0..1 << 16)
+            .map(|_| (rng.gen_range(0..P), rng.gen_range(0..P)))
+            .chain([(0, 0), (1, P - 1), (P - 1, P - 1), (P - 2, 2)]);
+        for (a, b) in values {
+            let (x, y) = (Fp::new(a), Fp::new(b));
+            assert_eq!(a * b % P, u64::from(x * y), "mismatch: `{a} * {b}`");
+            assert_eq!((a + b) % P, u64::from(x + y), "mismatch: `{a} + {b}`");
+            assert_eq!((a + P - b) % P, u64::from(x - y), "mismatch: `{a} - {b}`");
+            assert_eq!((P - a) % P, u64::from(-x), "mismatch: `-{a}`");
+        }
+    }
+
+    #[test]
+    fn inverse_matches_u64_reference() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for a in (0..1024).map(|_| rng.gen_range(1..P)).chain([1, 2, P - 1]) {
+            let inverse = Fp::new(a).inverse().unwrap();
+            assert_eq!(pow_ref(a, P - 2), u64::from(inverse), "mismatch: `{a}^-1`");
+            assert_eq!(1, a * u64::from(inverse) % P);
+        }
+        assert_eq!(None, Fp::new(0).inverse());
+    }
+
+    #[test]
+    fn generator_and_two_adicity() {
+        assert_eq!(P - 1, GENERATOR.multiplicative_order());
+        assert_eq!(15, (P - 1) >> TWO_ADICITY);
+        let root = GENERATOR.pow((P - 1) >> TWO_ADICITY);
+        assert_eq!(1 << TWO_ADICITY, root.multiplicative_order());
+    }
+}
@@ -0,0 +1,449 @@
+//! Criterion helpers shared by `benches/arithmetic.rs` and downstream crates
+//! experimenting with their own moduli. The suite is run with
+//! `cargo bench --features bench-support`.
+//!
+//! ```ignore
+//! let mut registry = Registry::default();
+//! registry.register::<MyFp>("my_fp");
+//! registry.multiplication(c);
+//! ```
+//!
+//! Fields implementing [`Reducible`] are registered with
+//! [`Registry::register_reducible`], which also benches multiplication under
+//! each of their reducers so the matrix follows [`crate::reduction`].
+//! [`Registry::register_fields`] registers every field of
+//! [`crate::dyn_field::REGISTRY`], under a bench id derived from its field id
+//! so suites can keep their existing names.
+use crate::dyn_field;
+use crate::dyn_field::FieldVisitor;
+use crate::field::Field;
+use crate::reduction::Reducer;
+use crate::reduction::ReducerVisitor;
+use crate::reduction::Reducible;
+use criterion::black_box;
+use criterion::Criterion;
+use num_traits::MulAdd;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use std::marker::PhantomData;
+use std::ops::Add;
+use std::ops::Mul;
+
+/// Number of operands each bench iteration processes
+const NUM_VALUES: usize = 256;
+
+fn random_values<T>(seed: u8) -> Vec<T>
+where
+    Standard: Distribution<T>,
+{
+    let mut rng = StdRng::from_seed([seed; 32]);
+    (0..NUM_VALUES).map(|_| rng.gen()).collect()
+}
+
+pub fn bench_multiplication<T: Mul<Output = T> + Copy>(c: &mut Criterion, id: &str)
+where
+    Standard: Distribution<T>,
+{
+    bench_multiplication_over(c, &format!("{id}/multiplication"), &random_values::<T>(1));
+}
+
+pub fn bench_addition<T: Add<Output = T> + Copy>(c: &mut Criterion, id: &str)
+where
+    Standard: Distribution<T>,
+{
+    bench_addition_over(c, &format!("{id}/addition"), &random_values::<T>(1));
+}
+
+fn bench_multiplication_over<T: Mul<Output = T> + Copy>(
+    c: &mut Criterion,
+    name: &str,
+    values: &[T],
+) {
+    c.bench_function(name, |b| {
+        b.iter(|| {
+            for chunk in values.chunks_exact(8) {
+                let [a, b, c, d, e, f, g, h]: [T; 8] = chunk.try_into().unwrap();
+                black_box([a * b, c * d, e * f, g * h]);
+            }
+        });
+    });
+}
+
+fn bench_addition_over<T: Add<Output = T> + Copy>(c: &mut Criterion, name: &str, values: &[T]) {
+    c.bench_function(name, |b| {
+        b.iter(|| {
+            for chunk in values.chunks_exact(8) {
+                let [a, b, c, d, e, f, g, h]: [T; 8] = chunk.try_into().unwrap();
+                black_box([a + b, c + d, e + f, g + h]);
+            }
+        });
+    });
+}
+
+/// Operand distributions for [`bench_operand_distributions`]. The reductions
+/// branch on their input so a uniform distribution alone hides the best and
+/// worst cases.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operands {
+    Uniform,
+    /// Values below `2^8`: sums and products rarely need a correction
+    Small,
+    /// Values in `[p - 2^8, p)`
+    NearModulus,
+    /// Values in `(p / 2, p)`: every sum needs the subtraction
+    Adversarial,
+}
+
+impl Operands {
+    pub const ALL: [Self; 4] = [
+        Self::Uniform,
+        Self::Small,
+        Self::NearModulus,
+        Self::Adversarial,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Uniform => "uniform",
+            Self::Small => "small",
+            Self::NearModulus => "near_modulus",
+            Self::Adversarial => "adversarial",
+        }
+    }
+
+    /// Samples `NUM_VALUES` canonical integers below `modulus`
+    pub fn sample(self, modulus: u32, seed: u8) -> Vec<u32> {
+        assert!(modulus >= 2);
+        let mut rng = StdRng::from_seed([seed; 32]);
+        let window = modulus.min(1 << 8);
+        (0..NUM_VALUES)
+            .map(|_| match self {
+                Self::Uniform => rng.gen_range(0..modulus),
+                Self::Small => rng.gen_range(0..window),
+                Self::NearModulus => modulus - 1 - rng.gen_range(0..window),
+                Self::Adversarial => rng.gen_range(modulus / 2 + 1..modulus),
+            })
+            .collect()
+    }
+}
+
+/// Benches multiplication and addition of a field with modulus `modulus`
+/// under every [`Operands`] distribution
+pub fn bench_operand_distributions<T: Add<Output = T> + Mul<Output = T> + Copy + From<u32>>(
+    c: &mut Criterion,
+    id: &str,
+    modulus: u32,
+) {
+    for operands in Operands::ALL {
+        let values = operands
+            .sample(modulus, 1)
+            .into_iter()
+            .map(T::from)
+            .collect::<Vec<T>>();
+        let name = operands.name();
+        bench_multiplication_over(c, &format!("{id}/multiplication/{name}"), &values);
+        bench_addition_over(c, &format!("{id}/addition/{name}"), &values);
+    }
+}
+
+/// Benches [`crate::slice::hadamard_acc`] over `NUM_VALUES` elements
+pub fn bench_hadamard_acc<T: Add<Output = T> + Mul<Output = T> + Copy>(c: &mut Criterion, id: &str)
+where
+    Standard: Distribution<T>,
+{
+    let a = random_values::<T>(1);
+    let b = random_values::<T>(2);
+    let mut dst = random_values::<T>(3);
+    c.bench_function(&format!("{id}/hadamard_acc"), |bencher| {
+        bencher.iter(|| {
+            crate::slice::hadamard_acc(&mut dst, black_box(&a), black_box(&b));
+            black_box(&dst);
+        });
+    });
+}
+
+/// Benches [`crate::slice::acc_scaled`] over `NUM_VALUES` elements
+pub fn bench_acc_scaled<T: Add<Output = T> + Mul<Output = T> + Copy>(c: &mut Criterion, id: &str)
+where
+    Standard: Distribution<T>,
+{
+    let src = random_values::<T>(1);
+    let k = random_values::<T>(2)[0];
+    let mut dst = random_values::<T>(3);
+    c.bench_function(&format!("{id}/acc_scaled"), |bencher| {
+        bencher.iter(|| {
+            crate::slice::acc_scaled(&mut dst, black_box(&src), black_box(k));
+            black_box(&dst);
+        });
+    });
+}
+
+/// Benches [`crate::slice::acc_scaled_fused`] over `NUM_VALUES` elements
+pub fn bench_acc_scaled_fused<T: MulAdd<Output = T> + Copy>(c: &mut Criterion, id: &str)
+where
+    Standard: Distribution<T>,
+{
+    let src = random_values::<T>(1);
+    let k = random_values::<T>(2)[0];
+    let mut dst = random_values::<T>(3);
+    c.bench_function(&format!("{id}/acc_scaled_fused"), |bencher| {
+        bencher.iter(|| {
+            crate::slice::acc_scaled_fused(&mut dst, black_box(&src), black_box(k));
+            black_box(&dst);
+        });
+    });
+}
+
+/// Benches Horner evaluation `acc = acc * x + c` over `NUM_VALUES`
+/// coefficients, once with the fused [`MulAdd`] and once with a separate
+/// multiply and add. Every step depends on the previous one so this measures
+/// the latency of the chain.
+pub fn bench_horner<T: Add<Output = T> + Mul<Output = T> + MulAdd<Output = T> + Copy>(
+    c: &mut Criterion,
+    id: &str,
+) where
+    Standard: Distribution<T>,
+{
+    let coeffs = random_values::<T>(1);
+    let x = random_values::<T>(2)[0];
+    c.bench_function(&format!("{id}/horner/fused"), |b| {
+        b.iter(|| {
+            let x = black_box(x);
+            black_box(
+                coeffs[1..]
+                    .iter()
+                    .fold(coeffs[0], |acc, &c| acc.mul_add(x, c)),
+            )
+        });
+    });
+    c.bench_function(&format!("{id}/horner/separate"), |b| {
+        b.iter(|| {
+            let x = black_box(x);
+            black_box(coeffs[1..].iter().fold(coeffs[0], |acc, &c| acc * x + c))
+        });
+    });
+}
+
+/// Benches `from` over `NUM_VALUES` integers below `bound` and `into` over
+/// the results. Takes the conversions as functions since not every type
+/// implements `From<u32>` (e.g. `U31` only has `TryFrom`).
+pub fn bench_conversion<T: Copy>(
+    c: &mut Criterion,
+    id: &str,
+    bound: u32,
+    from: fn(u32) -> T,
+    into: fn(T) -> u32,
+) {
+    let values = random_values::<u32>(1)
+        .into_iter()
+        .map(|v| v % bound)
+        .collect::<Vec<u32>>();
+    let converted = values.iter().map(|&v| from(v)).collect::<Vec<T>>();
+    c.bench_function(&format!("{id}/from_u32"), |b| {
+        b.iter(|| {
+            for &v in black_box(&values) {
+                black_box(from(v));
+            }
+        });
+    });
+    c.bench_function(&format!("{id}/into_u32"), |b| {
+        b.iter(|| {
+            for &v in black_box(&converted) {
+                black_box(into(v));
+            }
+        });
+    });
+}
+
+/// Benches bit decomposition and recomposition over `NUM_VALUES` elements
+pub fn bench_le_bits<T: Copy, const N: usize>(
+    c: &mut Criterion,
+    id: &str,
+    to_le_bits: fn(T) -> [bool; N],
+    from_le_bits: fn([bool; N]) -> T,
+) where
+    Standard: Distribution<T>,
+{
+    let values = random_values::<T>(1);
+    let bits = values.iter().map(|&v| to_le_bits(v)).collect::<Vec<_>>();
+    c.bench_function(&format!("{id}/to_le_bits"), |b| {
+        b.iter(|| {
+            for &v in black_box(&values) {
+                black_box(to_le_bits(v));
+            }
+        });
+    });
+    c.bench_function(&format!("{id}/from_le_bits"), |b| {
+        b.iter(|| {
+            for &v in black_box(&bits) {
+                black_box(from_le_bits(v));
+            }
+        });
+    });
+}
+
+type BenchFn = fn(&mut Criterion, &str);
+
+struct Entry {
+    id: String,
+    multiplication: BenchFn,
+    addition: BenchFn,
+    hadamard_acc: BenchFn,
+    acc_scaled: BenchFn,
+}
+
+/// Set of field types to run every bench over. Types are benched in
+/// registration order.
+#[derive(Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+    reduced: Vec<(String, BenchFn)>,
+}
+
+/// Multiplication of `T` with its remainder computed by `S`
+struct Reduced<T, S>(T, PhantomData<S>);
+
+// derives would require `S: Copy`
+impl<T: Copy, S> Clone for Reduced<T, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Copy, S> Copy for Reduced<T, S> {}
+
+impl<T: Reducible, S: Reducer<T::Float>> Mul for Reduced<T, S> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0.mul_reduced::<S>(rhs.0), PhantomData)
+    }
+}
+
+impl<T, S> Distribution<Reduced<T, S>> for Standard
+where
+    Standard: Distribution<T>,
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Reduced<T, S> {
+        Reduced(self.sample(rng), PhantomData)
+    }
+}
+
+/// Collects a multiplication bench per reducer of `T`
+struct ReducedBenches<'a, T> {
+    id: &'a str,
+    benches: &'a mut Vec<(String, BenchFn)>,
+    _field: PhantomData<T>,
+}
+
+impl<T: Reducible> ReducerVisitor<T::Float> for ReducedBenches<'_, T>
+where
+    Standard: Distribution<T>,
+{
+    fn visit<S: Reducer<T::Float>>(&mut self, name: &'static str) {
+        self.benches.push((
+            format!("{}/{name}", self.id),
+            bench_multiplication::<Reduced<T, S>>,
+        ));
+    }
+}
+
+impl Registry {
+    pub fn register<T: Add<Output = T> + Mul<Output = T> + Copy>(&mut self, id: &str) -> &mut Self
+    where
+        Standard: Distribution<T>,
+    {
+        self.entries.push(Entry {
+            id: id.to_owned(),
+            multiplication: bench_multiplication::<T>,
+            addition: bench_addition::<T>,
+            hadamard_acc: bench_hadamard_acc::<T>,
+            acc_scaled: bench_acc_scaled::<T>,
+        });
+        self
+    }
+
+    /// Registers `T` like [`Registry::register`] plus a multiplication bench
+    /// `{id}/{reducer}` for every reducer in [`Reducible::for_each_reducer`]
+    pub fn register_reducible<T: Reducible + Add<Output = T> + Mul<Output = T>>(
+        &mut self,
+        id: &str,
+    ) -> &mut Self
+    where
+        Standard: Distribution<T>,
+    {
+        self.register::<T>(id).register_reducers::<T>(id)
+    }
+
+    /// Registers only the `{id}/{reducer}` multiplication benches of
+    /// [`Registry::register_reducible`], for types registered elsewhere
+    pub fn register_reducers<T: Reducible>(&mut self, id: &str) -> &mut Self
+    where
+        Standard: Distribution<T>,
+    {
+        T::for_each_reducer(&mut ReducedBenches::<T> {
+            id,
+            benches: &mut self.reduced,
+            _field: PhantomData,
+        });
+        self
+    }
+
+    /// Registers every field of [`dyn_field::REGISTRY`] under
+    /// `bench_id(field_id)`
+    pub fn register_fields(&mut self, bench_id: fn(&'static str) -> &'static str) -> &mut Self {
+        dyn_field::for_each_field(&mut FieldBenches {
+            registry: self,
+            bench_id,
+        });
+        self
+    }
+
+    /// Benches every registered type's multiplication, then every reducer of
+    /// the [`Reducible`] ones
+    pub fn multiplication(&self, c: &mut Criterion) {
+        for entry in &self.entries {
+            (entry.multiplication)(c, &entry.id);
+        }
+        for (id, bench) in &self.reduced {
+            bench(c, id);
+        }
+    }
+
+    pub fn addition(&self, c: &mut Criterion) {
+        for entry in &self.entries {
+            (entry.addition)(c, &entry.id);
+        }
+    }
+
+    pub fn hadamard_acc(&self, c: &mut Criterion) {
+        for entry in &self.entries {
+            (entry.hadamard_acc)(c, &entry.id);
+        }
+    }
+
+    pub fn acc_scaled(&self, c: &mut Criterion) {
+        for entry in &self.entries {
+            (entry.acc_scaled)(c, &entry.id);
+        }
+    }
+}
+
+/// Registers the fields visited by [`dyn_field::for_each_field`]
+struct FieldBenches<'a> {
+    registry: &'a mut Registry,
+    bench_id: fn(&'static str) -> &'static str,
+}
+
+impl FieldVisitor for FieldBenches<'_> {
+    fn visit<F: Field + 'static>(&mut self, id: &'static str)
+    where
+        Standard: Distribution<F>,
+    {
+        self.registry.register::<F>((self.bench_id)(id));
+    }
+}
@@ -0,0 +1,494 @@
+//! Multiplication of large integers stored as little-endian limbs of
+//! [`U16`] using a number theoretic transform over `p = 65537`.
+//!
+//! Each limb is split into four 4-bit digits so that every coefficient of the
+//! digit convolution stays below the modulus. This is the small case of
+//! Schönhage–Strassen: no recursion, the transform is evaluated with the f32
+//! FMA reduction in [`mul_mod_fma`].
+use crate::arithmetic::single_precision::U16;
+use crate::f65537::mul_mod_fma;
+use crate::f65537::pow_mod_fma;
+use crate::f65537::MODULUS_F32 as MODULUS;
+
+/// Multiplicative generator of `F_65537`
+const GENERATOR: f32 = 3.0;
+
+/// Largest power-of-two subgroup of `F_65537^*`
+const MAX_NTT_SIZE: usize = 1 << 16;
+
+const DIGIT_BITS: u32 = 4;
+const DIGITS_PER_LIMB: usize = (u16::BITS / DIGIT_BITS) as usize;
+
+/// Largest operand length (in limbs) for which the digit convolution is
+/// exact: `72 * 4 * 15^2 < 65537`
+pub const MAX_LIMBS: usize = 72;
+
+/// Computes `a * b` where `a` and `b` are little-endian limbs.
+/// Output has `a.len() + b.len()` limbs.
+pub fn mul(a: &[U16], b: &[U16]) -> Vec<U16> {
+    assert!(
+        a.len().min(b.len()) <= MAX_LIMBS,
+        "operands too large for an exact convolution mod {MODULUS}"
+    );
+    let num_limbs = a.len() + b.len();
+    if a.is_empty() || b.is_empty() {
+        return vec![U16::new(0); num_limbs];
+    }
+
+    let n = (num_limbs * DIGITS_PER_LIMB).next_power_of_two();
+    assert!(n <= MAX_NTT_SIZE, "product exceeds the NTT size limit");
+    // zero padding to `n` makes the cyclic convolution a linear one
+    let digits = convolve(&to_digits(a, n), &to_digits(b, n));
+
+    // propagate carries from base 2^4 digits into base 2^16 limbs
+    let mut res = Vec::with_capacity(num_limbs);
+    let mut carry = 0u64;
+    for digits in digits.chunks(DIGITS_PER_LIMB).take(num_limbs) {
+        let mut limb = 0;
+        for (i, &digit) in digits.iter().enumerate() {
+            carry += digit as u64;
+            limb |= (carry as u16 & 0xF) << (i as u32 * DIGIT_BITS);
+            carry >>= DIGIT_BITS;
+        }
+        res.push(U16::new(limb));
+    }
+    res
+}
+
+/// Computes the cyclic convolution of canonical residues mod 65537:
+/// `c[k] = sum_{i + j = k mod n} a[i] * b[j]`. Lengths must be equal powers of
+/// two up to `2^16`.
+///
+/// Runs as NTT, pointwise product and inverse NTT with the `n^-1` scaling of
+/// the inverse folded into the pointwise pass, so there is no separate
+/// scaling sweep over the output.
+pub fn convolve(a: &[f32], b: &[f32]) -> Vec<f32> {
+    let n = a.len();
+    assert_eq!(n, b.len());
+    assert!(
+        n.is_power_of_two(),
+        "convolution length must be a power of two"
+    );
+    assert!(n <= MAX_NTT_SIZE, "convolution exceeds the NTT size limit");
+
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    let root = pow_mod_fma(GENERATOR, (MODULUS as u64 - 1) / n as u64);
+    ntt(&mut a, root);
+    ntt(&mut b, root);
+
+    // root^-1 = root^(n - 1) and n^-1 = p - (p - 1) / n since n | p - 1
+    let n_inv = MODULUS - ((MODULUS as u32 - 1) / n as u32) as f32;
+    for (a, b) in a.iter_mut().zip(&b) {
+        *a = mul_mod_fma(mul_mod_fma(*a, *b), n_inv);
+    }
+    let inverse_twiddles = twiddles(pow_mod_fma(root, n as u64 - 1), n / 2);
+    intt(&mut a, &inverse_twiddles);
+    a
+}
+
+/// Splits limbs into 4-bit digits zero padded to length `n`
+fn to_digits(limbs: &[U16], n: usize) -> Vec<f32> {
+    let mut digits = Vec::with_capacity(n);
+    for &limb in limbs {
+        let v = u16::from(limb);
+        for i in 0..DIGITS_PER_LIMB as u32 {
+            digits.push(((v >> (i * DIGIT_BITS)) & 0xF) as f32);
+        }
+    }
+    digits.resize(n, 0.0);
+    digits
+}
+
+/// In-place radix-2 Cooley-Tukey NTT where `root` has order `values.len()`
+fn ntt(values: &mut [f32], root: f32) {
+    let n = values.len();
+    debug_assert!(n.is_power_of_two());
+    if n == 1 {
+        return;
+    }
+
+    let log_n = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let step = pow_mod_fma(root, (n / len) as u64);
+        for chunk in values.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut w = 1.0;
+            for (u, v) in lo.iter_mut().zip(hi) {
+                let t = mul_mod_fma(*v, w);
+                *v = sub_mod(*u, t);
+                *u = add_mod(*u, t);
+                w = mul_mod_fma(w, step);
+            }
+        }
+        len *= 2;
+    }
+}
+
+/// Returns `[1, root, root^2, ..., root^(len - 1)]`
+fn twiddles(root: f32, len: usize) -> Vec<f32> {
+    let mut res = Vec::with_capacity(len);
+    let mut w = 1.0;
+    for _ in 0..len {
+        res.push(w);
+        w = mul_mod_fma(w, root);
+    }
+    res
+}
+
+/// In-place radix-2 Gentleman-Sande (decimation in frequency) inverse NTT
+/// without the final scaling by `n^-1`. `inverse_twiddles` holds the first
+/// `n / 2` powers of `root^-1` so the butterflies do no exponentiation or
+/// inversion.
+fn intt(values: &mut [f32], inverse_twiddles: &[f32]) {
+    let n = values.len();
+    debug_assert!(n.is_power_of_two());
+    debug_assert_eq!(n / 2, inverse_twiddles.len());
+    if n == 1 {
+        return;
+    }
+
+    let mut len = n;
+    while len >= 2 {
+        let stride = n / len;
+        for chunk in values.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for (j, (u, v)) in lo.iter_mut().zip(hi).enumerate() {
+                let t = sub_mod(*u, *v);
+                *u = add_mod(*u, *v);
+                *v = mul_mod_fma(t, inverse_twiddles[j * stride]);
+            }
+        }
+        len /= 2;
+    }
+
+    let log_n = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+#[inline]
+fn add_mod(a: f32, b: f32) -> f32 {
+    let c = a + b;
+    if c >= MODULUS {
+        c - MODULUS
+    } else {
+        c
+    }
+}
+
+#[inline]
+fn sub_mod(a: f32, b: f32) -> f32 {
+    let c = a - b;
+    if c < 0.0 {
+        c + MODULUS
+    } else {
+        c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+    use sha2::Digest;
+    use sha2::Sha256;
+
+    /// Computes `a^-1 mod p` using Fermat's little theorem
+    fn inverse(a: f32) -> f32 {
+        pow_mod_fma(a, MODULUS as u64 - 2)
+    }
+
+    fn to_limbs(v: u128, n: usize) -> Vec<U16> {
+        (0..n).map(|i| U16::new((v >> (16 * i)) as u16)).collect()
+    }
+
+    /// Schoolbook multiplication over native integers
+    fn mul_reference(a: &[u16], b: &[u16]) -> Vec<u16> {
+        let mut res = vec![0u16; a.len() + b.len()];
+        for (i, &a) in a.iter().enumerate() {
+            let mut carry = 0u32;
+            for (j, &b) in b.iter().enumerate() {
+                let v = a as u32 * b as u32 + res[i + j] as u32 + carry;
+                res[i + j] = v as u16;
+                carry = v >> 16;
+            }
+            res[i + b.len()] = carry as u16;
+        }
+        res
+    }
+
+    #[test]
+    fn ntt_round_trip() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let n = 256;
+        let values = (0..n)
+            .map(|_| (rng.gen::<u32>() % MODULUS as u32) as f32)
+            .collect::<Vec<f32>>();
+        let root = pow_mod_fma(GENERATOR, (MODULUS as u64 - 1) / n as u64);
+        let n_inv = inverse(n as f32);
+
+        let mut actual = values.clone();
+        ntt(&mut actual, root);
+        ntt(&mut actual, inverse(root));
+        actual.iter_mut().for_each(|v| *v = mul_mod_fma(*v, n_inv));
+
+        assert_eq!(values, actual);
+
+        let mut actual = values.clone();
+        ntt(&mut actual, root);
+        intt(&mut actual, &twiddles(inverse(root), n / 2));
+        actual.iter_mut().for_each(|v| *v = mul_mod_fma(*v, n_inv));
+
+        assert_eq!(values, actual);
+    }
+
+    #[test]
+    fn intt_matches_inverse_root_ntt() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for log_n in 0..=12 {
+            let n = 1 << log_n;
+            let values = (0..n)
+                .map(|_| (rng.gen::<u32>() % MODULUS as u32) as f32)
+                .collect::<Vec<f32>>();
+            let root_inv = inverse(pow_mod_fma(GENERATOR, (MODULUS as u64 - 1) / n as u64));
+
+            let mut expected = values.clone();
+            ntt(&mut expected, root_inv);
+            let mut actual = values.clone();
+            intt(&mut actual, &twiddles(root_inv, n / 2));
+            assert_eq!(expected, actual, "mismatch for size {n}");
+            assert_eq!(
+                1.0,
+                mul_mod_fma(n as f32, MODULUS - ((MODULUS as u32 - 1) / n as u32) as f32)
+            );
+        }
+    }
+
+    /// Computes `base^exp mod p` by square-and-multiply over `u64`
+    fn pow_reference(mut base: u64, mut exp: u64) -> u64 {
+        const P: u64 = MODULUS as u64;
+        let mut res = 1;
+        while exp != 0 {
+            if exp & 1 == 1 {
+                res = res * base % P;
+            }
+            base = base * base % P;
+            exp >>= 1;
+        }
+        res
+    }
+
+    /// Radix-2 NTT over native integers with the same twiddle schedule as
+    /// [`ntt`]
+    fn ntt_reference(values: &mut [u64], root: u64) {
+        const P: u64 = MODULUS as u64;
+        let n = values.len();
+        let log_n = n.trailing_zeros();
+        for i in 0..n {
+            let j = i
+                .reverse_bits()
+                .checked_shr(usize::BITS - log_n)
+                .unwrap_or(0);
+            if i < j {
+                values.swap(i, j);
+            }
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let step = pow_reference(root, (n / len) as u64);
+            for chunk in values.chunks_mut(len) {
+                let (lo, hi) = chunk.split_at_mut(len / 2);
+                let mut w = 1;
+                for (u, v) in lo.iter_mut().zip(hi) {
+                    let t = *v * w % P;
+                    *v = (*u + P - t) % P;
+                    *u = (*u + t) % P;
+                    w = w * step % P;
+                }
+            }
+            len *= 2;
+        }
+    }
+
+    /// Asserts the f32 NTT of `values * shift^i` is bit identical to the
+    /// integer reference
+    fn assert_ntt_matches_reference(values: &[u32], shift: u32) {
+        let n = values.len();
+        let root = pow_reference(GENERATOR as u64, (MODULUS as u64 - 1) / n as u64);
+        assert_eq!(
+            root as f32,
+            pow_mod_fma(GENERATOR, (MODULUS as u64 - 1) / n as u64)
+        );
+        let mut expected = Vec::with_capacity(n);
+        let mut s = 1;
+        for &v in values {
+            expected.push(v as u64 * s % MODULUS as u64);
+            s = s * shift as u64 % MODULUS as u64;
+        }
+        let mut actual = expected.iter().map(|&v| v as f32).collect::<Vec<f32>>();
+
+        ntt_reference(&mut expected, root);
+        ntt(&mut actual, root as f32);
+
+        let expected = expected.into_iter().map(|v| v as f32).collect::<Vec<f32>>();
+        assert!(
+            expected
+                .iter()
+                .zip(&actual)
+                .all(|(a, b)| a.to_bits() == b.to_bits()),
+            "mismatch: size {n}, shift {shift}"
+        );
+    }
+
+    #[test]
+    fn ntt_matches_integer_reference() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for log_n in 0..=MAX_NTT_SIZE.trailing_zeros() {
+            let values = (0..1 << log_n)
+                .map(|_| rng.gen::<u32>() % MODULUS as u32)
+                .collect::<Vec<u32>>();
+            for shift in [1, GENERATOR as u32, MODULUS as u32 - 1] {
+                assert_ntt_matches_reference(&values, shift);
+            }
+        }
+    }
+
+    #[test]
+    fn ntt_matches_integer_reference_random_sizes() {
+        let mut rng = StdRng::from_seed([2; 32]);
+        for _ in 0..64 {
+            let n = 1 << rng.gen_range(0..=12);
+            let values = (0..n)
+                .map(|_| match rng.gen_range(0..4) {
+                    0 => 0,
+                    1 => MODULUS as u32 - 1,
+                    _ => rng.gen::<u32>() % MODULUS as u32,
+                })
+                .collect::<Vec<u32>>();
+            let shift = 1 + rng.gen::<u32>() % (MODULUS as u32 - 1);
+            assert_ntt_matches_reference(&values, shift);
+        }
+    }
+
+    #[test]
+    fn convolution() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for log_n in [0, 1, 5, 9] {
+            let n = 1 << log_n;
+            let a = (0..n)
+                .map(|_| rng.gen::<u32>() % MODULUS as u32)
+                .collect::<Vec<u32>>();
+            let b = (0..n)
+                .map(|_| rng.gen::<u32>() % MODULUS as u32)
+                .collect::<Vec<u32>>();
+            let mut expected = vec![0u64; n];
+            for (i, &a) in a.iter().enumerate() {
+                for (j, &b) in b.iter().enumerate() {
+                    let k = (i + j) % n;
+                    expected[k] = (expected[k] + a as u64 * b as u64) % MODULUS as u64;
+                }
+            }
+            let expected = expected.into_iter().map(|v| v as f32).collect::<Vec<f32>>();
+            let actual = convolve(
+                &a.iter().map(|&v| v as f32).collect::<Vec<f32>>(),
+                &b.iter().map(|&v| v as f32).collect::<Vec<f32>>(),
+            );
+            assert_eq!(expected, actual, "mismatch for size {n}");
+        }
+    }
+
+    /// Pinned SHA-256 digests of the forward NTT of seeded random inputs.
+    /// Changing these means the transform's output changed.
+    const NTT_GOLDEN: [(u32, &str); 3] = [
+        (
+            4,
+            "b6be57594256c081863068890f49cfd8fb89f6d02407b05c56cd0b681667c2a0",
+        ),
+        (
+            10,
+            "cf537b44e634b5f0ae0d811201a4a5ae108d3a296817d5db80db85de1863acf6",
+        ),
+        (
+            16,
+            "3d3ee0c5762e30d2a57f45532f64e37189449648274fbedc02ffb4efcad4bdd7",
+        ),
+    ];
+
+    #[test]
+    fn ntt_golden() {
+        for (log_n, expected) in NTT_GOLDEN {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let n = 1 << log_n;
+            let mut values = (0..n)
+                .map(|_| (rng.gen::<u32>() % MODULUS as u32) as f32)
+                .collect::<Vec<f32>>();
+            ntt(
+                &mut values,
+                pow_mod_fma(GENERATOR, (MODULUS as u64 - 1) / n as u64),
+            );
+
+            let mut hasher = Sha256::new();
+            for v in values {
+                hasher.update((v as u32).to_le_bytes());
+            }
+            let actual = hasher
+                .finalize()
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect::<String>();
+            assert_eq!(expected, actual, "mismatch for size 2^{log_n}");
+        }
+    }
+
+    #[test]
+    fn multiplication() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..1024 {
+            let a = rng.gen::<u64>();
+            let b = rng.gen::<u64>();
+            let expected = to_limbs(a as u128 * b as u128, 8);
+            let actual = mul(&to_limbs(a as u128, 4), &to_limbs(b as u128, 4));
+            assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+        }
+    }
+
+    #[test]
+    fn multiplication_max_limbs() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for b_len in [1, 2, 17, MAX_LIMBS, 3 * MAX_LIMBS] {
+            let a = (0..MAX_LIMBS).map(|_| rng.gen()).collect::<Vec<u16>>();
+            let b = (0..b_len).map(|_| rng.gen()).collect::<Vec<u16>>();
+            let expected = mul_reference(&a, &b);
+            let actual = mul(
+                &a.iter().copied().map(U16::new).collect::<Vec<U16>>(),
+                &b.iter().copied().map(U16::new).collect::<Vec<U16>>(),
+            );
+            let actual = actual.into_iter().map(u16::from).collect::<Vec<u16>>();
+            assert_eq!(expected, actual, "mismatch for {b_len} limbs");
+        }
+    }
+
+    #[test]
+    fn multiplication_all_ones() {
+        let a = vec![U16::new(u16::MAX); MAX_LIMBS];
+        let expected = mul_reference(&[u16::MAX; MAX_LIMBS], &[u16::MAX; MAX_LIMBS]);
+        let actual = mul(&a, &a).into_iter().map(u16::from).collect::<Vec<u16>>();
+        assert_eq!(expected, actual);
+    }
+}
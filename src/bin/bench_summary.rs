@@ -0,0 +1,248 @@
+//! Collects the results of a criterion run into a single machine-readable
+//! table so runs from different machines can be compared side by side.
+//!
+//! Usage: `bench_summary [--json] [--machine NAME] [CRITERION_DIR]`, built
+//! with the `cli` feature.
+//!
+//! Benchmark ids are expected to be of the form `backend/operation[/size]`
+//! (as produced by `benches/arithmetic.rs`). Times are in nanoseconds.
+use serde_json::Value;
+use std::borrow::Cow;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Debug)]
+struct Row {
+    backend: String,
+    operation: String,
+    size: Option<String>,
+    mean_ns: f64,
+    median_ns: f64,
+    std_err_ns: f64,
+}
+
+const USAGE: &str = "usage: bench_summary [--json] [--machine NAME] [CRITERION_DIR]";
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    json: bool,
+    machine: String,
+    dir: PathBuf,
+}
+
+/// Parses the command line, rejecting unknown flags and extra arguments
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut json = false;
+    let mut machine = String::new();
+    let mut dir = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--machine" => {
+                machine = args
+                    .next()
+                    .ok_or_else(|| "missing value for --machine".to_string())?
+            }
+            flag if flag.starts_with('-') => return Err(format!("unknown flag `{flag}`")),
+            _ if dir.is_some() => return Err(format!("unexpected argument `{arg}`")),
+            _ => dir = Some(PathBuf::from(arg)),
+        }
+    }
+    Ok(Args {
+        json,
+        machine,
+        dir: dir.unwrap_or_else(|| PathBuf::from("target/criterion")),
+    })
+}
+
+fn main() -> ExitCode {
+    let Args { json, machine, dir } = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {e}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut rows = Vec::new();
+    collect(&dir, &mut rows);
+    rows.sort_by(|a, b| {
+        (&a.operation, &a.size, &a.backend).cmp(&(&b.operation, &b.size, &b.backend))
+    });
+
+    if json {
+        let rows = rows
+            .iter()
+            .map(|row| {
+                serde_json::json!({
+                    "machine": machine,
+                    "backend": row.backend,
+                    "operation": row.operation,
+                    "size": row.size,
+                    "mean_ns": row.mean_ns,
+                    "median_ns": row.median_ns,
+                    "std_err_ns": row.std_err_ns,
+                })
+            })
+            .collect::<Vec<Value>>();
+        println!("{}", serde_json::to_string_pretty(&rows).unwrap());
+    } else {
+        println!("machine,backend,operation,size,mean_ns,median_ns,std_err_ns");
+        for row in &rows {
+            println!(
+                "{},{},{},{},{},{},{}",
+                csv_field(&machine),
+                csv_field(&row.backend),
+                csv_field(&row.operation),
+                csv_field(row.size.as_deref().unwrap_or("")),
+                row.mean_ns,
+                row.median_ns,
+                row.std_err_ns
+            );
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Recursively finds criterion's `new/` result directories
+fn collect(dir: &Path, rows: &mut Vec<Row>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.file_name().is_some_and(|name| name == "new") {
+            if let Some(row) = read_result(&path) {
+                rows.push(row);
+            }
+        } else if path.file_name().is_some_and(|name| name != "report") {
+            collect(&path, rows);
+        }
+    }
+}
+
+fn read_result(dir: &Path) -> Option<Row> {
+    let read_json = |name: &str| -> Option<Value> {
+        let contents = fs::read_to_string(dir.join(name)).ok()?;
+        serde_json::from_str(&contents).ok()
+    };
+    let benchmark = read_json("benchmark.json")?;
+    let estimates = read_json("estimates.json")?;
+    let (backend, operation, size) = split_id(benchmark["full_id"].as_str()?);
+    Some(Row {
+        backend,
+        operation,
+        size,
+        mean_ns: estimates["mean"]["point_estimate"].as_f64()?,
+        median_ns: estimates["median"]["point_estimate"].as_f64()?,
+        std_err_ns: estimates["mean"]["standard_error"].as_f64()?,
+    })
+}
+
+/// Splits `backend/operation[/size]` into its components. The backend ends
+/// at the first `/` and the size starts after the last one, so an operation
+/// containing `/` is kept whole.
+fn split_id(id: &str) -> (String, String, Option<String>) {
+    let Some((backend, rest)) = id.split_once('/') else {
+        return (id.to_string(), String::new(), None);
+    };
+    let (operation, size) = match rest.rsplit_once('/') {
+        Some((operation, size)) => (operation, Some(size.to_string())),
+        None => (rest, None),
+    };
+    (backend.to_string(), operation.to_string(), size)
+}
+
+/// Quotes `field` for CSV (RFC 4180) if it contains a separator, quote or
+/// line break, doubling the quotes inside
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_benchmark_ids() {
+        assert_eq!(
+            (
+                "f32_sim_u31".to_string(),
+                "multiplication".to_string(),
+                None
+            ),
+            split_id("f32_sim_u31/multiplication")
+        );
+        assert_eq!(
+            (
+                "native_u32".to_string(),
+                "addition".to_string(),
+                Some("1024".to_string())
+            ),
+            split_id("native_u32/addition/1024")
+        );
+        assert_eq!(
+            (
+                "f64_baby_bear".to_string(),
+                "acc_scaled/fused".to_string(),
+                Some("1024".to_string())
+            ),
+            split_id("f64_baby_bear/acc_scaled/fused/1024")
+        );
+        assert_eq!(
+            ("native_u32".to_string(), String::new(), None),
+            split_id("native_u32")
+        );
+    }
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_command_line() {
+        assert_eq!(
+            Ok(Args {
+                json: false,
+                machine: String::new(),
+                dir: PathBuf::from("target/criterion"),
+            }),
+            args(&[])
+        );
+        assert_eq!(
+            Ok(Args {
+                json: true,
+                machine: "ci".to_string(),
+                dir: PathBuf::from("out"),
+            }),
+            args(&["--machine", "ci", "out", "--json"])
+        );
+        assert_eq!(
+            Err("missing value for --machine".to_string()),
+            args(&["--machine"])
+        );
+        assert_eq!(Err("unknown flag `--csv`".to_string()), args(&["--csv"]));
+        assert_eq!(
+            Err("unexpected argument `b`".to_string()),
+            args(&["a", "b"])
+        );
+    }
+
+    #[test]
+    fn csv_fields_are_quoted() {
+        assert_eq!("ci-runner", csv_field("ci-runner"));
+        assert_eq!("\"ci, x86\"", csv_field("ci, x86"));
+        assert_eq!("\"say \"\"hi\"\"\"", csv_field("say \"hi\""));
+        assert_eq!("\"a\nb\"", csv_field("a\nb"));
+        assert_eq!("", csv_field(""));
+    }
+}
@@ -0,0 +1,221 @@
+//! Runs one registered kernel over one field from a spec and prints the
+//! result as a JSON line, so sweeps can be scripted without editing the bench
+//! files.
+//!
+//! ```text
+//! cargo run --release --features cli --bin experiment -- [--list] [--spec FILE] [KEY=VALUE...]
+//! ```
+//!
+//! The keys are `field` (a [`dyn_field`] id such as `fp20-f64`), `kernel`,
+//! `size`, `iterations` and `seed`. A spec file holds the same keys as flat
+//! TOML (`key = value` lines, strings optionally quoted, `#` comments) and
+//! `KEY=VALUE` arguments override it. `--list` prints the registered fields and
+//! kernels.
+//!
+//! Operands are drawn from `seed` and the kernel runs `iterations` times in
+//! place over columns of `size` elements. The reported digest is
+//! [`hash::hash_columns`] over the low and high 32 bits of the canonical
+//! results, so every backend of a field (e.g. `fp20-u32` and `fp20-f64`)
+//! prints the same digest for the same spec.
+use ark_experimentation::dyn_field;
+use ark_experimentation::dyn_field::FieldVisitor;
+use ark_experimentation::field::Field;
+use ark_experimentation::hash;
+use ark_experimentation::slice;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use std::hint::black_box;
+use std::ops::Add;
+use std::ops::Mul;
+use std::process::ExitCode;
+use std::time::Instant;
+
+/// Kernels over `(dst, a, b)` columns, all in place on `dst`, see [`kernel`]
+const KERNELS: [&str; 4] = ["add", "hadamard", "hadamard_acc", "acc_scaled"];
+
+type Kernel<F> = fn(&mut [F], &[F], &[F]);
+
+/// Runs a spec over the registered field with id `spec.field`
+struct Runner<'a> {
+    spec: &'a Spec,
+    report: Option<Result<serde_json::Value, String>>,
+}
+
+impl FieldVisitor for Runner<'_> {
+    fn visit<F: Field + 'static>(&mut self, id: &'static str)
+    where
+        Standard: Distribution<F>,
+    {
+        if id == self.spec.field {
+            self.report = Some(run::<F>(self.spec));
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Spec {
+    field: String,
+    kernel: String,
+    size: usize,
+    iterations: usize,
+    seed: u64,
+}
+
+impl Default for Spec {
+    fn default() -> Self {
+        Self {
+            field: "fp20-f64".to_owned(),
+            kernel: "hadamard".to_owned(),
+            size: 1 << 16,
+            iterations: 100,
+            seed: 0,
+        }
+    }
+}
+
+impl Spec {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let parse_err = |e: std::num::ParseIntError| format!("invalid {key} `{value}`: {e}");
+        match key {
+            "field" => self.field = value.to_owned(),
+            "kernel" => self.kernel = value.to_owned(),
+            "size" => self.size = value.parse().map_err(parse_err)?,
+            "iterations" => self.iterations = value.parse().map_err(parse_err)?,
+            "seed" => self.seed = value.parse().map_err(parse_err)?,
+            _ => return Err(format!("unknown key `{key}`")),
+        }
+        Ok(())
+    }
+
+    /// Applies the `key = value` lines of a flat TOML document
+    fn set_toml(&mut self, toml: &str) -> Result<(), String> {
+        for (i, line) in toml.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `key = value`", i + 1))?;
+            let value = value.trim().trim_matches('"');
+            self.set(key.trim(), value)
+                .map_err(|e| format!("line {}: {e}", i + 1))?;
+        }
+        Ok(())
+    }
+}
+
+fn kernel<F: Copy + Add<Output = F> + Mul<Output = F>>(name: &str) -> Option<Kernel<F>> {
+    let kernel: Kernel<F> = match name {
+        "add" => |dst, a, _| slice::add(dst, a),
+        "hadamard" => |dst, a, _| slice::hadamard(dst, a),
+        "hadamard_acc" => slice::hadamard_acc,
+        "acc_scaled" => |dst, a, b| slice::acc_scaled(dst, a, b[0]),
+        _ => return None,
+    };
+    Some(kernel)
+}
+
+fn run<F: Field>(spec: &Spec) -> Result<serde_json::Value, String>
+where
+    Standard: Distribution<F>,
+{
+    let kernel =
+        kernel::<F>(&spec.kernel).ok_or_else(|| format!("unknown kernel `{}`", spec.kernel))?;
+    if spec.size == 0 {
+        return Err("size must be positive".to_owned());
+    }
+    let mut rng = StdRng::seed_from_u64(spec.seed);
+    let mut column = || (0..spec.size).map(|_| rng.gen()).collect::<Vec<F>>();
+    let (mut dst, a, b) = (column(), column(), column());
+
+    let start = Instant::now();
+    for _ in 0..spec.iterations {
+        kernel(black_box(&mut dst), black_box(&a), black_box(&b));
+    }
+    let elapsed = start.elapsed();
+
+    let (lo, hi): (Vec<u32>, Vec<u32>) = dst
+        .iter()
+        .map(|v| {
+            let v = v.to_canonical_u64();
+            (v as u32, (v >> 32) as u32)
+        })
+        .unzip();
+    let elements = (spec.size * spec.iterations) as f64;
+    Ok(serde_json::json!({
+        "field": spec.field,
+        "kernel": spec.kernel,
+        "size": spec.size,
+        "iterations": spec.iterations,
+        "seed": spec.seed,
+        "total_ns": elapsed.as_nanos() as u64,
+        "ns_per_element": elapsed.as_nanos() as f64 / elements,
+        "digest": hash::to_hex(&hash::hash_columns("experiment", &[&lo, &hi])),
+    }))
+}
+
+fn list() {
+    for field in dyn_field::names() {
+        println!("field={field}");
+    }
+    println!("kernels: {}", KERNELS.join(", "));
+}
+
+fn parse_args() -> Result<Option<Spec>, String> {
+    let mut spec = Spec::default();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--list" => return Ok(None),
+            "--spec" => {
+                let path = args.next().ok_or("missing value for --spec")?;
+                let toml = std::fs::read_to_string(&path).map_err(|e| format!("{path}: {e}"))?;
+                spec.set_toml(&toml)?;
+            }
+            _ => {
+                let (key, value) = arg
+                    .split_once('=')
+                    .ok_or_else(|| format!("expected KEY=VALUE, got `{arg}`"))?;
+                spec.set(key, value)?;
+            }
+        }
+    }
+    Ok(Some(spec))
+}
+
+fn main() -> ExitCode {
+    let spec = match parse_args() {
+        Ok(Some(spec)) => spec,
+        Ok(None) => {
+            list();
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut runner = Runner {
+        spec: &spec,
+        report: None,
+    };
+    dyn_field::for_each_field(&mut runner);
+    let Some(report) = runner.report else {
+        eprintln!("error: unknown field `{}`, see --list", spec.field);
+        return ExitCode::FAILURE;
+    };
+    match report {
+        Ok(report) => {
+            println!("{report}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
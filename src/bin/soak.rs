@@ -0,0 +1,263 @@
+//! Soak test: runs random sequences of mixed field operations on every fp20
+//! backend and the other float fields (the const-modulus, goldilocks and
+//! numeric backends) for a fixed duration and checks each step against a
+//! `u128` reference. Each round also checks an Fp2 NTT round trip and the
+//! 65537 cyclic convolution against a naive one.
+//!
+//! ```text
+//! cargo run --release --bin soak -- [seconds] [seed]
+//! ```
+//!
+//! A failing op sequence is shrunk to a minimal one before it is reported.
+use ark_experimentation::bigint;
+use ark_experimentation::field::numeric;
+use ark_experimentation::field::Field;
+use ark_experimentation::fp20::double_precision;
+use ark_experimentation::fp20::extension;
+use ark_experimentation::fp20::extension::Fp2;
+use ark_experimentation::fp20::integer;
+use ark_experimentation::fp20::single_precision;
+use ark_experimentation::fp_f32::FpF32;
+use ark_experimentation::fp_f64::FpF64;
+use ark_experimentation::goldilocks;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use std::process::ExitCode;
+use std::time::Duration;
+use std::time::Instant;
+
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+const SEQUENCE_LEN: usize = 256;
+const USAGE: &str = "usage: soak [seconds] [seed]";
+
+/// Operation applied to the running accumulator with a canonical operand
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Add(u64),
+    Sub(u64),
+    Mul(u64),
+    /// Division by a nonzero operand
+    Div(u64),
+    Square,
+}
+
+impl Op {
+    fn random(rng: &mut StdRng, order: u64) -> Self {
+        // bias operands towards the edges where the corrections happen
+        let operand = match rng.gen_range(0..4) {
+            0 => rng.gen_range(0..16),
+            1 => order - 1 - rng.gen_range(0..16),
+            _ => rng.gen_range(0..order),
+        };
+        match rng.gen_range(0..5) {
+            0 => Op::Add(operand),
+            1 => Op::Sub(operand),
+            2 => Op::Mul(operand),
+            3 => Op::Div(operand.max(1)),
+            _ => Op::Square,
+        }
+    }
+
+    fn apply<F: Field>(self, acc: F) -> F {
+        match self {
+            Op::Add(v) => acc + element(v),
+            Op::Sub(v) => acc - element(v),
+            Op::Mul(v) => acc * element(v),
+            Op::Div(v) => acc * element::<F>(v).inverse().unwrap(),
+            Op::Square => acc.square(),
+        }
+    }
+
+    /// [`Op::apply`] on canonical representatives modulo `p`
+    fn reference(self, acc: u64, p: u64) -> u64 {
+        let mul = |a: u64, b: u64| (a as u128 * b as u128 % p as u128) as u64;
+        match self {
+            Op::Add(v) => ((acc as u128 + v as u128) % p as u128) as u64,
+            Op::Sub(v) => ((acc as u128 + (p - v) as u128) % p as u128) as u64,
+            Op::Mul(v) => mul(acc, v),
+            Op::Div(v) => {
+                // `v^(p - 2)` by square-and-multiply
+                let (mut base, mut exp, mut inv) = (v, p - 2, 1);
+                while exp != 0 {
+                    if exp & 1 == 1 {
+                        inv = mul(inv, base);
+                    }
+                    base = mul(base, base);
+                    exp >>= 1;
+                }
+                mul(acc, inv)
+            }
+            Op::Square => mul(acc, acc),
+        }
+    }
+}
+
+/// Element with canonical representative `v`, which may exceed a `u32` for
+/// fields of order above `2^32`
+fn element<F: Field>(v: u64) -> F {
+    match u32::try_from(v) {
+        Ok(v) => F::from_canonical_u32(v),
+        Err(_) => F::from((v >> 32) as u32) * F::from(1 << 16).square() + F::from(v as u32),
+    }
+}
+
+/// Runs `ops` from `start` on `F` and on the reference. Returns the index of
+/// the first op whose result differs.
+fn first_mismatch<F: Field>(start: u64, ops: &[Op]) -> Option<usize> {
+    let mut expected = start;
+    let mut actual = element::<F>(start);
+    for (i, op) in ops.iter().enumerate() {
+        expected = op.reference(expected, F::order());
+        actual = op.apply(actual);
+        if expected != actual.to_canonical_u64() {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Greedily drops ops while the sequence still fails
+fn minimize<F: Field>(start: u64, ops: &[Op]) -> Vec<Op> {
+    let end = first_mismatch::<F>(start, ops).expect("sequence doesn't fail");
+    let mut ops = ops[..=end].to_vec();
+    let mut i = 0;
+    while i < ops.len() {
+        let mut candidate = ops.clone();
+        candidate.remove(i);
+        match first_mismatch::<F>(start, &candidate) {
+            Some(end) => {
+                candidate.truncate(end + 1);
+                ops = candidate;
+            }
+            None => i += 1,
+        }
+    }
+    ops
+}
+
+/// Checks a random op sequence over the order of `F` on `F`
+fn check_sequence<F: Field>(name: &str, rng: &mut StdRng) -> Result<(), String> {
+    let start = rng.gen_range(0..F::order());
+    let ops = (0..SEQUENCE_LEN)
+        .map(|_| Op::random(rng, F::order()))
+        .collect::<Vec<Op>>();
+    match first_mismatch::<F>(start, &ops) {
+        None => Ok(()),
+        Some(_) => Err(format!(
+            "{name}: mismatch from {start} after {:?}",
+            minimize::<F>(start, &ops)
+        )),
+    }
+}
+
+fn check_ntt_round_trip(rng: &mut StdRng) -> Result<(), String> {
+    let n = 1 << rng.gen_range(0..=extension::MAX_NTT_SIZE.ilog2());
+    let shift = Fp2::from(rng.gen_range(1..integer::MODULUS));
+    let domain = if rng.gen() {
+        Fp2::domain(n)
+    } else {
+        Fp2::coset(n, shift)
+    };
+    let values = (0..n).map(|_| rng.gen()).collect::<Vec<Fp2>>();
+    let mut actual = values.clone();
+    extension::ntt(&mut actual, &domain);
+    extension::intt(&mut actual, &domain);
+    if actual != values {
+        return Err(format!(
+            "fp2 ntt: round trip failed over {domain:?} for {values:?}"
+        ));
+    }
+    Ok(())
+}
+
+fn check_convolution(rng: &mut StdRng) -> Result<(), String> {
+    const P: u64 = 65537;
+    let n = 1 << rng.gen_range(0..=6);
+    let a = (0..n)
+        .map(|_| rng.gen_range(0..P) as f32)
+        .collect::<Vec<f32>>();
+    let b = (0..n)
+        .map(|_| rng.gen_range(0..P) as f32)
+        .collect::<Vec<f32>>();
+    let mut expected = vec![0; n];
+    for (i, &a) in a.iter().enumerate() {
+        for (j, &b) in b.iter().enumerate() {
+            let k = (i + j) % n;
+            expected[k] = (expected[k] + a as u64 * b as u64) % P;
+        }
+    }
+    let actual = bigint::convolve(&a, &b);
+    if actual.iter().zip(&expected).any(|(&x, &y)| x as u64 != y) {
+        return Err(format!("65537 convolve: mismatch for {a:?} * {b:?}"));
+    }
+    Ok(())
+}
+
+fn round(rng: &mut StdRng) -> Result<(), String> {
+    check_sequence::<integer::Fp>("fp20-u32", rng)?;
+    check_sequence::<single_precision::Fp>("fp20-f32", rng)?;
+    check_sequence::<double_precision::Fp>("fp20-f64", rng)?;
+    check_sequence::<FpF32<2097143>>("fp2097143-f32", rng)?;
+    check_sequence::<FpF64<1099511627689>>("fp1099511627689-f64", rng)?;
+    check_sequence::<goldilocks::Fp>("goldilocks-f64x2", rng)?;
+    check_sequence::<numeric::half_precision::Fp>("numeric-u32-f16", rng)?;
+    check_sequence::<numeric::single_precision::Fp>("numeric-f32x2", rng)?;
+    check_sequence::<numeric::double_precision::Fp>("numeric-f64", rng)?;
+    check_ntt_round_trip(rng)?;
+    check_convolution(rng)
+}
+
+/// Parses the optional positional argument `name`
+fn parse_arg<T: std::str::FromStr>(
+    arg: Option<String>,
+    name: &str,
+    default: T,
+) -> Result<T, String> {
+    match arg {
+        None => Ok(default),
+        Some(v) => v.parse().map_err(|_| format!("invalid {name} `{v}`")),
+    }
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let (seconds, seed) = match (
+        parse_arg(args.next(), "seconds", 3600),
+        parse_arg(args.next(), "seed", 0),
+    ) {
+        (Ok(seconds), Ok(seed)) if args.next().is_none() => (seconds, seed),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("error: {e}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+        _ => {
+            eprintln!("error: too many arguments\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let duration = Duration::from_secs(seconds);
+    println!("soaking for {seconds}s with seed {seed}");
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let start = Instant::now();
+    let mut last_report = start;
+    let mut rounds = 0u64;
+    while start.elapsed() < duration {
+        if let Err(e) = round(&mut rng) {
+            eprintln!("failure in round {rounds}: {e}");
+            return ExitCode::FAILURE;
+        }
+        rounds += 1;
+        if last_report.elapsed() >= PROGRESS_INTERVAL {
+            last_report = Instant::now();
+            println!(
+                "{:>6}s: {rounds} rounds ({} ops per field)",
+                start.elapsed().as_secs(),
+                rounds * SEQUENCE_LEN as u64
+            );
+        }
+    }
+    println!("ok: {rounds} rounds");
+    ExitCode::SUCCESS
+}
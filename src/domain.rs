@@ -0,0 +1,92 @@
+//! Evaluation domains `{shift * w^k : 0 <= k < n}` for the transforms.
+//!
+//! A [`Domain`] caches the twiddles of its subgroup and the inverses the
+//! inverse transform needs, so transforms take one object instead of loose
+//! size, root and shift parameters that can disagree with each other.
+//! Domains are built by the field modules that provide transforms, e.g.
+//! [`crate::fp20::extension::Fp2::domain`].
+use std::ops::Mul;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Domain<F> {
+    size: usize,
+    generator: F,
+    shift: F,
+    shift_inv: F,
+    size_inv: F,
+    /// `[1, w, ..., w^(n/2 - 1)]`
+    twiddles: Vec<F>,
+    /// `[1, w^-1, ..., w^-(n/2 - 1)]`
+    inverse_twiddles: Vec<F>,
+}
+
+impl<F: Copy + Mul<Output = F> + PartialEq + From<u32>> Domain<F> {
+    /// Panics unless `generator` has order exactly `size`, `size` is a power
+    /// of two and the supplied inverses are correct.
+    pub(crate) fn new(size: usize, generator: F, shift: F, shift_inv: F, size_inv: F) -> Self {
+        assert!(size.is_power_of_two(), "domain size must be a power of two");
+        let one = F::from(1);
+        assert!(shift * shift_inv == one, "incorrect shift inverse");
+        assert!(
+            F::from(size as u32) * size_inv == one,
+            "incorrect size inverse"
+        );
+
+        let powers = std::iter::successors(Some(one), |&w| Some(w * generator))
+            .take(size + 1)
+            .collect::<Vec<F>>();
+        assert!(
+            powers[size] == one && !powers[1..size].contains(&one),
+            "generator must have order {size}"
+        );
+
+        let twiddles = powers[..size / 2].to_vec();
+        // w^-k = w^(n - k)
+        let inverse_twiddles = (0..size / 2).map(|k| powers[(size - k) % size]).collect();
+        Self {
+            size,
+            generator,
+            shift,
+            shift_inv,
+            size_inv,
+            twiddles,
+            inverse_twiddles,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Generator `w` of the subgroup
+    pub fn generator(&self) -> F {
+        self.generator
+    }
+
+    /// Coset shift (one for the subgroup itself)
+    pub fn shift(&self) -> F {
+        self.shift
+    }
+
+    /// Iterates `shift * w^k` for `k = 0..n`
+    pub fn elements(&self) -> impl Iterator<Item = F> + '_ {
+        let generator = self.generator;
+        std::iter::successors(Some(self.shift), move |&x| Some(x * generator)).take(self.size)
+    }
+
+    pub(crate) fn shift_inv(&self) -> F {
+        self.shift_inv
+    }
+
+    pub(crate) fn size_inv(&self) -> F {
+        self.size_inv
+    }
+
+    pub(crate) fn twiddles(&self) -> &[F] {
+        &self.twiddles
+    }
+
+    pub(crate) fn inverse_twiddles(&self) -> &[F] {
+        &self.inverse_twiddles
+    }
+}
@@ -0,0 +1,191 @@
+//! Experimental packing of two residues into one f64 lane: `r mod 65537` and
+//! `s mod 2097143` (fp20) stored as `x = r * 2^22 + s`.
+//!
+//! The low region has one bit of headroom so the sum of two packed values
+//! never carries from `s` into `r`. Addition is therefore a single shared f64
+//! add followed by an independent correction of each lane.
+//!
+//! Multiplication can't be shared: `(r1 * 2^22 + s1) * (r2 * 2^22 + s2)`
+//! mixes the lanes through the cross terms and exceeds the 53 bit mantissa,
+//! so both residues are unpacked and reduced separately. This is the
+//! negative result of the experiment: mul costs more than two unpacked
+//! multiplications.
+use crate::macros::impl_assign_ops;
+use crate::macros::impl_pod;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use std::fmt::Display;
+use std::ops::Add;
+use std::ops::Mul;
+
+const MODULUS_HI: f64 = 65537.0;
+const MODULUS_LO: f64 = 0b111111111111111110111 as f64;
+const SHIFT: f64 = (1u64 << 22) as f64;
+const SHIFT_INV: f64 = 1.0 / SHIFT;
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct DualFp(f64);
+
+impl DualFp {
+    /// Packs `hi mod 65537` and `lo mod 2097143`
+    #[inline]
+    #[must_use]
+    pub const fn new(hi: u32, lo: u32) -> Self {
+        debug_assert!(hi < MODULUS_HI as u32);
+        debug_assert!(lo < MODULUS_LO as u32);
+        Self(hi as f64 * SHIFT + lo as f64)
+    }
+
+    /// Returns the canonical residues `(mod 65537, mod 2097143)`
+    #[inline]
+    #[must_use]
+    pub fn residues(self) -> (u32, u32) {
+        let (hi, lo) = self.unpack();
+        (hi as u32, lo as u32)
+    }
+
+    #[inline]
+    fn unpack(self) -> (f64, f64) {
+        let hi = (self.0 * SHIFT_INV).trunc();
+        let lo = (-hi).mul_add(SHIFT, self.0);
+        (hi, lo)
+    }
+}
+
+/// Computes `a * b mod m` for `a, b < m < 2^26`. The product is exact in an
+/// f64 so a single corrected quotient suffices.
+#[inline]
+fn mul_mod(a: f64, b: f64, m: f64) -> f64 {
+    let h = a * b;
+    let c = (h * (1.0 / m)).trunc();
+    let d = (-c).mul_add(m, h);
+    if d >= m {
+        d - m
+    } else if d < 0.0 {
+        d + m
+    } else {
+        d
+    }
+}
+
+impl Add for DualFp {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut a = self.0 + rhs.0;
+        let (hi, lo) = Self(a).unpack();
+        if lo >= MODULUS_LO {
+            a -= MODULUS_LO;
+        }
+        if hi >= MODULUS_HI {
+            a -= MODULUS_HI * SHIFT;
+        }
+        Self(a)
+    }
+}
+
+impl Mul for DualFp {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (a_hi, a_lo) = self.unpack();
+        let (b_hi, b_lo) = rhs.unpack();
+        let hi = mul_mod(a_hi, b_hi, MODULUS_HI);
+        let lo = mul_mod(a_lo, b_lo, MODULUS_LO);
+        Self(hi.mul_add(SHIFT, lo))
+    }
+}
+
+/// The residues as `(mod 65537, mod 2097143)`
+impl Display for DualFp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (hi, lo) = self.residues();
+        write!(f, "({hi}, {lo})")
+    }
+}
+
+impl_assign_ops!(DualFp; AddAssign::add_assign => +, MulAssign::mul_assign => *);
+
+impl_pod!(DualFp);
+
+impl Distribution<DualFp> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> DualFp {
+        let hi: u32 = self.sample(rng);
+        let lo: u32 = self.sample(rng);
+        DualFp::new(hi % MODULUS_HI as u32, lo % MODULUS_LO as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    const P_HI: u64 = MODULUS_HI as u64;
+    const P_LO: u64 = MODULUS_LO as u64;
+
+    fn edge_cases() -> Vec<(u32, u32)> {
+        let mut rng = StdRng::from_seed([1; 32]);
+        (0..512)
+            .map(|_| {
+                (
+                    rng.gen::<u32>() % P_HI as u32,
+                    rng.gen::<u32>() % P_LO as u32,
+                )
+            })
+            .chain([
+                (0, 0),
+                (1, 1),
+                (P_HI as u32 - 1, 0),
+                (0, P_LO as u32 - 1),
+                (P_HI as u32 - 1, P_LO as u32 - 1),
+            ])
+            .collect()
+    }
+
+    #[test]
+    fn pack_unpack() {
+        for (hi, lo) in edge_cases() {
+            assert_eq!((hi, lo), DualFp::new(hi, lo).residues());
+        }
+    }
+
+    #[test]
+    fn addition() {
+        let edge_cases = edge_cases();
+        for &(a_hi, a_lo) in &edge_cases {
+            for &(b_hi, b_lo) in &edge_cases {
+                let expected = (
+                    ((a_hi as u64 + b_hi as u64) % P_HI) as u32,
+                    ((a_lo as u64 + b_lo as u64) % P_LO) as u32,
+                );
+                let actual = (DualFp::new(a_hi, a_lo) + DualFp::new(b_hi, b_lo)).residues();
+                assert_eq!(
+                    expected, actual,
+                    "mismatch: `{a_hi}|{a_lo} + {b_hi}|{b_lo}`"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn multiplication() {
+        let edge_cases = edge_cases();
+        for &(a_hi, a_lo) in &edge_cases {
+            for &(b_hi, b_lo) in &edge_cases {
+                let expected = (
+                    (a_hi as u64 * b_hi as u64 % P_HI) as u32,
+                    (a_lo as u64 * b_lo as u64 % P_LO) as u32,
+                );
+                let actual = (DualFp::new(a_hi, a_lo) * DualFp::new(b_hi, b_lo)).residues();
+                assert_eq!(
+                    expected, actual,
+                    "mismatch: `{a_hi}|{a_lo} * {b_hi}|{b_lo}`"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Object-safe facade over the crate's prime fields so tools can pick a field
+//! by name at runtime instead of being monomorphised over every field type.
+//!
+//! Elements cross the facade as canonical `u64` values in `[0, order)`.
+//!
+//! ```
+//! use ark_experimentation::dyn_field;
+//!
+//! let field = dyn_field::by_name("fp20-f32").unwrap();
+//! assert_eq!(1, field.mul(2097142, 2097142));
+//! ```
+use crate::baby_bear;
+use crate::f65537;
+use crate::field::numeric;
+use crate::field::Field;
+use crate::fp20;
+use crate::fp_f16::FpF16;
+use crate::fp_f32::FpF32;
+use crate::goldilocks;
+use crate::m31;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use std::marker::PhantomData;
+
+/// Field operations over canonical `u64` representatives
+pub trait DynField {
+    fn name(&self) -> &'static str;
+
+    /// Number of elements in the field
+    fn order(&self) -> u64;
+
+    fn add(&self, a: u64, b: u64) -> u64;
+
+    fn mul(&self, a: u64, b: u64) -> u64;
+
+    fn pow(&self, a: u64, exp: u64) -> u64;
+
+    /// Returns the smallest `k > 0` such that `a^k = 1`. Panics if `a` is zero.
+    fn multiplicative_order(&self, a: u64) -> u64;
+}
+
+/// Constructor for a registered field, given its id
+pub type Constructor = fn(&'static str) -> Box<dyn DynField>;
+
+/// Generic code to run over every registered field, see [`for_each_field`].
+/// Tools that need the concrete type (benches, kernels over slices) use this
+/// instead of the [`DynField`] facade.
+pub trait FieldVisitor {
+    fn visit<F: Field + 'static>(&mut self, id: &'static str)
+    where
+        Standard: Distribution<F>;
+}
+
+macro_rules! registry {
+    ($($id:literal => $t:ty),* $(,)?) => {
+        /// Every field the tools can select, in listing order. New fields
+        /// only need an entry in the `registry!` invocation to show up in
+        /// [`by_name`], [`names`] and [`for_each_field`].
+        pub const REGISTRY: [(&str, Constructor); [$($id),*].len()] =
+            [$(($id, Facade::<$t>::boxed)),*];
+
+        /// Calls `visitor` with the type and id of every registered field, in
+        /// the order of [`REGISTRY`]
+        pub fn for_each_field(visitor: &mut impl FieldVisitor) {
+            $(visitor.visit::<$t>($id);)*
+        }
+    };
+}
+
+registry!(
+    "fp20-u32" => fp20::integer::Fp,
+    "fp20-f32" => fp20::single_precision::Fp,
+    "fp20-f64" => fp20::double_precision::Fp,
+    "fermat65537-f32" => f65537::Fp,
+    "fp251-f16" => FpF16<251>,
+    "fp1048573-f32" => FpF32<1048573>,
+    "m31-f64" => m31::Fp,
+    "baby-bear-f64" => baby_bear::Fp,
+    "goldilocks-f64x2" => goldilocks::Fp,
+    "numeric-u32-f16" => numeric::half_precision::Fp,
+    "numeric-f32x2" => numeric::single_precision::Fp,
+    "numeric-f64" => numeric::double_precision::Fp,
+);
+
+/// Returns the field registered under `name`
+pub fn by_name(name: &str) -> Option<Box<dyn DynField>> {
+    REGISTRY
+        .iter()
+        .find(|(id, _)| *id == name)
+        .map(|&(id, new)| new(id))
+}
+
+/// Ids of the registered fields
+pub fn names() -> impl Iterator<Item = &'static str> {
+    REGISTRY.iter().map(|&(id, _)| id)
+}
+
+struct Facade<F> {
+    name: &'static str,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field> Facade<F> {
+    fn boxed(name: &'static str) -> Box<dyn DynField>
+    where
+        F: 'static,
+    {
+        Box::new(Self {
+            name,
+            _field: PhantomData,
+        })
+    }
+
+    fn element(a: u64) -> F {
+        assert!(a < F::order(), "{a} is not a canonical element");
+        match u32::try_from(a) {
+            Ok(a) => F::from_canonical_u32(a),
+            // only fields of order above `2^32` get here
+            Err(_) => F::from((a >> 32) as u32) * F::from(1 << 16).square() + F::from(a as u32),
+        }
+    }
+}
+
+impl<F: Field> DynField for Facade<F> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn order(&self) -> u64 {
+        F::order()
+    }
+
+    fn add(&self, a: u64, b: u64) -> u64 {
+        (Self::element(a) + Self::element(b)).to_canonical_u64()
+    }
+
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        (Self::element(a) * Self::element(b)).to_canonical_u64()
+    }
+
+    fn pow(&self, a: u64, exp: u64) -> u64 {
+        Self::element(a).pow(exp).to_canonical_u64()
+    }
+
+    fn multiplicative_order(&self, a: u64) -> u64 {
+        Self::element(a).multiplicative_order()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn fields_match_u64_reference() {
+        for name in names() {
+            let field = by_name(name).unwrap();
+            assert_eq!(name, field.name());
+            let p = field.order();
+
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1024 {
+                let a = rng.gen::<u64>() % p;
+                let b = rng.gen::<u64>() % p;
+                let (wide_a, wide_b, wide_p) = (u128::from(a), u128::from(b), u128::from(p));
+                let sum = ((wide_a + wide_b) % wide_p) as u64;
+                let product = (wide_a * wide_b % wide_p) as u64;
+                assert_eq!(sum, field.add(a, b), "{name}: `{a} + {b}`");
+                assert_eq!(product, field.mul(a, b), "{name}: `{a} * {b}`");
+            }
+            assert_eq!(1, field.pow(p - 1, 2));
+            assert_eq!(2, field.multiplicative_order(p - 1));
+        }
+    }
+
+    #[test]
+    fn registered_fields() {
+        assert_eq!(
+            vec![
+                "fp20-u32",
+                "fp20-f32",
+                "fp20-f64",
+                "fermat65537-f32",
+                "fp251-f16",
+                "fp1048573-f32",
+                "m31-f64",
+                "baby-bear-f64",
+                "goldilocks-f64x2",
+                "numeric-u32-f16",
+                "numeric-f32x2",
+                "numeric-f64",
+            ],
+            names().collect::<Vec<_>>()
+        );
+        let fp20 = by_name("fp20-f64").unwrap();
+        assert_eq!(fp20::integer::MODULUS as u64, fp20.order());
+        assert_eq!(fp20.order() - 1, fp20.multiplicative_order(5));
+        let fermat = by_name("fermat65537-f32").unwrap();
+        assert_eq!(65537, fermat.order());
+        assert_eq!(1 << 16, fermat.multiplicative_order(3));
+        let goldilocks = by_name("goldilocks-f64x2").unwrap();
+        assert_eq!(goldilocks::MODULUS, goldilocks.order());
+        assert_eq!(
+            1,
+            goldilocks.mul(goldilocks.order() - 1, goldilocks.order() - 1)
+        );
+    }
+
+    #[test]
+    fn visits_every_registered_field() {
+        struct Orders(Vec<(&'static str, u64)>);
+
+        impl FieldVisitor for Orders {
+            fn visit<F: Field + 'static>(&mut self, id: &'static str)
+            where
+                Standard: Distribution<F>,
+            {
+                self.0.push((id, F::order()));
+            }
+        }
+
+        let mut orders = Orders(Vec::new());
+        for_each_field(&mut orders);
+        let registered = names()
+            .map(|name| (name, by_name(name).unwrap().order()))
+            .collect::<Vec<_>>();
+        assert_eq!(registered, orders.0);
+    }
+
+    #[test]
+    fn unknown_name() {
+        assert!(by_name("fp21-u32").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a canonical element")]
+    fn rejects_non_canonical_elements() {
+        let field = by_name("fp20-u32").unwrap();
+        field.add(field.order(), 0);
+    }
+}
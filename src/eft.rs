@@ -0,0 +1,58 @@
+//! Error-free transformations: an operation's rounded result together with
+//! its exact rounding error, so `a op b = s + e` holds exactly. These are the
+//! building blocks of the double-double arithmetic in [`crate::goldilocks`]
+//! and the float-float one in [`crate::field::numeric::single_precision`].
+use num_traits::Float;
+
+/// Returns `(s, e)` with `s = fl(a + b)` and `a + b = s + e` exactly (Knuth's
+/// branch-free TwoSum)
+#[inline]
+pub fn two_sum<F: Float>(a: F, b: F) -> (F, F) {
+    let s = a + b;
+    let a_prime = s - b;
+    let b_prime = s - a_prime;
+    (s, (a - a_prime) + (b - b_prime))
+}
+
+/// Returns `(p, e)` with `p = fl(a * b)` and `a * b = p + e` exactly. Relies
+/// on `mul_add` being fused, see [`crate::fma`].
+#[inline]
+pub fn two_prod<F: Float>(a: F, b: F) -> (F, F) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn exact_on_integers() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..1 << 16 {
+            let a = rng.gen::<u64>() as i64 >> rng.gen_range(0..63);
+            let b = rng.gen::<u64>() as i64 >> rng.gen_range(0..63);
+            let (x, y) = (a as f64, b as f64);
+            let (x_i, y_i) = (x as i128, y as i128);
+
+            let (s, e) = two_sum(x, y);
+            assert_eq!(s, x + y);
+            assert_eq!(x_i + y_i, s as i128 + e as i128, "`{x} + {y}`");
+
+            let (p, e) = two_prod(x, y);
+            assert_eq!(p, x * y);
+            assert_eq!(x_i * y_i, p as i128 + e as i128, "`{x} * {y}`");
+        }
+    }
+
+    #[test]
+    fn exact_on_fractions() {
+        let (s, e) = two_sum(1.0, 2f64.powi(-60));
+        assert_eq!((1.0, 2f64.powi(-60)), (s, e));
+        let a = 1.0 + 2f64.powi(-30);
+        assert_eq!((1.0 + 2f64.powi(-29), 2f64.powi(-60)), two_prod(a, a));
+    }
+}
@@ -0,0 +1,141 @@
+//! Extension field slice ops on top of the base field kernels.
+//!
+//! A slice of degree `D` extension elements is de-interleaved into `D` base
+//! field columns (`[c0, c1, ...]` per element becomes one column per
+//! coefficient), the base field kernels in [`crate::slice`] run over the
+//! columns and the result is interleaved back. Extension arithmetic thereby
+//! picks up whatever base field kernel is fastest (including the float
+//! backends after converting the columns) without dedicated packed extension
+//! types.
+//!
+//! Elements convert to and from their coefficient arrays, e.g. `Fp2 <->
+//! [Fp; 2]` for [`crate::fp20::extension::Fp2`].
+use crate::slice;
+use std::ops::Add;
+use std::ops::Mul;
+
+/// Splits `values` into one column per coefficient
+pub fn deinterleave<E: Copy + Into<[F; D]>, F: Copy, const D: usize>(values: &[E]) -> [Vec<F>; D] {
+    let mut columns: [Vec<F>; D] = std::array::from_fn(|_| Vec::with_capacity(values.len()));
+    for &v in values {
+        for (column, c) in columns.iter_mut().zip(v.into()) {
+            column.push(c);
+        }
+    }
+    columns
+}
+
+/// Inverse of [`deinterleave`]. All columns must have the same length.
+pub fn interleave<E: From<[F; D]>, F: Copy, const D: usize>(columns: [&[F]; D]) -> Vec<E> {
+    let n = columns.first().map_or(0, |column| column.len());
+    assert!(
+        columns.iter().all(|column| column.len() == n),
+        "columns must have the same length"
+    );
+    (0..n)
+        .map(|i| E::from(columns.map(|column| column[i])))
+        .collect()
+}
+
+/// Computes `dst[i] = dst[i] + src[i]` coefficient-wise
+pub fn add<F: Copy + Add<Output = F>, const D: usize>(dst: [&mut [F]; D], src: [&[F]; D]) {
+    for (dst, src) in dst.into_iter().zip(src) {
+        slice::add(dst, src);
+    }
+}
+
+/// Computes the Hadamard product `dst[i] = dst[i] * src[i]` in the quadratic
+/// extension `F[u] / (u^2 - non_residue)` with the base field kernels:
+/// `(a0 + a1 u)(b0 + b1 u) = (a0 b0 + non_residue a1 b1) + (a0 b1 + a1 b0) u`.
+/// Allocates one scratch column.
+pub fn quadratic_hadamard<F>(dst: [&mut [F]; 2], src: [&[F]; 2], non_residue: F)
+where
+    F: Copy + Add<Output = F> + Mul<Output = F>,
+{
+    let [a0, a1] = dst;
+    let [b0, b1] = src;
+    // a1 b1 before a1 is overwritten
+    let mut a1_b1 = a1.to_vec();
+    slice::hadamard(&mut a1_b1, b1);
+    // c1 = a0 b1 + a1 b0, computed in place of a1
+    slice::hadamard(a1, b0);
+    slice::hadamard_acc(a1, a0, b1);
+    // c0 = a0 b0 + non_residue a1 b1
+    slice::hadamard(a0, b0);
+    slice::acc_scaled(a0, &a1_b1, non_residue);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp20;
+    use crate::fp20::extension::Fp2;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    fn random_fp2(rng: &mut StdRng, n: usize) -> Vec<Fp2> {
+        (0..n).map(|_| rng.gen()).collect()
+    }
+
+    #[test]
+    fn interleave_round_trip() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let values = random_fp2(&mut rng, 100);
+        let [c0, c1] = deinterleave(&values);
+        assert_eq!(values.iter().map(|v| v.c0).collect::<Vec<_>>(), c0);
+        assert_eq!(values.iter().map(|v| v.c1).collect::<Vec<_>>(), c1);
+        assert_eq!(values, interleave::<Fp2, _, 2>([&c0, &c1]));
+        assert!(interleave::<Fp2, fp20::integer::Fp, 2>([&[], &[]]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "columns must have the same length")]
+    fn interleave_rejects_ragged_columns() {
+        let c0 = [fp20::integer::Fp::new(1); 2];
+        let _ = interleave::<Fp2, _, 2>([&c0, &c0[..1]]);
+    }
+
+    #[test]
+    fn fp2_ops_match_direct() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let a = random_fp2(&mut rng, 1000);
+        let b = random_fp2(&mut rng, 1000);
+        let [b0, b1] = deinterleave(&b);
+
+        let [mut c0, mut c1] = deinterleave(&a);
+        add([&mut c0, &mut c1], [&b0, &b1]);
+        let expected = a.iter().zip(&b).map(|(&a, &b)| a + b).collect::<Vec<_>>();
+        assert_eq!(expected, interleave::<Fp2, _, 2>([&c0, &c1]));
+
+        // i^2 = -1
+        let non_residue = -fp20::integer::Fp::new(1);
+        let [mut c0, mut c1] = deinterleave(&a);
+        quadratic_hadamard([&mut c0, &mut c1], [&b0, &b1], non_residue);
+        let expected = a.iter().zip(&b).map(|(&a, &b)| a * b).collect::<Vec<_>>();
+        assert_eq!(expected, interleave::<Fp2, _, 2>([&c0, &c1]));
+
+        // the same columns over the f64 backend
+        let to_double = |column: &[fp20::integer::Fp]| {
+            column
+                .iter()
+                .map(|&v| fp20::double_precision::Fp::from(v))
+                .collect::<Vec<_>>()
+        };
+        let [a0, a1] = deinterleave(&a);
+        let (mut c0, mut c1) = (to_double(&a0), to_double(&a1));
+        quadratic_hadamard(
+            [&mut c0, &mut c1],
+            [&to_double(&b0), &to_double(&b1)],
+            non_residue.into(),
+        );
+        let to_integer = |column: Vec<fp20::double_precision::Fp>| {
+            column
+                .into_iter()
+                .map(fp20::integer::Fp::from)
+                .collect::<Vec<_>>()
+        };
+        let (c0, c1) = (to_integer(c0), to_integer(c1));
+        assert_eq!(expected, interleave::<Fp2, _, 2>([&c0, &c1]));
+    }
+}
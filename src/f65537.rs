@@ -0,0 +1,623 @@
+//! The Fermat prime field `p = 65537 = 2^16 + 1` over f32.
+//!
+//! Products of two elements reach `2^32` so the FMA low part is carried into
+//! the reduction ([`mul_mod_fma`]). [`reduce_numeric_half`] reduces any exact
+//! integer f32, e.g. accumulated products, and [`Fp`] wraps the canonical
+//! residue as a field element. [`conv1d`] and [`conv2d`] are exact
+//! sliding-window convolutions for image-style workloads.
+use crate::field::Field;
+use crate::macros::impl_assign_ops;
+use crate::macros::impl_hash;
+use crate::macros::impl_le_bytes;
+use crate::macros::impl_num_traits;
+use crate::macros::impl_pod;
+use crate::macros::impl_serde;
+use crate::macros::impl_sum_product;
+use crate::macros::impl_word;
+use crate::reduction::Fma;
+use crate::reduction::Reducer;
+use crate::reduction::Reducible;
+use crate::rounding;
+use crate::rounding::Round;
+use crate::stats;
+use num_traits::MulAdd;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use std::fmt::Display;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+
+pub const MODULUS: u32 = 65537;
+
+/// [`MODULUS`] as an f32 for the reductions
+pub(crate) const MODULUS_F32: f32 = MODULUS as f32;
+const U: f32 = 1.0 / MODULUS_F32;
+
+/// Rounding primitive used for the quotient in the 65537 reductions
+pub type Rounding = rounding::Trunc;
+
+/// Integer valued f32 accepted by [`reduce_numeric_half`]
+///
+/// Magnitudes are bounded by `2^24` (the range where every integer is exact
+/// in an f32). Negative values, e.g. accumulations of FMA low parts, are
+/// accepted: over the whole range the quotient is off by at most one so a
+/// single correction step yields the canonical residue.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Unreduced65537(f32);
+
+impl Unreduced65537 {
+    pub const MAX: f32 = (1u32 << f32::MANTISSA_DIGITS) as f32;
+
+    #[inline]
+    #[must_use]
+    pub fn new(v: f32) -> Self {
+        assert!(
+            v.abs() <= Self::MAX && v.fract() == 0.0,
+            "{v} is not an exact integer in [-2^24, 2^24]"
+        );
+        Self(v)
+    }
+}
+
+impl From<u16> for Unreduced65537 {
+    #[inline]
+    fn from(v: u16) -> Self {
+        Self(v.into())
+    }
+}
+
+impl From<i16> for Unreduced65537 {
+    #[inline]
+    fn from(v: i16) -> Self {
+        Self(v.into())
+    }
+}
+
+impl From<Unreduced65537> for f32 {
+    #[inline]
+    fn from(v: Unreduced65537) -> f32 {
+        v.0
+    }
+}
+
+/// Reduces `a` to its canonical residue in `[0, 65537)`
+pub fn reduce_numeric_half(a: Unreduced65537) -> f32 {
+    let a = a.0;
+    let b = a * U;
+    let c = Rounding::round_f32(b);
+    // fused so `c * MODULUS` isn't rounded when it exceeds 2^24
+    let d = (-c).mul_add(MODULUS_F32, a);
+    stats::record(stats::Site::Reduce65537, d.into(), MODULUS_F32.into());
+    if d >= MODULUS_F32 {
+        d - MODULUS_F32
+    } else if d < 0.0 {
+        d + MODULUS_F32
+    } else {
+        d
+    }
+}
+
+/// Computes `(a_1 * a_2) mod p`
+pub(crate) fn mul_mod_fma(a1: f32, a2: f32) -> f32 {
+    mul_mod_fma_with::<Rounding>(a1, a2)
+}
+
+/// Computes `(a_1 * a_2) mod p` with the quotient rounded by `R`
+fn mul_mod_fma_with<R: Round>(a1: f32, a2: f32) -> f32 {
+    let h = a1 * a2;
+    let l = a1.mul_add(a2, -h);
+    let e = Fma::<R>::reduce(h, l, MODULUS_F32, U);
+    stats::record(stats::Site::Mul65537, e.into(), MODULUS_F32.into());
+    if e >= MODULUS_F32 {
+        e - MODULUS_F32
+    } else if e < 0.0 {
+        e + MODULUS_F32
+    } else {
+        e
+    }
+}
+
+/// Computes `a^exp mod p` with square-and-multiply over `mul_mod_fma`
+pub fn pow_mod_fma(mut a: f32, mut exp: u64) -> f32 {
+    let mut res = 1.0;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            res = mul_mod_fma(res, a);
+        }
+        a = mul_mod_fma(a, a);
+        exp >>= 1;
+    }
+    res
+}
+
+/// Generator of the multiplicative group
+pub const GENERATOR: Fp = Fp::new(3);
+
+/// Element of the 65537 field stored as a canonical integer valued f32
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Fp(f32);
+
+impl Fp {
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(1);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(v: u32) -> Self {
+        debug_assert!(v < MODULUS);
+        Self(v as f32)
+    }
+
+    /// Computes `self^exp` by square-and-multiply
+    pub fn pow(self, exp: u64) -> Self {
+        Field::pow(self, exp)
+    }
+
+    /// Computes `self^-1` as `self^(p - 2)` by Fermat's little theorem.
+    /// Returns `None` for zero.
+    pub fn inverse(self) -> Option<Self> {
+        (self != Self::ZERO).then(|| self.pow(MODULUS as u64 - 2))
+    }
+
+    /// Computes `self * a + b` with a single reduction. `b` is folded into the
+    /// low part of the product (exact as both are integers below `2^18`) so
+    /// the remainder lands in `(-p, 3p)`.
+    #[inline]
+    #[must_use]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        let h = self.0 * a.0;
+        let l = self.0.mul_add(a.0, -h) + b.0;
+        let e = Fma::<Rounding>::reduce(h, l, MODULUS_F32, U);
+        Self(if e >= 2.0 * MODULUS_F32 {
+            e - 2.0 * MODULUS_F32
+        } else if e >= MODULUS_F32 {
+            e - MODULUS_F32
+        } else if e < 0.0 {
+            e + MODULUS_F32
+        } else {
+            e
+        })
+    }
+
+    /// Multiplication with the remainder computed by `S`
+    #[inline]
+    #[must_use]
+    pub fn mul_reduced<S: Reducer<f32>>(self, rhs: Self) -> Self {
+        let h = self.0 * rhs.0;
+        let l = self.0.mul_add(rhs.0, -h);
+        let e = S::reduce(h, l, MODULUS_F32, U);
+        Self(if e >= MODULUS_F32 {
+            e - MODULUS_F32
+        } else if e < 0.0 {
+            e + MODULUS_F32
+        } else {
+            e
+        })
+    }
+}
+
+impl Display for Fp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        u32::from(*self).fmt(f)
+    }
+}
+
+impl Add for Fp {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let a = self.0 + rhs.0;
+        Self(if a >= MODULUS_F32 { a - MODULUS_F32 } else { a })
+    }
+}
+
+impl Sub for Fp {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let a = self.0 - rhs.0;
+        Self(if a < 0.0 { a + MODULUS_F32 } else { a })
+    }
+}
+
+impl Neg for Fp {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(if self.0 == 0.0 {
+            0.0
+        } else {
+            MODULUS_F32 - self.0
+        })
+    }
+}
+
+impl Div for Fp {
+    type Output = Self;
+
+    /// Panics if `rhs` is zero
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse().expect("division by zero")
+    }
+}
+
+impl Mul for Fp {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(mul_mod_fma(self.0, rhs.0))
+    }
+}
+
+impl_assign_ops!(
+    Fp;
+    AddAssign::add_assign => +,
+    SubAssign::sub_assign => -,
+    MulAssign::mul_assign => *,
+);
+
+impl_sum_product!(Fp);
+
+impl_num_traits!(Fp);
+
+impl_hash!(Fp);
+
+impl_serde!(Fp);
+
+impl_le_bytes!(Fp, u32);
+
+impl_pod!(Fp);
+impl_word!(Fp, f32);
+
+impl From<u32> for Fp {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self::new(value % MODULUS)
+    }
+}
+
+impl From<Fp> for u32 {
+    #[inline]
+    fn from(value: Fp) -> Self {
+        value.0 as u32
+    }
+}
+
+impl Field for Fp {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    const ORDER: u64 = MODULUS as u64;
+
+    fn from_canonical_u32(v: u32) -> Self {
+        Self::new(v)
+    }
+
+    fn to_canonical_u64(self) -> u64 {
+        u32::from(self).into()
+    }
+}
+
+impl MulAdd for Fp {
+    type Output = Self;
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Fp::mul_add(self, a, b)
+    }
+}
+
+impl Reducible for Fp {
+    type Float = f32;
+
+    fn mul_reduced<S: Reducer<f32>>(self, rhs: Self) -> Self {
+        Fp::mul_reduced::<S>(self, rhs)
+    }
+}
+
+impl Distribution<Fp> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Fp {
+        Fp::new(rng.gen_range(0..MODULUS))
+    }
+}
+
+/// Sliding-window dot products `out[i] = sum_j signal[i + j] * kernel[j]` over
+/// the windows that fit entirely in `signal` (no padding), so the output has
+/// `signal.len() - kernel.len() + 1` elements. Each term is accumulated with
+/// [`Fp::mul_add`], one reduction per term. This is the portable scalar
+/// kernel, a packed (e.g. NEON f32x4) one can be checked against it.
+pub fn conv1d(signal: &[Fp], kernel: &[Fp]) -> Vec<Fp> {
+    assert!(
+        !kernel.is_empty() && kernel.len() <= signal.len(),
+        "kernel must be nonempty and fit in the signal"
+    );
+    signal
+        .windows(kernel.len())
+        .map(|window| dot(window, kernel))
+        .collect()
+}
+
+/// [`conv1d`] over row-major images: `out[y][x] = sum_{i, j} image[y + i][x +
+/// j] * kernel[i][j]` with `width` and `kernel_width` columns. The output is
+/// row-major with `width - kernel_width + 1` columns.
+pub fn conv2d(image: &[Fp], width: usize, kernel: &[Fp], kernel_width: usize) -> Vec<Fp> {
+    assert!(width > 0 && kernel_width > 0, "widths must be nonzero");
+    assert!(
+        image.len().is_multiple_of(width) && kernel.len().is_multiple_of(kernel_width),
+        "length is not a multiple of the width"
+    );
+    let (height, kernel_height) = (image.len() / width, kernel.len() / kernel_width);
+    assert!(
+        kernel_height > 0 && kernel_width <= width && kernel_height <= height,
+        "kernel must be nonempty and fit in the image"
+    );
+    let rows = image.chunks_exact(width).collect::<Vec<&[Fp]>>();
+    let mut out = Vec::with_capacity((height - kernel_height + 1) * (width - kernel_width + 1));
+    for window in rows.windows(kernel_height) {
+        for x in 0..=width - kernel_width {
+            out.push(
+                window
+                    .iter()
+                    .zip(kernel.chunks_exact(kernel_width))
+                    .fold(Fp::ZERO, |acc, (row, kernel)| {
+                        acc + dot(&row[x..x + kernel_width], kernel)
+                    }),
+            );
+        }
+    }
+    out
+}
+
+/// Computes `sum_i a[i] * b[i]` with one reduction per term
+fn dot(a: &[Fp], b: &[Fp]) -> Fp {
+    a.iter()
+        .zip(b)
+        .fold(Fp::ZERO, |acc, (&a, &b)| a.mul_add(b, acc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    /// Every `a` against a sample of `b`, the full square takes too long
+    #[test]
+    fn mul_mod_fma_all_rows() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let columns = (0..16)
+            .map(|_| rng.gen_range(0..MODULUS))
+            .chain([0, 1, 2, 65535, 65536])
+            .collect::<Vec<u32>>();
+        for a in 0..MODULUS {
+            for &b in &columns {
+                let expected = (a as u64 * b as u64 % MODULUS as u64) as f32;
+                let actual = mul_mod_fma(a as f32, b as f32);
+                assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+            }
+        }
+    }
+
+    #[test]
+    fn fp_matches_u64_reference() {
+        let p = MODULUS as u64;
+        let mut rng = StdRng::from_seed([1; 32]);
+        let values = (0..1 << 16)
+            .map(|_| (rng.gen_range(0..p), rng.gen_range(0..p)))
+            .chain([(0, 0), (1, p - 1), (p - 1, p - 1), (p - 1, 1)]);
+        for (a, b) in values {
+            let (x, y) = (Fp::new(a as u32), Fp::new(b as u32));
+            assert_eq!(a * b % p, u32::from(x * y) as u64, "mismatch: `{a} * {b}`");
+            assert_eq!(
+                (a + b) % p,
+                u32::from(x + y) as u64,
+                "mismatch: `{a} + {b}`"
+            );
+            assert_eq!(
+                (a + p - b) % p,
+                u32::from(x - y) as u64,
+                "mismatch: `{a} - {b}`"
+            );
+            assert_eq!((p - a) % p, u32::from(-x) as u64, "mismatch: `-{a}`");
+        }
+    }
+
+    #[test]
+    fn fp_inverse() {
+        for a in 1..MODULUS {
+            let x = Fp::new(a);
+            assert_eq!(Fp::ONE, x * x.inverse().unwrap(), "mismatch: `{a}^-1`");
+        }
+        assert_eq!(None, Fp::ZERO.inverse());
+        assert_eq!(Fp::new(2), Fp::new(6) / Fp::new(3));
+    }
+
+    #[test]
+    fn fp_generator_and_display() {
+        assert_eq!(MODULUS as u64 - 1, GENERATOR.multiplicative_order());
+        // 2^16 = -1
+        assert_eq!(-Fp::ONE, Fp::from(2).pow(16));
+        assert_eq!(Fp::ZERO, Fp::from(MODULUS));
+        assert_eq!("65536", (-Fp::ONE).to_string());
+        assert_eq!("3", GENERATOR.to_string());
+    }
+
+    #[test]
+    fn reduce_numeric_half_full_domain() {
+        let max = Unreduced65537::MAX as i64;
+        let values = (-max..=max).step_by(7).chain([
+            -max,
+            -max + 1,
+            -65537,
+            -65536,
+            -1,
+            0,
+            1,
+            65536,
+            65537,
+            max - 1,
+            max,
+        ]);
+        for a in values {
+            let expected = a.rem_euclid(MODULUS as i64) as f32;
+            let actual = reduce_numeric_half(Unreduced65537::new(a as f32));
+            assert_eq!(expected, actual, "mismatch: `{a}`");
+        }
+    }
+
+    #[test]
+    fn reduce_numeric_half_fma_low_parts() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..1 << 16 {
+            // accumulate the (signed) low parts of several products
+            let mut acc = 0.0f32;
+            for _ in 0..8 {
+                let a = (rng.gen::<u32>() % MODULUS) as f32;
+                let b = (rng.gen::<u32>() % MODULUS) as f32;
+                let h = a * b;
+                acc += a.mul_add(b, -h);
+            }
+            let expected = (acc as i64).rem_euclid(MODULUS as i64) as f32;
+            let actual = reduce_numeric_half(Unreduced65537::new(acc));
+            assert_eq!(expected, actual, "mismatch: `{acc}`");
+        }
+        assert_eq!(32769.0, reduce_numeric_half(i16::MIN.into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn unreduced_65537_rejects_out_of_range() {
+        let _ = Unreduced65537::new(Unreduced65537::MAX * 2.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unreduced_65537_rejects_fractions() {
+        let _ = Unreduced65537::new(0.5);
+    }
+
+    fn mul_mod_fma_with_rounding<R: Round>() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let edge_cases = (0..1024)
+            .map(|_| rng.gen::<u32>() % MODULUS)
+            .chain([0, 1, 2, 65535, 65536])
+            .collect::<Vec<u32>>();
+        for &a in &edge_cases {
+            for &b in &edge_cases {
+                let expected = (a as u64 * b as u64 % MODULUS as u64) as f32;
+                let actual = mul_mod_fma_with::<R>(a as f32, b as f32);
+                assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+            }
+        }
+    }
+
+    #[test]
+    fn mul_mod_fma_trunc() {
+        mul_mod_fma_with_rounding::<rounding::Trunc>();
+    }
+
+    #[test]
+    fn mul_mod_fma_floor() {
+        mul_mod_fma_with_rounding::<rounding::Floor>();
+    }
+
+    #[test]
+    fn mul_mod_fma_nearest() {
+        mul_mod_fma_with_rounding::<rounding::Nearest>();
+    }
+
+    #[test]
+    fn pow_mod_fma_matches_u128_reference() {
+        let pow_ref = |base: u128, mut exp: u64| {
+            let (mut base, mut res) = (base, 1u128);
+            while exp != 0 {
+                if exp & 1 == 1 {
+                    res = res * base % 65537;
+                }
+                base = base * base % 65537;
+                exp >>= 1;
+            }
+            res as f32
+        };
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..1024 {
+            let a = rng.gen::<u32>() % MODULUS;
+            let exp = rng.gen::<u64>();
+            assert_eq!(
+                pow_ref(a.into(), exp),
+                pow_mod_fma(a as f32, exp),
+                "mismatch: `{a}^{exp}`"
+            );
+        }
+        assert_eq!(1.0, pow_mod_fma(0.0, 0));
+        assert_eq!(0.0, pow_mod_fma(0.0, 5));
+        assert_eq!(1.0, pow_mod_fma(3.0, 65536));
+        assert_eq!(65536.0, pow_mod_fma(3.0, 32768));
+    }
+    /// `sum_j a[j] * b[j] mod p` on u64
+    fn dot_reference(a: &[Fp], b: &[Fp]) -> u32 {
+        let p = MODULUS as u64;
+        let sum = a.iter().zip(b).fold(0, |acc, (&a, &b)| {
+            (acc + u64::from(u32::from(a)) * u64::from(u32::from(b))) % p
+        });
+        sum as u32
+    }
+
+    #[test]
+    fn conv1d_matches_u64_reference() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let signal = (0..256)
+            .map(|_| rng.gen())
+            .chain([Fp::new(MODULUS - 1); 16])
+            .collect::<Vec<Fp>>();
+        for len in [1, 3, 16, 272] {
+            let kernel = (0..len - 1)
+                .map(|_| rng.gen())
+                .chain([Fp::new(MODULUS - 1)])
+                .collect::<Vec<Fp>>();
+            let out = conv1d(&signal, &kernel);
+            assert_eq!(signal.len() - len + 1, out.len());
+            for (i, &v) in out.iter().enumerate() {
+                let expected = dot_reference(&signal[i..i + len], &kernel);
+                assert_eq!(expected, u32::from(v), "mismatch at {i} (len = {len})");
+            }
+        }
+    }
+
+    #[test]
+    fn conv2d_matches_u64_reference() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let (width, height) = (37, 23);
+        let image = (0..width * height).map(|_| rng.gen()).collect::<Vec<Fp>>();
+        for (kernel_width, kernel_height) in [(1, 1), (3, 3), (5, 2), (37, 23)] {
+            let kernel = (0..kernel_width * kernel_height)
+                .map(|_| rng.gen())
+                .collect::<Vec<Fp>>();
+            let out = conv2d(&image, width, &kernel, kernel_width);
+            let out_width = width - kernel_width + 1;
+            assert_eq!(out_width * (height - kernel_height + 1), out.len());
+            for (k, &v) in out.iter().enumerate() {
+                let (y, x) = (k / out_width, k % out_width);
+                let expected = (0..kernel_height).fold(0, |acc, i| {
+                    let row = &image[(y + i) * width + x..][..kernel_width];
+                    let kernel = &kernel[i * kernel_width..][..kernel_width];
+                    (acc + dot_reference(row, kernel)) % MODULUS
+                });
+                assert_eq!(expected, u32::from(v), "mismatch at ({y}, {x})");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "kernel must be nonempty and fit in the signal")]
+    fn conv1d_rejects_long_kernels() {
+        conv1d(&[Fp::ONE; 2], &[Fp::ONE; 3]);
+    }
+}
@@ -0,0 +1,296 @@
+//! C ABI over the bulk kernels so they can be driven from C++/Python
+//! benchmark harnesses and compared against other implementations directly.
+//! Add/mul are exported for the scalar fields of [`crate::dyn_field`] with a
+//! single-float or `u32` representation, named after their ids: the three
+//! fp20 backends, 65537, `FpF32<1048573>`, Mersenne31 and BabyBear. The NTT
+//! is exported over [`Fp2`].
+//!
+//! Elements cross the boundary in their in-memory representation: canonical
+//! residues as `uint32_t` for the integer backend, `float`/`double` for the
+//! float backends and `[c0, c1]` pairs of `uint32_t` for [`Fp2`]. Slices are a
+//! pointer plus a length in elements; a null pointer is accepted when the
+//! length is zero. Every function returns [`OK`] or an error code: invalid
+//! arguments are reported through the return value and a panic is caught
+//! and reported as [`PANICKED`] instead of unwinding across the boundary.
+//!
+//! ```c
+//! int ark_fp20_u32_add(uint32_t *dst, const uint32_t *src, size_t len);
+//! int ark_fp20_u32_mul(uint32_t *dst, const uint32_t *src, size_t len);
+//! int ark_fp20_f32_add(float *dst, const float *src, size_t len);
+//! int ark_fp20_f32_mul(float *dst, const float *src, size_t len);
+//! int ark_fp20_f64_add(double *dst, const double *src, size_t len);
+//! int ark_fp20_f64_mul(double *dst, const double *src, size_t len);
+//! int ark_fermat65537_f32_add(float *dst, const float *src, size_t len);
+//! int ark_fermat65537_f32_mul(float *dst, const float *src, size_t len);
+//! int ark_fp1048573_f32_add(float *dst, const float *src, size_t len);
+//! int ark_fp1048573_f32_mul(float *dst, const float *src, size_t len);
+//! int ark_m31_f64_add(double *dst, const double *src, size_t len);
+//! int ark_m31_f64_mul(double *dst, const double *src, size_t len);
+//! int ark_baby_bear_f64_add(double *dst, const double *src, size_t len);
+//! int ark_baby_bear_f64_mul(double *dst, const double *src, size_t len);
+//! int ark_fp20_ext2_ntt(uint32_t *values, size_t len);
+//! int ark_fp20_ext2_intt(uint32_t *values, size_t len);
+//! ```
+use crate::baby_bear;
+use crate::f65537;
+use crate::fp20;
+use crate::fp20::extension::Fp2;
+use crate::fp_f32::FpF32;
+use crate::m31;
+use crate::slice;
+use std::panic::AssertUnwindSafe;
+
+/// Returned on success
+pub const OK: i32 = 0;
+/// Returned by the transforms for a length that isn't a supported domain size
+pub const INVALID_LENGTH: i32 = -1;
+/// Returned when the Rust side panicked, the output is unspecified
+pub const PANICKED: i32 = -2;
+
+/// Runs `f`, turning a panic into [`PANICKED`] since unwinding into C is
+/// undefined
+fn guard(f: impl FnOnce() -> i32) -> i32 {
+    std::panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(PANICKED)
+}
+
+/// # Safety
+///
+/// `ptr` must be valid for `len` reads of `F` unless `len` is zero
+unsafe fn as_slice<'a, F>(ptr: *const F, len: usize) -> &'a [F] {
+    if len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(ptr, len)
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must be valid for `len` reads and writes of `F` and not aliased
+/// unless `len` is zero
+unsafe fn as_mut_slice<'a, F>(ptr: *mut F, len: usize) -> &'a mut [F] {
+    if len == 0 {
+        &mut []
+    } else {
+        std::slice::from_raw_parts_mut(ptr, len)
+    }
+}
+
+/// Exports `dst[i] += src[i]` and `dst[i] *= src[i]` for a backend whose
+/// element type is `repr(transparent)` over `$repr`, returning [`OK`]
+macro_rules! export_kernels {
+    ($fp:ty, $repr:ty; $add:ident, $mul:ident) => {
+        #[doc = concat!("[`slice::add`] over [`", stringify!($fp), "`]")]
+        ///
+        /// # Safety
+        ///
+        /// `dst` and `src` must be valid for `len` canonical elements and
+        /// must not overlap
+        #[no_mangle]
+        pub unsafe extern "C" fn $add(dst: *mut $repr, src: *const $repr, len: usize) -> i32 {
+            guard(|| {
+                let dst = as_mut_slice(dst.cast::<$fp>(), len);
+                slice::add(dst, as_slice(src.cast::<$fp>(), len));
+                OK
+            })
+        }
+
+        #[doc = concat!("[`slice::hadamard`] over [`", stringify!($fp), "`]")]
+        ///
+        /// # Safety
+        ///
+        /// `dst` and `src` must be valid for `len` canonical elements and
+        /// must not overlap
+        #[no_mangle]
+        pub unsafe extern "C" fn $mul(dst: *mut $repr, src: *const $repr, len: usize) -> i32 {
+            guard(|| {
+                let dst = as_mut_slice(dst.cast::<$fp>(), len);
+                slice::hadamard(dst, as_slice(src.cast::<$fp>(), len));
+                OK
+            })
+        }
+    };
+}
+
+export_kernels!(fp20::integer::Fp, u32; ark_fp20_u32_add, ark_fp20_u32_mul);
+export_kernels!(fp20::single_precision::Fp, f32; ark_fp20_f32_add, ark_fp20_f32_mul);
+export_kernels!(fp20::double_precision::Fp, f64; ark_fp20_f64_add, ark_fp20_f64_mul);
+export_kernels!(f65537::Fp, f32; ark_fermat65537_f32_add, ark_fermat65537_f32_mul);
+export_kernels!(FpF32<1048573>, f32; ark_fp1048573_f32_add, ark_fp1048573_f32_mul);
+export_kernels!(m31::Fp, f64; ark_m31_f64_add, ark_m31_f64_mul);
+export_kernels!(baby_bear::Fp, f64; ark_baby_bear_f64_add, ark_baby_bear_f64_mul);
+
+/// Validates the length before building the domain (which panics)
+fn domain(len: usize) -> Option<crate::domain::Domain<Fp2>> {
+    (len.is_power_of_two() && len <= fp20::extension::MAX_NTT_SIZE).then(|| Fp2::domain(len))
+}
+
+/// [`fp20::extension::ntt`] over the subgroup of order `len`. Returns
+/// [`INVALID_LENGTH`] unless `len` is a power of two up to
+/// [`fp20::extension::MAX_NTT_SIZE`].
+///
+/// # Safety
+///
+/// `values` must be valid for `2 * len` canonical `uint32_t`s
+#[no_mangle]
+pub unsafe extern "C" fn ark_fp20_ext2_ntt(values: *mut u32, len: usize) -> i32 {
+    guard(|| {
+        let Some(domain) = domain(len) else {
+            return INVALID_LENGTH;
+        };
+        fp20::extension::ntt(as_mut_slice(values.cast::<Fp2>(), len), &domain);
+        OK
+    })
+}
+
+/// Inverse of [`ark_fp20_ext2_ntt`]
+///
+/// # Safety
+///
+/// `values` must be valid for `2 * len` canonical `uint32_t`s
+#[no_mangle]
+pub unsafe extern "C" fn ark_fp20_ext2_intt(values: *mut u32, len: usize) -> i32 {
+    guard(|| {
+        let Some(domain) = domain(len) else {
+            return INVALID_LENGTH;
+        };
+        fp20::extension::intt(as_mut_slice(values.cast::<Fp2>(), len), &domain);
+        OK
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::distributions::Standard;
+    use rand::prelude::Distribution;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn kernels_match_slice() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let a = (0..1000)
+            .map(|_| rng.gen())
+            .collect::<Vec<fp20::integer::Fp>>();
+        let b = (0..1000)
+            .map(|_| rng.gen())
+            .collect::<Vec<fp20::integer::Fp>>();
+        let (mut sum, mut product) = (a.clone(), a.clone());
+        slice::add(&mut sum, &b);
+        slice::hadamard(&mut product, &b);
+        let expected = |values: &[fp20::integer::Fp]| fp20::integer::as_u32_slice(values).to_vec();
+
+        let (a_u32, b_u32) = (expected(&a), expected(&b));
+        let mut dst = a_u32.clone();
+        assert_eq!(OK, unsafe {
+            ark_fp20_u32_add(dst.as_mut_ptr(), b_u32.as_ptr(), dst.len())
+        });
+        assert_eq!(expected(&sum), dst);
+        let mut dst = a_u32.clone();
+        assert_eq!(OK, unsafe {
+            ark_fp20_u32_mul(dst.as_mut_ptr(), b_u32.as_ptr(), dst.len())
+        });
+        assert_eq!(expected(&product), dst);
+
+        let to_f32 = |v: &[u32]| v.iter().map(|&v| v as f32).collect::<Vec<f32>>();
+        let (mut dst, src) = (to_f32(&a_u32), to_f32(&b_u32));
+        assert_eq!(OK, unsafe {
+            ark_fp20_f32_add(dst.as_mut_ptr(), src.as_ptr(), dst.len())
+        });
+        assert_eq!(to_f32(&expected(&sum)), dst);
+        let mut dst = to_f32(&a_u32);
+        assert_eq!(OK, unsafe {
+            ark_fp20_f32_mul(dst.as_mut_ptr(), src.as_ptr(), dst.len())
+        });
+        assert_eq!(to_f32(&expected(&product)), dst);
+
+        let to_f64 = |v: &[u32]| v.iter().map(|&v| v.into()).collect::<Vec<f64>>();
+        let (mut dst, src) = (to_f64(&a_u32), to_f64(&b_u32));
+        assert_eq!(OK, unsafe {
+            ark_fp20_f64_add(dst.as_mut_ptr(), src.as_ptr(), dst.len())
+        });
+        assert_eq!(to_f64(&expected(&sum)), dst);
+        let mut dst = to_f64(&a_u32);
+        assert_eq!(OK, unsafe {
+            ark_fp20_f64_mul(dst.as_mut_ptr(), src.as_ptr(), dst.len())
+        });
+        assert_eq!(to_f64(&expected(&product)), dst);
+
+        assert_eq!(OK, unsafe {
+            ark_fp20_u32_add(std::ptr::null_mut(), std::ptr::null(), 0)
+        });
+    }
+
+    type Kernel<R> = unsafe extern "C" fn(*mut R, *const R, usize) -> i32;
+
+    /// Checks `add` and `mul` against [`slice::add`] and [`slice::hadamard`]
+    /// on elements passed in their `R` representation
+    fn assert_kernels<F, R>(add: Kernel<R>, mul: Kernel<R>)
+    where
+        F: Copy + PartialEq + std::fmt::Debug + std::ops::Add<Output = F>,
+        F: std::ops::Mul<Output = F>,
+        Standard: Distribution<F>,
+    {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let a = (0..1000).map(|_| rng.gen()).collect::<Vec<F>>();
+        let b = (0..1000).map(|_| rng.gen()).collect::<Vec<F>>();
+        let (mut sum, mut product) = (a.clone(), a.clone());
+        slice::add(&mut sum, &b);
+        slice::hadamard(&mut product, &b);
+
+        for (kernel, expected) in [(add, sum), (mul, product)] {
+            let mut dst = a.clone();
+            let ptr = dst.as_mut_ptr().cast::<R>();
+            assert_eq!(OK, unsafe { kernel(ptr, b.as_ptr().cast(), dst.len()) });
+            assert_eq!(expected, dst);
+            assert_eq!(OK, unsafe {
+                kernel(std::ptr::null_mut(), std::ptr::null(), 0)
+            });
+        }
+    }
+
+    #[test]
+    fn field_kernels_match_slice() {
+        assert_kernels::<f65537::Fp, f32>(ark_fermat65537_f32_add, ark_fermat65537_f32_mul);
+        assert_kernels::<FpF32<1048573>, f32>(ark_fp1048573_f32_add, ark_fp1048573_f32_mul);
+        assert_kernels::<m31::Fp, f64>(ark_m31_f64_add, ark_m31_f64_mul);
+        assert_kernels::<baby_bear::Fp, f64>(ark_baby_bear_f64_add, ark_baby_bear_f64_mul);
+    }
+
+    #[test]
+    fn guard_catches_panics() {
+        assert_eq!(OK, guard(|| OK));
+        assert_eq!(PANICKED, guard(|| panic!("in the kernel")));
+    }
+
+    #[test]
+    fn ntt_round_trip() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let coeffs = (0..16).map(|_| rng.gen()).collect::<Vec<Fp2>>();
+        let mut expected = coeffs.clone();
+        fp20::extension::ntt(&mut expected, &Fp2::domain(16));
+
+        let mut values = coeffs
+            .iter()
+            .flat_map(|v| [u32::from(v.c0), u32::from(v.c1)])
+            .collect::<Vec<u32>>();
+        assert_eq!(OK, unsafe { ark_fp20_ext2_ntt(values.as_mut_ptr(), 16) });
+        let packed = values
+            .chunks(2)
+            .map(|v| Fp2::new(v[0], v[1]))
+            .collect::<Vec<Fp2>>();
+        assert_eq!(expected, packed);
+        assert_eq!(OK, unsafe { ark_fp20_ext2_intt(values.as_mut_ptr(), 16) });
+        let unpacked = fp20::extension::unpack(&coeffs);
+        assert_eq!(fp20::integer::as_u32_slice(&unpacked), values);
+
+        for len in [0, 3, 32] {
+            let mut values = vec![0; 2 * len];
+            let ptr = values.as_mut_ptr();
+            assert_eq!(INVALID_LENGTH, unsafe { ark_fp20_ext2_ntt(ptr, len) });
+            assert_eq!(INVALID_LENGTH, unsafe { ark_fp20_ext2_intt(ptr, len) });
+        }
+    }
+}
@@ -1,10 +1,112 @@
+//! Prime (and extension) field interface shared by every backend, so
+//! experiments like NTTs and hashes can be written once over all of them.
+use crate::macros::impl_assign_ops;
+use crate::macros::impl_hash;
+use crate::macros::impl_le_bytes;
+use crate::macros::impl_num_traits;
+use crate::macros::impl_pod;
+use crate::macros::impl_serde;
+use crate::macros::impl_word;
+use crate::utils::factor;
 use core::fmt::Debug;
+use core::fmt::Display;
 use std::ops::Add;
 use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
 
-trait Field:
-    Clone + Copy + Debug + Sized + Add<Output = Self> + Mul<Output = Self> + From<u32>
+/// Finite field of [`Field::order`] elements. Every element has a canonical
+/// integer representative in `[0, order)`: its residue for prime fields and
+/// `c0 + p * c1` for a quadratic extension `c0 + c1 * u`.
+pub trait Field:
+    Clone
+    + Copy
+    + Debug
+    + Sized
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + From<u32>
 {
+    /// Additive identity
+    const ZERO: Self;
+
+    /// Multiplicative identity
+    const ONE: Self;
+
+    /// Number of elements in the field, for const contexts like the range
+    /// checks of [`crate::field_consts`]
+    const ORDER: u64;
+
+    /// Number of elements in the field
+    fn order() -> u64 {
+        Self::ORDER
+    }
+
+    /// Element with canonical representative `v`. Unlike [`From<u32>`] this
+    /// doesn't reduce, `v` must be below [`Field::order`].
+    fn from_canonical_u32(v: u32) -> Self;
+
+    /// Canonical representative in `[0, order)`
+    fn to_canonical_u64(self) -> u64;
+
+    /// Canonical representative in `[0, order)`. Panics if it doesn't fit in
+    /// a `u32`, which can only happen for fields of order above `2^32`.
+    fn to_canonical_u32(self) -> u32 {
+        u32::try_from(self.to_canonical_u64()).expect("canonical value exceeds u32")
+    }
+
+    /// Computes `self + self`
+    fn double(self) -> Self {
+        self + self
+    }
+
+    /// Computes `self * self`
+    fn square(self) -> Self {
+        self * self
+    }
+
+    /// Computes `self^exp` by repeated squaring
+    fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut res = Self::ONE;
+        while exp != 0 {
+            if exp & 1 == 1 {
+                res = res * base;
+            }
+            base = base.square();
+            exp >>= 1;
+        }
+        res
+    }
+
+    /// Computes `self^-1` as `self^(order - 2)`. Returns `None` for zero.
+    fn inverse(self) -> Option<Self> {
+        (self != Self::ZERO).then(|| self.pow(Self::order() - 2))
+    }
+
+    /// Returns the smallest `k > 0` such that `self^k = 1`.
+    /// Panics if `self` is zero.
+    fn multiplicative_order(self) -> u64 {
+        let group_order = Self::order() - 1;
+        assert!(
+            self.pow(group_order) == Self::ONE,
+            "zero has no multiplicative order"
+        );
+        // strip prime factors from the group order while `self^k = 1` holds
+        let mut k = group_order;
+        for (prime, exponent) in factor(group_order) {
+            for _ in 0..exponent {
+                if self.pow(k / prime) != Self::ONE {
+                    break;
+                }
+                k /= prime;
+            }
+        }
+        k
+    }
 }
 
 // /// Source: https://arxiv.org/ftp/arxiv/papers/1407/1407.3383.pdf (function 16)
@@ -12,23 +114,64 @@ trait Field:
 //     ($t:ty) => {};
 // }
 
+/// `F_p` for `p = 2^32 - 5`, the largest 32-bit prime, over three float
+/// representations to see how each precision copes with a full 32-bit
+/// modulus. Every reduction folds with `2^32 = 5 mod p`.
 pub mod numeric {
     use super::*;
+    use rand::distributions::Standard;
+    use rand::prelude::Distribution;
+
+    pub const MODULUS: u32 = u32::MAX - 4;
+
+    /// Generator of the multiplicative group
+    pub const GENERATOR: u32 = 2;
 
+    /// [`crate::fp_f64::FpF64`] at the 32-bit modulus: products reach `2^64`
+    /// and the FMA low part is carried into the reduction
     pub mod double_precision {
         use super::*;
+        use crate::fp_f64::FpF64;
+
+        type Inner = FpF64<{ MODULUS as u64 }>;
 
         #[repr(transparent)]
-        #[derive(Clone, Copy, Debug)]
-        pub struct Fp(f64);
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct Fp(Inner);
+
+        impl Fp {
+            /// Computes `self^exp` by square-and-multiply
+            pub fn pow(self, exp: u64) -> Self {
+                Field::pow(self, exp)
+            }
+        }
+
+        impl Field for Fp {
+            const ZERO: Self = Fp(Inner::new(0));
+            const ONE: Self = Fp(Inner::new(1));
+
+            const ORDER: u64 = MODULUS as u64;
 
-        impl Field for Fp {}
+            fn from_canonical_u32(v: u32) -> Self {
+                Fp(Inner::new(v.into()))
+            }
+
+            fn to_canonical_u64(self) -> u64 {
+                self.0.into()
+            }
+        }
+
+        impl Display for Fp {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.to_canonical_u64(), f)
+            }
+        }
 
         impl Add for Fp {
             type Output = Self;
 
             fn add(self, rhs: Self) -> Self {
-                todo!()
+                Fp(self.0 + rhs.0)
             }
         }
 
@@ -36,31 +179,137 @@ pub mod numeric {
             type Output = Self;
 
             fn mul(self, rhs: Self) -> Self {
-                todo!()
+                Fp(self.0 * rhs.0)
+            }
+        }
+
+        impl Sub for Fp {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Fp(self.0 - rhs.0)
             }
         }
 
+        impl Neg for Fp {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Fp(-self.0)
+            }
+        }
+
+        impl_assign_ops!(
+            Fp;
+            AddAssign::add_assign => +,
+            SubAssign::sub_assign => -,
+            MulAssign::mul_assign => *,
+        );
+
+        impl_num_traits!(Fp);
+
+        impl_hash!(Fp);
+
+        impl_serde!(Fp);
+
+        impl_le_bytes!(Fp, u32);
+
+        impl_pod!(Fp);
+        impl_word!(Fp, Inner);
+
         impl From<u32> for Fp {
             fn from(value: u32) -> Self {
-                todo!()
+                Fp(Inner::from(value))
+            }
+        }
+
+        impl Distribution<Fp> for Standard {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Fp {
+                Fp::from_canonical_u32(rng.gen_range(0..MODULUS))
             }
         }
     }
 
+    /// Float-float: an element `x` is stored as `[hi, lo]` with `hi = fl(x)`
+    /// and `lo = x - hi` (an integer with `|lo| <= 2^7`). Products are formed
+    /// exactly from the error-free transformations in [`crate::eft`] and
+    /// every term is folded with `2^32 = 5` into an exact accumulator.
     pub mod single_precision {
         use super::*;
+        use crate::eft::two_prod;
+        use crate::eft::two_sum;
+
+        /// The modulus as a normalized float-float: `2^32` and `-5`
+        const MODULUS_FF: [f32; 2] = [TWO_POW_32, -5.0];
+        const TWO_POW_32: f32 = (1u64 << 32) as f32;
 
         #[repr(transparent)]
-        #[derive(Clone, Copy, Debug)]
+        #[derive(Clone, Copy, Debug, PartialEq)]
         pub struct Fp([f32; 2]);
 
-        impl Field for Fp {}
+        impl Fp {
+            const fn new(v: u32) -> Self {
+                debug_assert!(v < MODULUS);
+                let hi = v as f32;
+                Fp([hi, (v as i64 - hi as i64) as f32])
+            }
+
+            /// Canonical element equal to the normalized float-float `v` for
+            /// `|v| < 2p`. Only the sign of `hi` is inspected, which is the
+            /// sign of `v` as `hi = fl(v)`.
+            fn canonical(mut v: [f32; 2]) -> Self {
+                while v[0] < 0.0 {
+                    v = ff_add(v, MODULUS_FF);
+                }
+                loop {
+                    let w = ff_add(v, MODULUS_FF.map(Neg::neg));
+                    if w[0] < 0.0 {
+                        return Fp(v);
+                    }
+                    v = w;
+                }
+            }
+
+            /// Computes `self^exp` by square-and-multiply
+            pub fn pow(self, exp: u64) -> Self {
+                Field::pow(self, exp)
+            }
+        }
+
+        /// Sum of normalized integer valued float-floats. Exact as long as the
+        /// low parts stay below `2^24`.
+        fn ff_add(a: [f32; 2], b: [f32; 2]) -> [f32; 2] {
+            let (s, e) = two_sum(a[0], b[0]);
+            let (hi, lo) = two_sum(s, e + a[1] + b[1]);
+            [hi, lo]
+        }
+
+        impl Field for Fp {
+            const ZERO: Self = Fp([0.0, 0.0]);
+            const ONE: Self = Fp([1.0, 0.0]);
+
+            const ORDER: u64 = MODULUS as u64;
+
+            fn from_canonical_u32(v: u32) -> Self {
+                Self::new(v)
+            }
+
+            fn to_canonical_u64(self) -> u64 {
+                (self.0[0] as i64 + self.0[1] as i64) as u64
+            }
+        }
+
+        impl Display for Fp {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.to_canonical_u64(), f)
+            }
+        }
 
         impl Add for Fp {
             type Output = Self;
 
             fn add(self, rhs: Self) -> Self {
-                todo!()
+                Self::canonical(ff_add(self.0, rhs.0))
             }
         }
 
@@ -68,32 +317,135 @@ pub mod numeric {
             type Output = Self;
 
             fn mul(self, rhs: Self) -> Self {
-                todo!()
+                let [a0, a1] = self.0;
+                let [b0, b1] = rhs.0;
+                // seven exact terms, `a1 * b1` is below 2^14
+                let (p0, e0) = two_prod(a0, b0);
+                let (p1, e1) = two_prod(a0, b1);
+                let (p2, e2) = two_prod(a1, b0);
+                let p3 = a1 * b1;
+                // t = q 2^32 + r = 5q + r where the truncation keeps `r` made of
+                // the low bits of `t`. `5q` isn't exact in an f32 but `4q` and
+                // `q` are. The sum stays below 2^38 so the errors collected in
+                // `acc[1]` stay below 2^24.
+                let mut acc = [0.0f32; 2];
+                for t in [p0, e0, p1, e1, p2, e2, p3] {
+                    let q = (t * TWO_POW_32.recip()).trunc();
+                    let r = t - q * TWO_POW_32;
+                    for v in [r, 4.0 * q, q] {
+                        let (s, e) = two_sum(acc[0], v);
+                        acc = [s, acc[1] + e];
+                    }
+                }
+                // fold the small multiple of 2^32 left in the sum once more
+                let (hi, lo) = two_sum(acc[0], acc[1]);
+                let q = (hi * TWO_POW_32.recip()).trunc();
+                let (hi, lo) = two_sum(hi - q * TWO_POW_32, lo + 5.0 * q);
+                Self::canonical([hi, lo])
+            }
+        }
+
+        impl Sub for Fp {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self::canonical(ff_add(self.0, rhs.0.map(Neg::neg)))
             }
         }
 
+        impl Neg for Fp {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self::ZERO - self
+            }
+        }
+
+        impl_assign_ops!(
+            Fp;
+            AddAssign::add_assign => +,
+            SubAssign::sub_assign => -,
+            MulAssign::mul_assign => *,
+        );
+
+        impl_num_traits!(Fp);
+
+        impl_hash!(Fp);
+
+        impl_serde!(Fp);
+
+        impl_le_bytes!(Fp, u32);
+
+        impl_pod!(Fp);
+        impl_word!(Fp, [f32; 2]);
+
         impl From<u32> for Fp {
             fn from(value: u32) -> Self {
-                todo!()
+                Self::new(value % MODULUS)
+            }
+        }
+
+        impl Distribution<Fp> for Standard {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Fp {
+                Fp::new(rng.gen_range(0..MODULUS))
             }
         }
     }
 
+    /// u32 simulated with f16 limbs ([`U32`]). Sums wrap modulo `2^32` so
+    /// going past `p` is corrected by adding `2^32 - p = 5`, and the high
+    /// half of [`U32::widening_mul`] is folded in as `5 hi`.
     pub mod half_precision {
         use super::*;
         use crate::arithmetic::half_precision::U32;
 
+        const MODULUS_U32: U32 = U32::new(MODULUS);
+        const FIVE: U32 = U32::new(5);
+
         #[repr(transparent)]
-        #[derive(Clone, Copy, Debug)]
+        #[derive(Clone, Copy, Debug, PartialEq)]
         pub struct Fp(U32);
 
-        impl Field for Fp {}
+        impl Fp {
+            /// Computes `self^exp` by square-and-multiply
+            pub fn pow(self, exp: u64) -> Self {
+                Field::pow(self, exp)
+            }
+        }
+
+        impl Field for Fp {
+            const ZERO: Self = Fp(U32::new(0));
+            const ONE: Self = Fp(U32::new(1));
+
+            const ORDER: u64 = MODULUS as u64;
+
+            fn from_canonical_u32(v: u32) -> Self {
+                debug_assert!(v < MODULUS);
+                Fp(U32::new(v))
+            }
+
+            fn to_canonical_u64(self) -> u64 {
+                u32::from(self.0).into()
+            }
+        }
+
+        impl Display for Fp {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.to_canonical_u64(), f)
+            }
+        }
 
         impl Add for Fp {
             type Output = Self;
 
             fn add(self, rhs: Self) -> Self {
-                todo!()
+                let s = self.0 + rhs.0;
+                // past 2^32 or past p: `s - p = s + 5 mod 2^32`
+                Fp(if s < self.0 || s >= MODULUS_U32 {
+                    s + FIVE
+                } else {
+                    s
+                })
             }
         }
 
@@ -101,16 +453,523 @@ pub mod numeric {
             type Output = Self;
 
             fn mul(self, rhs: Self) -> Self {
-                todo!()
+                let (lo, hi) = self.0.widening_mul(rhs.0);
+                // `hi < p` as both operands are, `lo < 2^32 < 2p`
+                let lo = Fp(if lo >= MODULUS_U32 { lo + FIVE } else { lo });
+                let hi = Fp(hi);
+                lo + hi.double().double() + hi
             }
         }
 
+        impl Sub for Fp {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                let d = self.0 - rhs.0;
+                // borrowed: `d + p = d - 5 mod 2^32`
+                Fp(if self.0 < rhs.0 { d - FIVE } else { d })
+            }
+        }
+
+        impl Neg for Fp {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self::ZERO - self
+            }
+        }
+
+        impl_assign_ops!(
+            Fp;
+            AddAssign::add_assign => +,
+            SubAssign::sub_assign => -,
+            MulAssign::mul_assign => *,
+        );
+
+        impl_num_traits!(Fp);
+
+        impl_hash!(Fp);
+
+        impl_serde!(Fp);
+
+        impl_le_bytes!(Fp, u32);
+
+        impl_pod!(Fp);
+
         impl From<u32> for Fp {
             fn from(value: u32) -> Self {
-                todo!()
+                Fp(U32::new(value % MODULUS))
+            }
+        }
+
+        impl Distribution<Fp> for Standard {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Fp {
+                Fp(U32::new(rng.gen_range(0..MODULUS)))
             }
         }
     }
 }
 
 mod integer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baby_bear;
+    use crate::f65537;
+    use crate::fp20;
+    use crate::fp_f16::FpF16;
+    use crate::fp_f32::FpF32;
+    use crate::goldilocks;
+    use num_traits::Inv;
+    use num_traits::One;
+    use num_traits::Pow;
+    use num_traits::Zero;
+    use rand::distributions::Standard;
+    use rand::prelude::Distribution;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    /// Checks the provided methods against the ring ops and the canonical
+    /// representatives round trip
+    fn surface<F: Field>()
+    where
+        Standard: Distribution<F>,
+    {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..256 {
+            let x: F = rng.gen();
+            assert_eq!(x, x + F::ZERO);
+            assert_eq!(x, x * F::ONE);
+            assert_eq!(F::ZERO, x + -x);
+            assert_eq!(x + x, x.double());
+            assert_eq!(x * x, x.square());
+            assert_eq!(x * x * x, x.pow(3));
+            match x.inverse() {
+                Some(inverse) => assert_eq!(F::ONE, x * inverse),
+                None => assert_eq!(F::ZERO, x),
+            }
+
+            let canonical = x.to_canonical_u64();
+            assert!(canonical < F::order());
+            if let Ok(v) = u32::try_from(canonical) {
+                assert_eq!(x, F::from_canonical_u32(v));
+                assert_eq!(v, x.to_canonical_u32());
+            }
+        }
+        assert_eq!(None, F::ZERO.inverse());
+        assert_eq!(0, F::ZERO.to_canonical_u64());
+        assert_eq!(1, F::ONE.to_canonical_u64());
+        assert_eq!(F::ONE.double(), F::from_canonical_u32(2));
+    }
+
+    /// `num_traits` impls agree with the [`Field`] methods
+    fn num_traits_surface<F>()
+    where
+        F: Field + Zero + One + Pow<u64, Output = F> + Inv<Output = F>,
+        Standard: Distribution<F>,
+    {
+        assert!(F::zero().is_zero());
+        assert_eq!(<F as Field>::ONE, F::one());
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..64 {
+            let x: F = rng.gen();
+            assert_eq!(x == <F as Field>::ZERO, Zero::is_zero(&x));
+            assert_eq!(Field::pow(x, 5), Pow::pow(x, 5u64));
+            if let Some(inverse) = Field::inverse(x) {
+                assert_eq!(inverse, x.inv());
+            }
+        }
+    }
+
+    #[test]
+    fn num_traits() {
+        num_traits_surface::<fp20::integer::Fp>();
+        num_traits_surface::<fp20::single_precision::Fp>();
+        num_traits_surface::<fp20::double_precision::Fp>();
+        num_traits_surface::<fp20::extension::Fp2>();
+        num_traits_surface::<FpF16<251>>();
+        num_traits_surface::<FpF32<2097143>>();
+        num_traits_surface::<baby_bear::Fp>();
+        num_traits_surface::<f65537::Fp>();
+        num_traits_surface::<goldilocks::Fp>();
+        num_traits_surface::<numeric::double_precision::Fp>();
+        num_traits_surface::<numeric::single_precision::Fp>();
+        num_traits_surface::<numeric::half_precision::Fp>();
+    }
+
+    #[test]
+    #[should_panic(expected = "inverse of zero")]
+    fn inv_of_zero() {
+        let _ = <fp20::integer::Fp as Field>::ZERO.inv();
+    }
+
+    #[cfg(feature = "serde")]
+    fn serde_round_trip<F>()
+    where
+        F: Field + serde::Serialize + serde::de::DeserializeOwned,
+        Standard: Distribution<F>,
+    {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let values = (0..64).map(|_| rng.gen()).collect::<Vec<F>>();
+        let json = serde_json::to_string(&values).unwrap();
+        assert_eq!(values, serde_json::from_str::<Vec<F>>(&json).unwrap());
+        let max = F::order() - 1;
+        assert_eq!(
+            max,
+            serde_json::from_str::<F>(&max.to_string())
+                .unwrap()
+                .to_canonical_u64()
+        );
+        assert!(serde_json::from_str::<F>(&F::order().to_string()).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        serde_round_trip::<fp20::integer::Fp>();
+        serde_round_trip::<fp20::single_precision::Fp>();
+        serde_round_trip::<fp20::double_precision::Fp>();
+        serde_round_trip::<FpF16<251>>();
+        serde_round_trip::<FpF32<2097143>>();
+        serde_round_trip::<baby_bear::Fp>();
+        serde_round_trip::<f65537::Fp>();
+        serde_round_trip::<goldilocks::Fp>();
+        serde_round_trip::<numeric::double_precision::Fp>();
+        serde_round_trip::<numeric::single_precision::Fp>();
+        serde_round_trip::<numeric::half_precision::Fp>();
+
+        // the same document for every backend
+        let x = fp20::integer::Fp::new(2097142);
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!("2097142", json);
+        assert_eq!(
+            json,
+            serde_json::to_string(&fp20::single_precision::Fp::from(x)).unwrap()
+        );
+        assert_eq!(
+            json,
+            serde_json::to_string(&fp20::double_precision::Fp::from(x)).unwrap()
+        );
+
+        let y = fp20::extension::Fp2::from([fp20::integer::Fp::new(1), x]);
+        assert_eq!("[1,2097142]", serde_json::to_string(&y).unwrap());
+        assert_eq!(y, serde_json::from_str("[1,2097142]").unwrap());
+        assert!(serde_json::from_str::<fp20::extension::Fp2>("[2097143,0]").is_err());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let values = (0..64)
+            .map(|_| rng.gen())
+            .collect::<Vec<fp20::single_precision::Fp>>();
+        let floats: &[f32] = bytemuck::cast_slice(&values);
+        for (&v, &f) in values.iter().zip(floats) {
+            assert_eq!(v.to_canonical_u64(), f as u64);
+        }
+        let values = values
+            .iter()
+            .map(|&v| v.into())
+            .collect::<Vec<fp20::double_precision::Fp>>();
+        let floats: &[f64] = bytemuck::cast_slice(&values);
+        for (&v, &f) in values.iter().zip(floats) {
+            assert_eq!(v.to_canonical_u64(), f as u64);
+        }
+        // float-float elements are `[hi, lo]` pairs
+        let x = numeric::single_precision::Fp::from_canonical_u32(numeric::MODULUS - 1);
+        let pair: &[f32] = bytemuck::cast_slice(std::slice::from_ref(&x));
+        assert_eq!(
+            x.to_canonical_u64(),
+            (pair[0] as i64 + pair[1] as i64) as u64
+        );
+        assert_eq!(
+            <FpF32<2097143> as Field>::ZERO,
+            bytemuck::Zeroable::zeroed()
+        );
+        // f16-limb elements are little-endian bytes
+        let x = numeric::half_precision::Fp::from_canonical_u32(numeric::MODULUS - 1);
+        let bytes: &[half::f16] = bytemuck::cast_slice(std::slice::from_ref(&x));
+        assert_eq!(
+            x.to_canonical_u64(),
+            bytes
+                .iter()
+                .rev()
+                .fold(0, |acc, b| acc << 8 | f32::from(*b) as u64)
+        );
+    }
+
+    #[test]
+    fn le_bytes() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..256 {
+            let x: fp20::integer::Fp = rng.gen();
+            let bytes = x.to_le_bytes();
+            assert_eq!(u32::from(x).to_le_bytes(), bytes);
+            // the same bytes for every backend
+            let single = fp20::single_precision::Fp::from(x);
+            let double = fp20::double_precision::Fp::from(x);
+            assert_eq!(bytes, single.to_le_bytes());
+            assert_eq!(bytes, double.to_le_bytes());
+            assert_eq!(bytes, FpF32::<2097143>::from(u32::from(x)).to_le_bytes());
+            assert_eq!(Some(x), fp20::integer::Fp::from_le_bytes(bytes));
+            assert_eq!(
+                Some(single),
+                fp20::single_precision::Fp::from_le_bytes(bytes)
+            );
+            assert_eq!(
+                Some(double),
+                fp20::double_precision::Fp::from_le_bytes(bytes)
+            );
+
+            let y = fp20::extension::Fp2 {
+                c0: x,
+                c1: rng.gen(),
+            };
+            assert_eq!(
+                Some(y),
+                fp20::extension::Fp2::from_le_bytes(y.to_le_bytes())
+            );
+
+            let z: goldilocks::Fp = rng.gen();
+            assert_eq!(u64::from(z).to_le_bytes(), z.to_le_bytes());
+            assert_eq!(Some(z), goldilocks::Fp::from_le_bytes(z.to_le_bytes()));
+
+            let v = rng.gen_range(0..numeric::MODULUS);
+            let half = numeric::half_precision::Fp::from_canonical_u32(v);
+            let single = numeric::single_precision::Fp::from_canonical_u32(v);
+            let double = numeric::double_precision::Fp::from_canonical_u32(v);
+            assert_eq!(v.to_le_bytes(), half.to_le_bytes());
+            assert_eq!(v.to_le_bytes(), single.to_le_bytes());
+            assert_eq!(v.to_le_bytes(), double.to_le_bytes());
+            assert_eq!(
+                Some(single),
+                numeric::single_precision::Fp::from_le_bytes(v.to_le_bytes())
+            );
+        }
+        let w = FpF16::<251>::from_canonical_u32(250);
+        assert_eq!(Some(w), FpF16::<251>::from_le_bytes(w.to_le_bytes()));
+        let b = baby_bear::Fp::from_canonical_u32(baby_bear::MODULUS - 1);
+        assert_eq!(Some(b), baby_bear::Fp::from_le_bytes(b.to_le_bytes()));
+        assert_eq!(
+            Some(f65537::Fp::ONE),
+            f65537::Fp::from_le_bytes(1u32.to_le_bytes())
+        );
+
+        // non-canonical values are rejected
+        let p = fp20::integer::MODULUS.to_le_bytes();
+        assert_eq!(None, fp20::single_precision::Fp::from_le_bytes(p));
+        assert_eq!(
+            None,
+            fp20::extension::Fp2::from_le_bytes([p, [0; 4]].concat().try_into().unwrap())
+        );
+        assert_eq!(None, goldilocks::Fp::from_le_bytes(u64::MAX.to_le_bytes()));
+        assert_eq!(
+            None,
+            numeric::half_precision::Fp::from_le_bytes(u32::MAX.to_le_bytes())
+        );
+    }
+
+    /// Equal elements reached through different operations hash the same
+    fn hash_surface<F: Field + Eq + std::hash::Hash>()
+    where
+        Standard: Distribution<F>,
+    {
+        let state = std::collections::hash_map::RandomState::new();
+        let hash = |x: F| std::hash::BuildHasher::hash_one(&state, x);
+        let mut rng = StdRng::from_seed([1; 32]);
+        let mut table = std::collections::HashMap::new();
+        for i in 0..64 {
+            let (a, b, c): (F, F, F) = (rng.gen(), rng.gen(), rng.gen());
+            let x = (a + b) * c;
+            let y = a * c + b * c;
+            let z = -(-x - c) - c;
+            assert_eq!(x, y);
+            assert_eq!(hash(x), hash(y));
+            assert_eq!(hash(x), hash(z));
+            table.insert(x, i);
+            assert_eq!(Some(&i), table.get(&y));
+        }
+        // zero as `x + -x`, `-0` and a product
+        let x: F = rng.gen();
+        let zeros = [F::ZERO, x + -x, -F::ZERO, F::ZERO * x, -(-x + x)];
+        for zero in zeros {
+            assert_eq!(hash(F::ZERO), hash(zero));
+        }
+    }
+
+    #[test]
+    fn hash() {
+        hash_surface::<fp20::integer::Fp>();
+        hash_surface::<fp20::single_precision::Fp>();
+        hash_surface::<fp20::double_precision::Fp>();
+        hash_surface::<fp20::extension::Fp2>();
+        hash_surface::<FpF16<251>>();
+        hash_surface::<FpF32<2097143>>();
+        hash_surface::<baby_bear::Fp>();
+        hash_surface::<f65537::Fp>();
+        hash_surface::<goldilocks::Fp>();
+        hash_surface::<numeric::double_precision::Fp>();
+        hash_surface::<numeric::single_precision::Fp>();
+        hash_surface::<numeric::half_precision::Fp>();
+
+        // the canonical value is hashed, not the representation
+        let state = std::collections::hash_map::RandomState::new();
+        let x = fp20::integer::Fp::new(2097142);
+        let hash = std::hash::BuildHasher::hash_one(&state, x);
+        let single = fp20::single_precision::Fp::from(x);
+        let double = fp20::double_precision::Fp::from(x);
+        assert_eq!(hash, std::hash::BuildHasher::hash_one(&state, single));
+        assert_eq!(hash, std::hash::BuildHasher::hash_one(&state, double));
+    }
+
+    #[test]
+    fn display_canonical() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let values = (0..256)
+            .map(|_| rng.gen())
+            .chain([0, 1, 1 << 20, fp20::integer::MODULUS - 1].map(fp20::integer::Fp::new));
+        for x in values {
+            let s = u32::from(x).to_string();
+            assert_eq!(s, x.to_string());
+            assert_eq!(s, fp20::single_precision::Fp::from(x).to_string());
+            assert_eq!(s, fp20::double_precision::Fp::from(x).to_string());
+            assert_eq!(s, FpF32::<2097143>::from(u32::from(x)).to_string());
+        }
+        let p = numeric::MODULUS - 1;
+        assert_eq!(
+            p.to_string(),
+            numeric::half_precision::Fp::from(p).to_string()
+        );
+        assert_eq!(
+            p.to_string(),
+            numeric::single_precision::Fp::from(p).to_string()
+        );
+        assert_eq!(
+            p.to_string(),
+            numeric::double_precision::Fp::from(p).to_string()
+        );
+        assert_eq!("16777216", baby_bear::Fp::from(1 << 24).to_string());
+        let i = fp20::extension::Fp2::new(1, 2097142);
+        assert_eq!("1 + 2097142i", i.to_string());
+    }
+
+    #[test]
+    fn fp20() {
+        surface::<fp20::integer::Fp>();
+        surface::<fp20::single_precision::Fp>();
+        surface::<fp20::double_precision::Fp>();
+    }
+
+    #[test]
+    fn fp20_extension() {
+        surface::<fp20::extension::Fp2>();
+        let p = fp20::integer::MODULUS;
+        let x = fp20::extension::Fp2::new(3, 5);
+        assert_eq!(3 + 5 * p as u64, x.to_canonical_u64());
+        assert_eq!(x, Field::from_canonical_u32(3 + 5 * p));
+    }
+
+    #[test]
+    fn const_modulus() {
+        surface::<FpF16<251>>();
+        surface::<FpF32<2097143>>();
+        surface::<baby_bear::Fp>();
+        surface::<f65537::Fp>();
+    }
+
+    #[test]
+    fn goldilocks() {
+        surface::<goldilocks::Fp>();
+    }
+
+    fn numeric_matches_u64_reference<F: Field + From<u32>>() {
+        let p = numeric::MODULUS as u64;
+        let mut rng = StdRng::from_seed([1; 32]);
+        let edges = [
+            0,
+            1,
+            2,
+            5,
+            1 << 24,
+            (1 << 24) + 1,
+            1 << 31,
+            p - 5,
+            p - 2,
+            p - 1,
+        ];
+        let values = (0..1 << 14)
+            .map(|_| (rng.gen_range(0..p), rng.gen_range(0..p)))
+            .chain(edges.iter().flat_map(|&a| edges.map(|b| (a, b))));
+        for (a, b) in values {
+            let x = F::from_canonical_u32(a as u32);
+            let y = F::from_canonical_u32(b as u32);
+            assert_eq!(
+                a * b % p,
+                (x * y).to_canonical_u64(),
+                "mismatch: `{a} * {b}`"
+            );
+            assert_eq!(
+                (a + b) % p,
+                (x + y).to_canonical_u64(),
+                "mismatch: `{a} + {b}`"
+            );
+            assert_eq!(
+                (a + p - b) % p,
+                (x - y).to_canonical_u64(),
+                "mismatch: `{a} - {b}`"
+            );
+        }
+        assert_eq!(F::from(4), F::from(u32::MAX));
+        let generator = F::from(numeric::GENERATOR);
+        assert_eq!(p - 1, generator.multiplicative_order());
+    }
+
+    /// Checks `pow` against square-and-multiply over `u128`
+    fn numeric_pow_matches_u128_reference<F: Field>(pow: impl Fn(F, u64) -> F) {
+        let p = numeric::MODULUS as u128;
+        let reference = |mut base: u128, mut exp: u64| {
+            let mut res = 1;
+            while exp != 0 {
+                if exp & 1 == 1 {
+                    res = res * base % p;
+                }
+                base = base * base % p;
+                exp >>= 1;
+            }
+            res as u64
+        };
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..256 {
+            let a = rng.gen_range(0..numeric::MODULUS);
+            let exp = rng.gen();
+            assert_eq!(
+                reference(a.into(), exp),
+                pow(F::from_canonical_u32(a), exp).to_canonical_u64(),
+                "mismatch: `{a}^{exp}`"
+            );
+        }
+        assert_eq!(F::ONE, pow(F::ZERO, 0));
+    }
+
+    #[test]
+    fn numeric() {
+        surface::<numeric::double_precision::Fp>();
+        surface::<numeric::single_precision::Fp>();
+        surface::<numeric::half_precision::Fp>();
+        numeric_matches_u64_reference::<numeric::double_precision::Fp>();
+        numeric_matches_u64_reference::<numeric::single_precision::Fp>();
+        numeric_matches_u64_reference::<numeric::half_precision::Fp>();
+        numeric_pow_matches_u128_reference(numeric::double_precision::Fp::pow);
+        numeric_pow_matches_u128_reference(numeric::single_precision::Fp::pow);
+        numeric_pow_matches_u128_reference(numeric::half_precision::Fp::pow);
+    }
+
+    #[test]
+    #[should_panic(expected = "canonical value exceeds u32")]
+    fn to_canonical_u32_rejects_wide_values() {
+        let _ = goldilocks::Fp::new(1 << 32).to_canonical_u32();
+    }
+}
@@ -0,0 +1,48 @@
+//! Self-check that `mul_add` compiles to a fused multiply add.
+//!
+//! Every float reduction in this crate recovers the rounding error of a
+//! product with `a.mul_add(b, -(a * b))`. This is only exact if `mul_add`
+//! rounds once. If it is lowered to a separate multiply and add the low part
+//! silently comes out as zero and the reductions return wrong results.
+use std::hint::black_box;
+
+/// Returns true if [`f32::mul_add`] rounds once
+pub fn is_fused_f32() -> bool {
+    // (1 + 2^-12)^2 = 1 + 2^-11 + 2^-24 where 2^-24 is lost by rounding the
+    // product to f32 and can only be recovered by a fused operation
+    let a = black_box(1.0 + 2f32.powi(-12));
+    let h = a * a;
+    let l = a.mul_add(a, -h);
+    l == 2f32.powi(-24)
+}
+
+/// Returns true if [`f64::mul_add`] rounds once
+pub fn is_fused_f64() -> bool {
+    // (1 + 2^-27)^2 = 1 + 2^-26 + 2^-54
+    let a = black_box(1.0 + 2f64.powi(-27));
+    let h = a * a;
+    let l = a.mul_add(a, -h);
+    l == 2f64.powi(-54)
+}
+
+/// Panics if `mul_add` isn't fused for f32 or f64
+pub fn assert_fused_mul_add() {
+    assert!(
+        is_fused_f32(),
+        "f32::mul_add is not fused: float reductions are not exact"
+    );
+    assert!(
+        is_fused_f64(),
+        "f64::mul_add is not fused: float reductions are not exact"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_add_is_fused() {
+        assert_fused_mul_add();
+    }
+}
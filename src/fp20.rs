@@ -1,9 +1,33 @@
 pub mod single_precision {
+    use super::integer::NUM_BITS;
+    use crate::field::Field;
+    use crate::macros::impl_assign_ops;
+    use crate::macros::impl_hash;
+    use crate::macros::impl_le_bytes;
+    use crate::macros::impl_num_traits;
+    use crate::macros::impl_pod;
+    use crate::macros::impl_serde;
+    use crate::macros::impl_sum_product;
+    use crate::macros::impl_word;
+    use crate::reduction::Fma;
+    use crate::reduction::Reducer;
+    use crate::reduction::Reducible;
+    use crate::rounding::Floor;
+    use crate::rounding::Round;
+    use crate::stats;
+    use crate::trace::MulTrace;
+    use num_traits::MulAdd;
     use rand::distributions::Standard;
     use rand::prelude::Distribution;
     use std::fmt::Display;
     use std::ops::Add;
+    use std::ops::Div;
     use std::ops::Mul;
+    use std::ops::Neg;
+    use std::ops::Sub;
+
+    /// Rounding primitive used for the quotient in [`Fp`] multiplication
+    pub type Rounding = Floor;
 
     #[repr(transparent)]
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
@@ -21,11 +45,116 @@ pub mod single_precision {
             debug_assert!(v < Self::MODULUS as u32);
             Self(v as f32)
         }
+
+        /// Computes `self^exp` by square-and-multiply
+        pub fn pow(self, exp: u64) -> Self {
+            Field::pow(self, exp)
+        }
+
+        /// Computes `self^-1` as `self^(p - 2)` by Fermat's little theorem.
+        /// Returns `None` for zero.
+        pub fn inverse(self) -> Option<Self> {
+            (self != Self::ZERO).then(|| self.pow(Self::MODULUS as u64 - 2))
+        }
+
+        /// Returns a square root of `self` if one exists. `p = 3 mod 4` so the
+        /// only candidate up to sign is `self^((p + 1) / 4)`.
+        pub fn sqrt(self) -> Option<Self> {
+            let root = self.pow((Self::MODULUS as u64 + 1) / 4);
+            (root * root == self).then_some(root)
+        }
+
+        /// Little-endian bits extracted by repeated halving, without a
+        /// round trip through an integer register
+        pub fn to_le_bits(self) -> [bool; NUM_BITS] {
+            let mut v = self.0;
+            std::array::from_fn(|_| {
+                let half = v * 0.5;
+                v = half.trunc();
+                half != v
+            })
+        }
+
+        /// Inverse of [`Fp::to_le_bits`]. The bits must encode a canonical
+        /// element.
+        pub fn from_le_bits(bits: [bool; NUM_BITS]) -> Self {
+            let v = bits.iter().rev().fold(0.0, |acc: f32, &bit| {
+                acc.mul_add(2.0, f32::from(u8::from(bit)))
+            });
+            debug_assert!(v < Self::MODULUS);
+            Self(v)
+        }
+
+        /// Multiplication with the quotient rounded by `R`
+        #[inline]
+        #[must_use]
+        pub fn mul_with<R: Round>(self, rhs: Self) -> Self {
+            self.mul_reduced::<Fma<R>>(rhs)
+        }
+
+        /// Multiplication with the remainder computed by `S`
+        #[inline]
+        #[must_use]
+        pub fn mul_reduced<S: Reducer<f32>>(self, rhs: Self) -> Self {
+            let h = self.0 * rhs.0;
+            let l = self.0.mul_add(rhs.0, -h);
+            let e = S::reduce(h, l, Self::MODULUS, Self::MODULUS_INV);
+            stats::record(stats::Site::Fp20SingleMul, e.into(), Self::MODULUS.into());
+            Self(if e >= Self::MODULUS {
+                e - Self::MODULUS
+            } else if e < 0.0 {
+                e + Self::MODULUS
+            } else {
+                e
+            })
+        }
+
+        /// Computes `self * a + b` with a single reduction. `b` is folded into
+        /// the low part of the product (exact as both are integers below
+        /// `2^22`) so the remainder lands in `(-p, 3p)`.
+        #[inline]
+        #[must_use]
+        pub fn mul_add(self, a: Self, b: Self) -> Self {
+            let h = self.0 * a.0;
+            let l = self.0.mul_add(a.0, -h) + b.0;
+            let e = Fma::<Rounding>::reduce(h, l, Self::MODULUS, Self::MODULUS_INV);
+            Self(if e >= 2.0 * Self::MODULUS {
+                e - 2.0 * Self::MODULUS
+            } else if e >= Self::MODULUS {
+                e - Self::MODULUS
+            } else if e < 0.0 {
+                e + Self::MODULUS
+            } else {
+                e
+            })
+        }
+
+        /// Returns every intermediate of `self * rhs` (with [`Rounding`])
+        pub fn trace_mul(self, rhs: Self) -> MulTrace<f32> {
+            let h = self.0 * rhs.0;
+            let l = self.0.mul_add(rhs.0, -h);
+            let b = h * Self::MODULUS_INV;
+            let c = Rounding::round_f32(b);
+            let d = (-c).mul_add(Self::MODULUS, h);
+            let e = d + l;
+            MulTrace {
+                lhs: self.0,
+                rhs: rhs.0,
+                modulus: Self::MODULUS,
+                h,
+                l,
+                b,
+                c,
+                d,
+                e,
+                result: (self * rhs).0,
+            }
+        }
     }
 
     impl Display for Fp {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            self.0.fmt(f)
+            u32::from(*self).fmt(f)
         }
     }
 
@@ -34,6 +163,7 @@ pub mod single_precision {
 
         fn add(self, rhs: Self) -> Self {
             let a = self.0 + rhs.0;
+            stats::record(stats::Site::Fp20SingleAdd, a.into(), Self::MODULUS.into());
             Self(if a >= Self::MODULUS {
                 a - Self::MODULUS
             } else {
@@ -42,26 +172,104 @@ pub mod single_precision {
         }
     }
 
-    impl Mul for Fp {
+    impl Sub for Fp {
         type Output = Self;
 
-        fn mul(self, rhs: Self) -> Self {
-            let h = self.0 * rhs.0;
-            let l = self.0.mul_add(rhs.0, -h);
-            let b = h * Self::MODULUS_INV;
-            let c = b.floor();
-            let d = (-c).mul_add(Self::MODULUS, h);
-            let e = d + l;
-            Self(if e >= Self::MODULUS {
-                e - Self::MODULUS
-            } else if e < 0.0 {
-                e + Self::MODULUS
+        fn sub(self, rhs: Self) -> Self {
+            let a = self.0 - rhs.0;
+            Self(if a < 0.0 { a + Self::MODULUS } else { a })
+        }
+    }
+
+    impl Neg for Fp {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            Self(if self.0 == 0.0 {
+                0.0
             } else {
-                e
+                Self::MODULUS - self.0
             })
         }
     }
 
+    impl Div for Fp {
+        type Output = Self;
+
+        /// Panics if `rhs` is zero
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn div(self, rhs: Self) -> Self {
+            self * rhs.inverse().expect("division by zero")
+        }
+    }
+
+    impl Mul for Fp {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self {
+            self.mul_with::<Rounding>(rhs)
+        }
+    }
+
+    impl_assign_ops!(
+        Fp;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_sum_product!(Fp);
+
+    impl_num_traits!(Fp);
+
+    impl_hash!(Fp);
+
+    impl_serde!(Fp);
+
+    impl_le_bytes!(Fp, u32);
+
+    impl_pod!(Fp);
+    impl_word!(Fp, f32);
+
+    impl From<u32> for Fp {
+        #[inline]
+        fn from(value: u32) -> Self {
+            Self::new(value % Self::MODULUS as u32)
+        }
+    }
+
+    impl Field for Fp {
+        const ZERO: Self = Self::ZERO;
+        const ONE: Self = Self::ONE;
+
+        const ORDER: u64 = Self::MODULUS as u64;
+
+        fn from_canonical_u32(v: u32) -> Self {
+            Self::new(v)
+        }
+
+        fn to_canonical_u64(self) -> u64 {
+            u32::from(self).into()
+        }
+    }
+
+    impl MulAdd for Fp {
+        type Output = Self;
+
+        #[inline]
+        fn mul_add(self, a: Self, b: Self) -> Self {
+            Fp::mul_add(self, a, b)
+        }
+    }
+
+    impl Reducible for Fp {
+        type Float = f32;
+
+        fn mul_reduced<S: Reducer<f32>>(self, rhs: Self) -> Self {
+            Fp::mul_reduced::<S>(self, rhs)
+        }
+    }
+
     impl Distribution<Fp> for Standard {
         fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Fp {
             // TODO: make sure sampling is done correctly
@@ -77,6 +285,13 @@ pub mod single_precision {
         }
     }
 
+    impl From<Fp> for super::double_precision::Fp {
+        #[inline]
+        fn from(value: Fp) -> Self {
+            Self::new(value.0 as u32)
+        }
+    }
+
     impl From<Fp> for u32 {
         #[inline]
         fn from(value: Fp) -> Self {
@@ -88,6 +303,9 @@ pub mod single_precision {
     mod tests {
         use super::super::integer;
         use super::*;
+        use crate::reduction::Barrett;
+        use crate::rounding::Nearest;
+        use crate::rounding::Trunc;
         use rand::rngs::StdRng;
         use rand::Rng;
         use rand::SeedableRng;
@@ -109,6 +327,66 @@ pub mod single_precision {
             }
         }
 
+        fn multiplication_with<R: Round>() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..1024)
+                .map(|_| rng.gen::<u32>() % MODULUS)
+                .chain([0, 1, 2, MODULUS - 2, MODULUS - 1])
+                .collect::<Vec<u32>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let expected = Fp::new((a as u64 * b as u64 % MODULUS as u64) as u32);
+                    let actual = Fp::new(a).mul_with::<R>(Fp::new(b));
+                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn multiplication_trunc() {
+            multiplication_with::<Trunc>();
+        }
+
+        #[test]
+        fn multiplication_floor() {
+            multiplication_with::<Floor>();
+        }
+
+        #[test]
+        fn multiplication_nearest() {
+            multiplication_with::<Nearest>();
+        }
+
+        #[test]
+        fn multiplication_barrett() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1 << 16 {
+                let (a, b) = (rng.gen::<u32>() % MODULUS, rng.gen::<u32>() % MODULUS);
+                let expected = Fp::new((a as u64 * b as u64 % MODULUS as u64) as u32);
+                let actual = Fp::new(a).mul_reduced::<Barrett>(Fp::new(b));
+                assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+            }
+        }
+
+        #[test]
+        fn trace_mul() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1024 {
+                let a: Fp = rng.gen();
+                let b: Fp = rng.gen();
+                let trace = a.trace_mul(b);
+                assert_eq!((a * b).0, trace.result);
+                for v in [trace.h, trace.l, trace.c, trace.d, trace.e] {
+                    assert_eq!(0.0, v.fract(), "inexact step: {trace}");
+                }
+            }
+            let trace = Fp::new(MODULUS - 1).trace_mul(Fp::new(MODULUS - 1));
+            assert_eq!(1.0, trace.result);
+            assert!(trace
+                .to_string()
+                .starts_with("2097142 * 2097142 mod 2097143\n"));
+        }
+
         #[test]
         fn addition() {
             let mut rng = StdRng::from_seed([1; 32]);
@@ -123,17 +401,120 @@ pub mod single_precision {
                 }
             }
         }
+
+        #[test]
+        fn subtraction() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..1024)
+                .map(|_| rng.gen::<u32>() % MODULUS)
+                .chain([0, 1, 2, MODULUS - 2, MODULUS - 1])
+                .collect::<Vec<u32>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let expected = integer::Fp::new(a) - integer::Fp::new(b);
+                    let actual = Fp::new(a) - Fp::new(b);
+                    assert_eq!(expected, actual.into(), "mismatch: `{a} - {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn negation() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases =
+                (0..1 << 16)
+                    .map(|_| rng.gen::<u32>() % MODULUS)
+                    .chain([0, 1, MODULUS - 1]);
+            for a in edge_cases {
+                let expected = -integer::Fp::new(a);
+                let actual = -Fp::new(a);
+                assert_eq!(expected, actual.into(), "mismatch: `-{a}`");
+                assert_eq!(Fp::new(0), actual + Fp::new(a), "mismatch: `-{a}`");
+            }
+        }
+
+        #[test]
+        fn sqrt() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..1 << 12).map(|_| rng.gen::<u32>() % MODULUS).chain([
+                0,
+                1,
+                2,
+                4,
+                5,
+                MODULUS - 1,
+            ]);
+            for a in edge_cases {
+                let expected = integer::Fp::new(a).sqrt();
+                let actual = Fp::new(a).sqrt();
+                assert_eq!(expected, actual.map(Into::into), "mismatch: `sqrt({a})`");
+            }
+        }
+
+        #[test]
+        fn inverse() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..1 << 12)
+                .map(|_| rng.gen::<u32>() % MODULUS)
+                .chain([1, 2, MODULUS - 1])
+                .collect::<Vec<u32>>();
+            for a in edge_cases.into_iter().filter(|&a| a != 0) {
+                let expected = integer::Fp::new(a).inverse().unwrap();
+                let actual = Fp::new(a).inverse().unwrap();
+                assert_eq!(expected, actual.into(), "mismatch: `{a}^-1`");
+                let b = rng.gen::<u32>() % MODULUS;
+                let expected = integer::Fp::new(b) / integer::Fp::new(a);
+                let actual = Fp::new(b) / Fp::new(a);
+                assert_eq!(expected, actual.into(), "mismatch: `{b} / {a}`");
+            }
+            assert_eq!(None, Fp::new(0).inverse());
+        }
+
+        #[test]
+        fn multiplicative_order() {
+            let group_order = MODULUS as u64 - 1;
+            assert_eq!(group_order, Fp::new(5).multiplicative_order());
+            assert_eq!(1, Fp::new(1).multiplicative_order());
+            assert_eq!(2, Fp::new(MODULUS - 1).multiplicative_order());
+
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..64 {
+                let x = rng.gen::<Fp>();
+                if x == Fp::new(0) {
+                    continue;
+                }
+                let expected = integer::Fp::from(x).multiplicative_order();
+                assert_eq!(expected, x.multiplicative_order(), "mismatch: `{x}`");
+            }
+        }
     }
 }
 
 pub mod integer {
+    use crate::field::Field;
+    use crate::macros::impl_assign_ops;
+    use crate::macros::impl_hash;
+    use crate::macros::impl_le_bytes;
+    use crate::macros::impl_num_traits;
+    use crate::macros::impl_serde;
+    use crate::macros::impl_sum_product;
+    use crate::macros::impl_word;
+    use crate::slice::LINEAR_COMBINATION_BLOCK;
+    use num_traits::MulAdd;
     use rand::distributions::Standard;
     use rand::prelude::Distribution;
+    use std::fmt::Display;
     use std::ops::Add;
+    use std::ops::Div;
     use std::ops::Mul;
+    use std::ops::Neg;
+    use std::ops::Sub;
 
     /// Pseudo-Mersenne prime field modulus `p = 2097143`
-    const MODULUS: u32 = 0b111111111111111110111;
+    pub const MODULUS: u32 = 0b111111111111111110111;
+
+    /// Number of bits in a canonical element
+    pub const NUM_BITS: usize = (u32::BITS - MODULUS.leading_zeros()) as usize;
 
     /// Pseudo-Mersenne Reduction:
     /// <https://hal.sorbonne-universite.fr/hal-02883333/file/BaDueprintversion.pdf>
@@ -163,6 +544,97 @@ pub mod integer {
             debug_assert!(v < MODULUS);
             Self(v)
         }
+
+        /// Computes `self^exp` by square-and-multiply
+        pub fn pow(self, exp: u64) -> Self {
+            Field::pow(self, exp)
+        }
+
+        /// Computes `self^-1` as `self^(p - 2)` by Fermat's little theorem.
+        /// Returns `None` for zero.
+        pub fn inverse(self) -> Option<Self> {
+            (self != Self(0)).then(|| self.pow(MODULUS as u64 - 2))
+        }
+
+        /// Computes `self * a + b` with a single reduction
+        #[inline]
+        #[must_use]
+        pub fn mul_add(self, a: Self, b: Self) -> Self {
+            Self(reduce(self.0 as u64 * a.0 as u64 + b.0 as u64))
+        }
+
+        /// Returns a square root of `self` if one exists. `p = 3 mod 4` so the
+        /// only candidate up to sign is `self^((p + 1) / 4)`.
+        pub fn sqrt(self) -> Option<Self> {
+            let root = self.pow((MODULUS as u64 + 1) / 4);
+            (root * root == self).then_some(root)
+        }
+
+        pub fn to_le_bits(self) -> [bool; NUM_BITS] {
+            std::array::from_fn(|i| (self.0 >> i) & 1 == 1)
+        }
+
+        /// Inverse of [`Fp::to_le_bits`]. The bits must encode a canonical
+        /// element.
+        pub fn from_le_bits(bits: [bool; NUM_BITS]) -> Self {
+            let v = bits
+                .iter()
+                .rev()
+                .fold(0, |acc, &bit| (acc << 1) | bit as u32);
+            Self::new(v)
+        }
+    }
+
+    /// Views elements as their canonical `u32` representatives without a copy
+    pub fn as_u32_slice(values: &[Fp]) -> &[u32] {
+        // SAFETY: `Fp` is `repr(transparent)` over `u32`
+        unsafe { std::slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+    }
+
+    /// Views `u32`s (e.g. a GPU readback) as elements without a copy. Every
+    /// value must be canonical, which is only checked in debug builds.
+    pub fn from_u32_slice(values: &[u32]) -> &[Fp] {
+        debug_assert!(
+            values.iter().all(|&v| v < MODULUS),
+            "values must be canonical"
+        );
+        // SAFETY: `Fp` is `repr(transparent)` over `u32` and every `u32` is a
+        // valid bit pattern for it
+        unsafe { std::slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+    }
+
+    /// [`crate::slice::acc_linear_combination`] with lazy reduction: the
+    /// products of a block are summed unreduced in `u64` and reduced once per
+    /// element at the end. Products are below `2^42` so the sums can't
+    /// overflow for fewer than `2^22` terms.
+    pub fn acc_linear_combination(dst: &mut [Fp], terms: &[(Fp, &[Fp])]) {
+        assert!(terms.len() < 1 << 22, "too many terms for lazy reduction");
+        for (_, column) in terms {
+            assert_eq!(dst.len(), column.len());
+        }
+        let mut acc = [0u64; LINEAR_COMBINATION_BLOCK];
+        for (i, block) in dst.chunks_mut(LINEAR_COMBINATION_BLOCK).enumerate() {
+            let offset = i * LINEAR_COMBINATION_BLOCK;
+            let acc = &mut acc[..block.len()];
+            for (a, d) in acc.iter_mut().zip(block.iter()) {
+                *a = d.0 as u64;
+            }
+            for &(k, column) in terms {
+                let column = &column[offset..offset + block.len()];
+                for (a, c) in acc.iter_mut().zip(column) {
+                    *a += k.0 as u64 * c.0 as u64;
+                }
+            }
+            for (d, &a) in block.iter_mut().zip(acc.iter()) {
+                *d = Fp((a % MODULUS as u64) as u32);
+            }
+        }
+    }
+
+    impl Display for Fp {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.fmt(f)
+        }
     }
 
     impl Add for Fp {
@@ -174,6 +646,33 @@ pub mod integer {
         }
     }
 
+    impl Sub for Fp {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            let (a, borrow) = self.0.overflowing_sub(rhs.0);
+            Self(if borrow { a.wrapping_add(MODULUS) } else { a })
+        }
+    }
+
+    impl Neg for Fp {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            Self(if self.0 == 0 { 0 } else { MODULUS - self.0 })
+        }
+    }
+
+    impl Div for Fp {
+        type Output = Self;
+
+        /// Panics if `rhs` is zero
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn div(self, rhs: Self) -> Self {
+            self * rhs.inverse().expect("division by zero")
+        }
+    }
+
     impl Mul for Fp {
         type Output = Self;
 
@@ -182,6 +681,55 @@ pub mod integer {
         }
     }
 
+    impl_assign_ops!(
+        Fp;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_sum_product!(Fp);
+
+    impl_num_traits!(Fp);
+
+    impl_hash!(Fp);
+
+    impl_serde!(Fp);
+
+    impl_le_bytes!(Fp, u32);
+    impl_word!(Fp, u32);
+
+    impl From<u32> for Fp {
+        #[inline]
+        fn from(value: u32) -> Self {
+            Self::new(value % MODULUS)
+        }
+    }
+
+    impl Field for Fp {
+        const ZERO: Self = Fp(0);
+        const ONE: Self = Fp(1);
+
+        const ORDER: u64 = MODULUS as u64;
+
+        fn from_canonical_u32(v: u32) -> Self {
+            Self::new(v)
+        }
+
+        fn to_canonical_u64(self) -> u64 {
+            self.0.into()
+        }
+    }
+
+    impl MulAdd for Fp {
+        type Output = Self;
+
+        #[inline]
+        fn mul_add(self, a: Self, b: Self) -> Self {
+            Fp::mul_add(self, a, b)
+        }
+    }
+
     impl Distribution<Fp> for Standard {
         fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Fp {
             let v: u32 = self.sample(rng);
@@ -212,6 +760,9 @@ pub mod integer {
 
     #[cfg(test)]
     mod tests {
+        use super::super::double_precision;
+        use super::super::integer;
+        use super::super::single_precision;
         use super::*;
         use rand::rngs::StdRng;
         use rand::Rng;
@@ -233,12 +784,56 @@ pub mod integer {
         }
 
         #[test]
-        fn multiply_by_zero() {
-            let res = Fp::new(1) * Fp::new(0);
-
+        fn fused_mul_add() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..128)
+                .map(|_| rng.gen::<u32>() % MODULUS)
+                .chain([0, 1, 2, MODULUS - 2, MODULUS - 1])
+                .collect::<Vec<u32>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    for &c in &edge_cases {
+                        let expected = (a as u64 * b as u64 + c as u64) % MODULUS as u64;
+                        let expected = Fp::new(expected as u32);
+                        assert_eq!(
+                            expected,
+                            Fp::new(a).mul_add(Fp::new(b), Fp::new(c)),
+                            "mismatch: `{a} * {b} + {c}`"
+                        );
+                        let [a, b, c] = [a, b, c].map(single_precision::Fp::new);
+                        assert_eq!(expected, a.mul_add(b, c).into());
+                        let [a, b, c] = [a, b, c].map(double_precision::Fp::from);
+                        assert_eq!(expected, a.mul_add(b, c).into());
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn multiply_by_zero() {
+            let res = Fp::new(1) * Fp::new(0);
+
             assert_eq!(Fp::new(0), res);
         }
 
+        #[test]
+        fn u32_slice_views() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let values = (0..1024).map(|_| rng.gen()).collect::<Vec<Fp>>();
+            let raw = as_u32_slice(&values);
+            assert_eq!(values.len(), raw.len());
+            assert!(raw.iter().zip(&values).all(|(&r, &v)| r == u32::from(v)));
+            assert_eq!(values, from_u32_slice(raw));
+            assert!(from_u32_slice(&[]).is_empty());
+        }
+
+        #[test]
+        #[cfg(debug_assertions)]
+        #[should_panic(expected = "values must be canonical")]
+        fn u32_slice_view_rejects_non_canonical() {
+            from_u32_slice(&[0, MODULUS]);
+        }
+
         #[test]
         fn addition() {
             let mut rng = StdRng::from_seed([1; 32]);
@@ -253,15 +848,368 @@ pub mod integer {
                 }
             }
         }
+
+        #[test]
+        fn subtraction() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..1024)
+                .map(|_| rng.gen::<u32>() % MODULUS)
+                .chain([0, 1, 2, MODULUS - 2, MODULUS - 1])
+                .collect::<Vec<u32>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let expected = Fp::new((a + MODULUS - b) % MODULUS);
+                    let actual = Fp::new(a) - Fp::new(b);
+                    assert_eq!(expected, actual, "mismatch: `{a} - {b}`");
+                    assert_eq!(Fp::new(a), actual + Fp::new(b), "mismatch: `{a} - {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn negation() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases =
+                (0..1 << 16)
+                    .map(|_| rng.gen::<u32>() % MODULUS)
+                    .chain([0, 1, MODULUS - 1]);
+            for a in edge_cases {
+                let expected = Fp::new((MODULUS - a) % MODULUS);
+                let actual = -Fp::new(a);
+                assert_eq!(expected, actual, "mismatch: `-{a}`");
+                assert_eq!(Fp::new(0), actual + Fp::new(a), "mismatch: `-{a}`");
+            }
+        }
+
+        #[test]
+        fn pow() {
+            let pow_ref = |base: u32, mut exp: u64| {
+                let (mut base, mut res) = (base as u128, 1u128);
+                while exp != 0 {
+                    if exp & 1 == 1 {
+                        res = res * base % MODULUS as u128;
+                    }
+                    base = base * base % MODULUS as u128;
+                    exp >>= 1;
+                }
+                Fp::new(res as u32)
+            };
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1024 {
+                let a = rng.gen::<u32>() % MODULUS;
+                let exp = rng.gen::<u64>();
+                let expected = pow_ref(a, exp);
+                assert_eq!(expected, Fp::new(a).pow(exp), "mismatch: `{a}^{exp}`");
+                let single = single_precision::Fp::new(a).pow(exp);
+                assert_eq!(expected, single.into(), "mismatch: `{a}^{exp}`");
+                let double = double_precision::Fp::new(a).pow(exp);
+                assert_eq!(expected, double.into(), "mismatch: `{a}^{exp}`");
+            }
+            assert_eq!(Fp::new(1), Fp::new(0).pow(0));
+            assert_eq!(Fp::new(0), Fp::new(0).pow(1));
+            assert_eq!(Fp::new(1), Fp::new(5).pow(MODULUS as u64 - 1));
+        }
+
+        #[test]
+        fn sqrt() {
+            let mut squares = 0;
+            for a in 0..MODULUS {
+                let a = Fp::new(a);
+                if let Some(root) = a.sqrt() {
+                    assert_eq!(a, root * root, "mismatch: `sqrt({a:?})`");
+                    squares += 1;
+                }
+            }
+            // zero and the (p - 1) / 2 quadratic residues
+            assert_eq!(1 + (MODULUS - 1) / 2, squares);
+            assert_eq!(None, Fp::new(5).sqrt());
+            assert_eq!(Some(Fp::new(0)), Fp::new(0).sqrt());
+        }
+
+        #[test]
+        fn inverse() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases =
+                (0..1 << 12)
+                    .map(|_| rng.gen::<u32>() % MODULUS)
+                    .chain([1, 2, MODULUS - 1]);
+            for a in edge_cases.filter(|&a| a != 0) {
+                let inv = Fp::new(a).inverse().unwrap();
+                assert_eq!(Fp::new(1), Fp::new(a) * inv, "mismatch: `{a}^-1`");
+            }
+            assert_eq!(None, Fp::new(0).inverse());
+            assert_eq!(Some(Fp::new(MODULUS - 1)), Fp::new(MODULUS - 1).inverse());
+        }
+
+        #[test]
+        fn division() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1 << 12 {
+                let a: Fp = rng.gen();
+                let b: Fp = rng.gen();
+                if b == Fp::new(0) {
+                    continue;
+                }
+                assert_eq!(a, a / b * b, "mismatch: `{a:?} / {b:?}`");
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "division by zero")]
+        fn division_by_zero() {
+            let _ = Fp::new(1) / Fp::new(0);
+        }
+
+        #[test]
+        fn multiplicative_order() {
+            let group_order = MODULUS as u64 - 1;
+            assert_eq!(group_order, Fp::new(5).multiplicative_order());
+            assert_eq!(1, Fp::new(1).multiplicative_order());
+            assert_eq!(2, Fp::new(MODULUS - 1).multiplicative_order());
+
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..64 {
+                let x = rng.gen::<Fp>();
+                if x == Fp::new(0) {
+                    continue;
+                }
+                let order = x.multiplicative_order();
+                assert_eq!(0, group_order % order, "mismatch: `{x:?}`");
+                assert_eq!(Fp::ONE, x.pow(order), "mismatch: `{x:?}`");
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn multiplicative_order_of_zero() {
+            Fp::new(0).multiplicative_order();
+        }
+
+        #[test]
+        fn le_bits() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let values = (0..1 << 12)
+                .map(|_| rng.gen::<Fp>())
+                .chain([0, 1, 1 << 20, MODULUS - 1].map(Fp::new))
+                .collect::<Vec<Fp>>();
+            for x in values {
+                let bits = x.to_le_bits();
+                let v = u32::from(x);
+                for (i, &bit) in bits.iter().enumerate() {
+                    assert_eq!((v >> i) & 1 == 1, bit, "mismatch: bit {i} of `{v}`");
+                }
+                assert_eq!(x, Fp::from_le_bits(bits));
+
+                let single = single_precision::Fp::from(x);
+                assert_eq!(bits, single.to_le_bits(), "mismatch: `{v}`");
+                assert_eq!(single, single_precision::Fp::from_le_bits(bits));
+
+                let double = double_precision::Fp::from(x);
+                assert_eq!(bits, double.to_le_bits(), "mismatch: `{v}`");
+                assert_eq!(double, double_precision::Fp::from_le_bits(bits));
+            }
+            assert_eq!(21, NUM_BITS);
+        }
+
+        #[test]
+        fn conversion_round_trip() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..1024)
+                .map(|_| rng.gen::<u32>() % MODULUS)
+                .chain([0, 1, MODULUS - 1])
+                .collect::<Vec<u32>>();
+            for a in edge_cases {
+                let integer = integer::Fp::new(a);
+                let single = single_precision::Fp::new(a);
+                let double = double_precision::Fp::new(a);
+
+                assert_eq!(integer, integer::Fp::from(single), "mismatch: `{a}`");
+                assert_eq!(integer, integer::Fp::from(double), "mismatch: `{a}`");
+                assert_eq!(
+                    single,
+                    single_precision::Fp::from(integer),
+                    "mismatch: `{a}`"
+                );
+                assert_eq!(
+                    single,
+                    single_precision::Fp::from(double),
+                    "mismatch: `{a}`"
+                );
+                assert_eq!(
+                    double,
+                    double_precision::Fp::from(integer),
+                    "mismatch: `{a}`"
+                );
+                assert_eq!(
+                    double,
+                    double_precision::Fp::from(single),
+                    "mismatch: `{a}`"
+                );
+                assert_eq!(
+                    a,
+                    u32::from(single_precision::Fp::from(double_precision::Fp::from(
+                        single
+                    )))
+                );
+            }
+        }
+    }
+}
+
+/// Bitwise operations over the little-endian bit decompositions returned by
+/// `to_le_bits` (on every backend), for Monolith-style bricks and range
+/// checks. Results can exceed the modulus, check [`is_canonical`] before
+/// turning them back into an element with `from_le_bits`.
+pub mod bits {
+    use super::integer::MODULUS;
+    use super::integer::NUM_BITS;
+
+    /// Little-endian bit decomposition of a value below `2^NUM_BITS`
+    pub type Bits = [bool; NUM_BITS];
+
+    pub fn and(a: Bits, b: Bits) -> Bits {
+        std::array::from_fn(|i| a[i] & b[i])
+    }
+
+    pub fn or(a: Bits, b: Bits) -> Bits {
+        std::array::from_fn(|i| a[i] | b[i])
+    }
+
+    pub fn xor(a: Bits, b: Bits) -> Bits {
+        std::array::from_fn(|i| a[i] ^ b[i])
+    }
+
+    pub fn not(a: Bits) -> Bits {
+        a.map(|bit| !bit)
+    }
+
+    /// Shifts towards the most significant bit, dropping the bits shifted
+    /// past `NUM_BITS`
+    pub fn shl(a: Bits, n: usize) -> Bits {
+        std::array::from_fn(|i| i >= n && a[i - n])
+    }
+
+    /// Shifts towards the least significant bit
+    pub fn shr(a: Bits, n: usize) -> Bits {
+        std::array::from_fn(|i| i + n < NUM_BITS && a[i + n])
+    }
+
+    /// Rotates towards the most significant bit within `NUM_BITS`
+    pub fn rotate_left(a: Bits, n: usize) -> Bits {
+        let n = n % NUM_BITS;
+        std::array::from_fn(|i| a[(i + NUM_BITS - n) % NUM_BITS])
+    }
+
+    pub fn rotate_right(a: Bits, n: usize) -> Bits {
+        rotate_left(a, NUM_BITS - n % NUM_BITS)
+    }
+
+    /// Returns whether `a` encodes a value below the modulus, comparing from
+    /// the most significant bit down
+    pub fn is_canonical(a: Bits) -> bool {
+        for i in (0..NUM_BITS).rev() {
+            let modulus_bit = (MODULUS >> i) & 1 == 1;
+            if a[i] != modulus_bit {
+                return modulus_bit;
+            }
+        }
+        false
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::double_precision;
+        use super::super::integer::Fp;
+        use super::super::single_precision;
+        use super::*;
+        use rand::rngs::StdRng;
+        use rand::Rng;
+        use rand::SeedableRng;
+
+        const MASK: u32 = (1 << NUM_BITS) - 1;
+
+        fn to_u32(bits: Bits) -> u32 {
+            bits.iter()
+                .rev()
+                .fold(0, |acc, &bit| (acc << 1) | bit as u32)
+        }
+
+        #[test]
+        fn ops_match_u32() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1 << 12 {
+                let (x, y): (Fp, Fp) = (rng.gen(), rng.gen());
+                let (a, b) = (u32::from(x), u32::from(y));
+                // decompositions from the float backends
+                let (p, q) = (
+                    single_precision::Fp::from(x).to_le_bits(),
+                    double_precision::Fp::from(y).to_le_bits(),
+                );
+                assert_eq!(a & b, to_u32(and(p, q)));
+                assert_eq!(a | b, to_u32(or(p, q)));
+                assert_eq!(a ^ b, to_u32(xor(p, q)));
+                assert_eq!(!a & MASK, to_u32(not(p)));
+                for n in [0, 1, 7, NUM_BITS - 1, NUM_BITS] {
+                    assert_eq!((a << n) & MASK, to_u32(shl(p, n)), "`{a} << {n}`");
+                    assert_eq!(a >> n, to_u32(shr(p, n)), "`{a} >> {n}`");
+                    let rotated = ((a << n) | (a >> ((NUM_BITS - n) % NUM_BITS))) & MASK;
+                    assert_eq!(rotated, to_u32(rotate_left(p, n)), "`{a} <<< {n}`");
+                    assert_eq!(p, rotate_right(rotate_left(p, n), n));
+                }
+                let v = to_u32(xor(p, q));
+                assert_eq!(v < MODULUS, is_canonical(xor(p, q)));
+                if v < MODULUS {
+                    assert_eq!(
+                        x.to_le_bits(),
+                        Fp::from_le_bits(xor(xor(p, q), q)).to_le_bits()
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn canonical_boundary() {
+            for v in [0, 1, MODULUS - 1, MODULUS, MODULUS + 1, MASK] {
+                let bits = std::array::from_fn(|i| (v >> i) & 1 == 1);
+                assert_eq!(v < MODULUS, is_canonical(bits), "`{v}`");
+            }
+        }
     }
 }
 
 pub mod double_precision {
+    use super::integer::NUM_BITS;
+    use crate::field::Field;
+    use crate::macros::impl_assign_ops;
+    use crate::macros::impl_hash;
+    use crate::macros::impl_le_bytes;
+    use crate::macros::impl_num_traits;
+    use crate::macros::impl_pod;
+    use crate::macros::impl_serde;
+    use crate::macros::impl_sum_product;
+    use crate::macros::impl_word;
+    use crate::reduction::Backend;
+    use crate::reduction::Fma;
+    use crate::reduction::Reducer;
+    use crate::reduction::ReducerVisitor;
+    use crate::reduction::Reducible;
+    use crate::reduction::Shift;
+    use crate::rounding::Round;
+    use crate::rounding::Trunc;
+    use crate::stats;
+    use crate::trace::MulTrace;
+    use num_traits::MulAdd;
     use rand::distributions::Standard;
     use rand::prelude::Distribution;
     use std::fmt::Display;
     use std::ops::Add;
+    use std::ops::Div;
     use std::ops::Mul;
+    use std::ops::Neg;
+    use std::ops::Sub;
+
+    /// Rounding primitive used for the quotient in [`Fp`] multiplication
+    pub type Rounding = Trunc;
 
     #[repr(transparent)]
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
@@ -279,11 +1227,114 @@ pub mod double_precision {
             debug_assert!(v < Self::MODULUS as u32);
             Self(v as f64)
         }
+
+        /// Computes `self^exp` by square-and-multiply
+        pub fn pow(self, exp: u64) -> Self {
+            Field::pow(self, exp)
+        }
+
+        /// Computes `self^-1` as `self^(p - 2)` by Fermat's little theorem.
+        /// Returns `None` for zero.
+        pub fn inverse(self) -> Option<Self> {
+            (self != Self::ZERO).then(|| self.pow(Self::MODULUS as u64 - 2))
+        }
+
+        /// Returns a square root of `self` if one exists. `p = 3 mod 4` so the
+        /// only candidate up to sign is `self^((p + 1) / 4)`.
+        pub fn sqrt(self) -> Option<Self> {
+            let root = self.pow((Self::MODULUS as u64 + 1) / 4);
+            (root * root == self).then_some(root)
+        }
+
+        /// Little-endian bits extracted by repeated halving, without a
+        /// round trip through an integer register
+        pub fn to_le_bits(self) -> [bool; NUM_BITS] {
+            let mut v = self.0;
+            std::array::from_fn(|_| {
+                let half = v * 0.5;
+                v = half.trunc();
+                half != v
+            })
+        }
+
+        /// Inverse of [`Fp::to_le_bits`]. The bits must encode a canonical
+        /// element.
+        pub fn from_le_bits(bits: [bool; NUM_BITS]) -> Self {
+            let v = bits.iter().rev().fold(0.0, |acc: f64, &bit| {
+                acc.mul_add(2.0, f64::from(u8::from(bit)))
+            });
+            debug_assert!(v < Self::MODULUS);
+            Self(v)
+        }
+
+        /// Multiplication with the quotient rounded by `R`. The product of two
+        /// elements is exact in an f64 so no FMA low part is needed.
+        ///
+        /// Source: https://arxiv.org/ftp/arxiv/papers/1407/1407.3383.pdf (function 14)
+        #[inline]
+        #[must_use]
+        pub fn mul_with<R: Round>(self, rhs: Self) -> Self {
+            self.mul_reduced::<Fma<R>>(rhs)
+        }
+
+        /// Multiplication with the remainder computed by `S`. The product is
+        /// exact so `S` sees `l = 0`.
+        #[inline]
+        #[must_use]
+        pub fn mul_reduced<S: Reducer<f64>>(self, rhs: Self) -> Self {
+            let h = self.0 * rhs.0;
+            let d = S::reduce(h, 0.0, Self::MODULUS, Self::MODULUS_INV);
+            stats::record(stats::Site::Fp20DoubleMul, d, Self::MODULUS);
+            Self(if d >= Self::MODULUS {
+                d - Self::MODULUS
+            } else if d < 0.0 {
+                d + Self::MODULUS
+            } else {
+                d
+            })
+        }
+
+        /// Computes `self * a + b` with a single reduction. `a * b + c` is
+        /// below `2^43` so one FMA computes it exactly.
+        #[inline]
+        #[must_use]
+        pub fn mul_add(self, a: Self, b: Self) -> Self {
+            let h = self.0.mul_add(a.0, b.0);
+            let d = Fma::<Rounding>::reduce(h, 0.0, Self::MODULUS, Self::MODULUS_INV);
+            Self(if d >= Self::MODULUS {
+                d - Self::MODULUS
+            } else if d < 0.0 {
+                d + Self::MODULUS
+            } else {
+                d
+            })
+        }
+
+        /// Returns every intermediate of `self * rhs` (with [`Rounding`]).
+        /// `l` is always zero as the product is exact.
+        pub fn trace_mul(self, rhs: Self) -> MulTrace<f64> {
+            let h = self.0 * rhs.0;
+            let b = h * Self::MODULUS_INV;
+            let c = Rounding::round_f64(b);
+            let d = h - c * Self::MODULUS;
+            MulTrace {
+                lhs: self.0,
+                rhs: rhs.0,
+                modulus: Self::MODULUS,
+                h,
+                l: 0.0,
+                b,
+                c,
+                d,
+                e: d,
+                result: (self * rhs).0,
+            }
+        }
     }
 
     impl Display for Fp {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            self.0.fmt(f)
+            u32::from(*self).fmt(f)
         }
     }
 
@@ -292,6 +1343,7 @@ pub mod double_precision {
 
         fn add(self, rhs: Self) -> Self {
             let a = self.0 + rhs.0;
+            stats::record(stats::Site::Fp20DoubleAdd, a, Self::MODULUS);
             Self(if a >= Self::MODULUS {
                 a - Self::MODULUS
             } else {
@@ -300,24 +1352,107 @@ pub mod double_precision {
         }
     }
 
+    impl Sub for Fp {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            let a = self.0 - rhs.0;
+            Self(if a < 0.0 { a + Self::MODULUS } else { a })
+        }
+    }
+
+    impl Neg for Fp {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            Self(if self.0 == 0.0 {
+                0.0
+            } else {
+                Self::MODULUS - self.0
+            })
+        }
+    }
+
+    impl Div for Fp {
+        type Output = Self;
+
+        /// Panics if `rhs` is zero
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn div(self, rhs: Self) -> Self {
+            self * rhs.inverse().expect("division by zero")
+        }
+    }
+
     impl Mul for Fp {
         type Output = Self;
 
-        /// Source: https://arxiv.org/ftp/arxiv/papers/1407/1407.3383.pdf (function 14)
         fn mul(self, rhs: Self) -> Self {
-            let a = self.0 * rhs.0;
-            let b = a * Self::MODULUS_INV;
-            let c = b.trunc();
-            let d = a - c * Self::MODULUS;
-            Self(d)
-            // TODO: depending on the rounding mode
-            // Self(if d >= Self::MODULUS {
-            //     d - Self::MODULUS
-            // } else if d < 0.0 {
-            //     d + Self::MODULUS
-            // } else {
-            //     d
-            // })
+            self.mul_with::<Rounding>(rhs)
+        }
+    }
+
+    impl_assign_ops!(
+        Fp;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_sum_product!(Fp);
+
+    impl_num_traits!(Fp);
+
+    impl_hash!(Fp);
+
+    impl_serde!(Fp);
+
+    impl_le_bytes!(Fp, u32);
+
+    impl_pod!(Fp);
+    impl_word!(Fp, f64);
+
+    impl From<u32> for Fp {
+        #[inline]
+        fn from(value: u32) -> Self {
+            Self::new(value % Self::MODULUS as u32)
+        }
+    }
+
+    impl Field for Fp {
+        const ZERO: Self = Self::ZERO;
+        const ONE: Self = Self::ONE;
+
+        const ORDER: u64 = Self::MODULUS as u64;
+
+        fn from_canonical_u32(v: u32) -> Self {
+            Self::new(v)
+        }
+
+        fn to_canonical_u64(self) -> u64 {
+            u32::from(self).into()
+        }
+    }
+
+    impl MulAdd for Fp {
+        type Output = Self;
+
+        #[inline]
+        fn mul_add(self, a: Self, b: Self) -> Self {
+            Fp::mul_add(self, a, b)
+        }
+    }
+
+    impl Reducible for Fp {
+        type Float = f64;
+
+        fn mul_reduced<S: Reducer<f64>>(self, rhs: Self) -> Self {
+            Fp::mul_reduced::<S>(self, rhs)
+        }
+
+        /// `p = 2^21 - 9` so the shift-and-add folding applies as well
+        fn for_each_reducer(visitor: &mut impl ReducerVisitor<f64>) {
+            f64::for_each_reducer(visitor);
+            visitor.visit::<Shift<21, 9>>("shift");
         }
     }
 
@@ -336,6 +1471,13 @@ pub mod double_precision {
         }
     }
 
+    impl From<Fp> for super::single_precision::Fp {
+        #[inline]
+        fn from(value: Fp) -> Self {
+            Self::new(value.0 as u32)
+        }
+    }
+
     impl From<Fp> for u32 {
         #[inline]
         fn from(value: Fp) -> Self {
@@ -347,6 +1489,9 @@ pub mod double_precision {
     mod tests {
         use super::super::integer;
         use super::*;
+        use crate::reduction::Barrett;
+        use crate::rounding::Floor;
+        use crate::rounding::Nearest;
         use rand::rngs::StdRng;
         use rand::Rng;
         use rand::SeedableRng;
@@ -368,6 +1513,75 @@ pub mod double_precision {
             }
         }
 
+        fn multiplication_with<R: Round>() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..1024)
+                .map(|_| rng.gen::<u32>() % MODULUS)
+                .chain([0, 1, 2, MODULUS - 2, MODULUS - 1])
+                .collect::<Vec<u32>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let expected = Fp::new((a as u64 * b as u64 % MODULUS as u64) as u32);
+                    let actual = Fp::new(a).mul_with::<R>(Fp::new(b));
+                    assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn multiplication_trunc() {
+            multiplication_with::<Trunc>();
+        }
+
+        #[test]
+        fn multiplication_floor() {
+            multiplication_with::<Floor>();
+        }
+
+        #[test]
+        fn multiplication_nearest() {
+            multiplication_with::<Nearest>();
+        }
+
+        fn multiplication_reduced<S: Reducer<f64>>() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1 << 16 {
+                let (a, b) = (rng.gen::<u32>() % MODULUS, rng.gen::<u32>() % MODULUS);
+                let expected = Fp::new((a as u64 * b as u64 % MODULUS as u64) as u32);
+                let actual = Fp::new(a).mul_reduced::<S>(Fp::new(b));
+                assert_eq!(expected, actual, "mismatch: `{a} * {b}`");
+            }
+        }
+
+        #[test]
+        fn multiplication_barrett() {
+            multiplication_reduced::<Barrett>();
+        }
+
+        #[test]
+        fn multiplication_shift() {
+            multiplication_reduced::<Shift<21, 9>>();
+        }
+
+        #[test]
+        fn trace_mul() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1024 {
+                let a: Fp = rng.gen();
+                let b: Fp = rng.gen();
+                let trace = a.trace_mul(b);
+                assert_eq!((a * b).0, trace.result);
+                for v in [trace.h, trace.l, trace.c, trace.d, trace.e] {
+                    assert_eq!(0.0, v.fract(), "inexact step: {trace}");
+                }
+            }
+            let trace = Fp::new(MODULUS - 1).trace_mul(Fp::new(MODULUS - 1));
+            assert_eq!(1.0, trace.result);
+            assert!(trace
+                .to_string()
+                .starts_with("2097142 * 2097142 mod 2097143\n"));
+        }
+
         #[test]
         fn addition() {
             let mut rng = StdRng::from_seed([1; 32]);
@@ -390,5 +1604,496 @@ pub mod double_precision {
                 }
             }
         }
+
+        #[test]
+        fn subtraction() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..1024)
+                .map(|_| rng.gen::<u32>() % MODULUS)
+                .chain([0, 1, 2, MODULUS - 2, MODULUS - 1])
+                .collect::<Vec<u32>>();
+            for &a in &edge_cases {
+                for &b in &edge_cases {
+                    let expected = integer::Fp::new(a) - integer::Fp::new(b);
+                    let actual = Fp::new(a) - Fp::new(b);
+                    assert_eq!(expected, actual.into(), "mismatch: `{a} - {b}`");
+                }
+            }
+        }
+
+        #[test]
+        fn negation() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases =
+                (0..1 << 16)
+                    .map(|_| rng.gen::<u32>() % MODULUS)
+                    .chain([0, 1, MODULUS - 1]);
+            for a in edge_cases {
+                let expected = -integer::Fp::new(a);
+                let actual = -Fp::new(a);
+                assert_eq!(expected, actual.into(), "mismatch: `-{a}`");
+                assert_eq!(Fp::new(0), actual + Fp::new(a), "mismatch: `-{a}`");
+            }
+        }
+
+        #[test]
+        fn sqrt() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..1 << 12).map(|_| rng.gen::<u32>() % MODULUS).chain([
+                0,
+                1,
+                2,
+                4,
+                5,
+                MODULUS - 1,
+            ]);
+            for a in edge_cases {
+                let expected = integer::Fp::new(a).sqrt();
+                let actual = Fp::new(a).sqrt();
+                assert_eq!(expected, actual.map(Into::into), "mismatch: `sqrt({a})`");
+            }
+        }
+
+        #[test]
+        fn inverse() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let edge_cases = (0..1 << 12)
+                .map(|_| rng.gen::<u32>() % MODULUS)
+                .chain([1, 2, MODULUS - 1])
+                .collect::<Vec<u32>>();
+            for a in edge_cases.into_iter().filter(|&a| a != 0) {
+                let expected = integer::Fp::new(a).inverse().unwrap();
+                let actual = Fp::new(a).inverse().unwrap();
+                assert_eq!(expected, actual.into(), "mismatch: `{a}^-1`");
+                let b = rng.gen::<u32>() % MODULUS;
+                let expected = integer::Fp::new(b) / integer::Fp::new(a);
+                let actual = Fp::new(b) / Fp::new(a);
+                assert_eq!(expected, actual.into(), "mismatch: `{b} / {a}`");
+            }
+            assert_eq!(None, Fp::new(0).inverse());
+        }
+
+        #[test]
+        fn multiplicative_order() {
+            let group_order = MODULUS as u64 - 1;
+            assert_eq!(group_order, Fp::new(5).multiplicative_order());
+            assert_eq!(1, Fp::new(1).multiplicative_order());
+            assert_eq!(2, Fp::new(MODULUS - 1).multiplicative_order());
+
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..64 {
+                let x = rng.gen::<Fp>();
+                if x == Fp::new(0) {
+                    continue;
+                }
+                let expected = integer::Fp::from(x).multiplicative_order();
+                assert_eq!(expected, x.multiplicative_order(), "mismatch: `{x}`");
+            }
+        }
+    }
+}
+
+/// Quadratic extension `Fp2 = Fp[i] / (i^2 + 1)` over the integer backend
+/// (irreducible since `p ≡ 3 mod 4`).
+///
+/// fp20 has two-adicity 1 but `p^2 - 1 = (p - 1)(p + 1)` with
+/// `p + 1 = 2^3 * 262143`, so `Fp2^*` has a subgroup of order 16 and supports
+/// radix-2 NTTs up to that length.
+pub mod extension {
+    use super::integer::Fp;
+    use super::integer::MODULUS;
+    use crate::domain::Domain;
+    use crate::field::Field;
+    use crate::macros::impl_assign_ops;
+    use crate::macros::impl_hash;
+    use crate::macros::impl_num_traits;
+    use crate::macros::impl_sum_product;
+    use num_traits::MulAdd;
+    use rand::distributions::Standard;
+    use rand::prelude::Distribution;
+    use std::fmt::Display;
+    use std::ops::Add;
+    use std::ops::Mul;
+    use std::ops::Neg;
+    use std::ops::Sub;
+
+    /// Largest power-of-two subgroup of `Fp2^*`
+    pub const MAX_NTT_SIZE: usize = 16;
+
+    /// `c0 + c1 * i`, laid out as the pair `[c0, c1]`
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Fp2 {
+        pub c0: Fp,
+        pub c1: Fp,
+    }
+
+    impl Fp2 {
+        const ZERO: Self = Self::new(0, 0);
+        const ONE: Self = Self::new(1, 0);
+
+        /// Element of order [`MAX_NTT_SIZE`]
+        const ROOT_OF_UNITY: Self = Self::new(1191006, 559618);
+
+        #[inline]
+        #[must_use]
+        pub const fn new(c0: u32, c1: u32) -> Self {
+            Self {
+                c0: Fp::new(c0),
+                c1: Fp::new(c1),
+            }
+        }
+
+        /// Computes `self^exp` by square-and-multiply
+        pub fn pow(self, exp: u64) -> Self {
+            Field::pow(self, exp)
+        }
+
+        /// Little-endian bytes of `c0` followed by those of `c1`
+        #[inline]
+        pub fn to_le_bytes(self) -> [u8; 8] {
+            let mut bytes = [0; 8];
+            bytes[..4].copy_from_slice(&self.c0.to_le_bytes());
+            bytes[4..].copy_from_slice(&self.c1.to_le_bytes());
+            bytes
+        }
+
+        /// Inverse of [`Fp2::to_le_bytes`]. Returns `None` unless both
+        /// coefficients are canonical.
+        #[inline]
+        pub fn from_le_bytes(bytes: [u8; 8]) -> Option<Self> {
+            let [c0, c1] =
+                [&bytes[..4], &bytes[4..]].map(|b| Fp::from_le_bytes(b.try_into().unwrap()));
+            Some(Self { c0: c0?, c1: c1? })
+        }
+
+        /// Computes `self * a + b`, fusing `b` into the base field products
+        #[inline]
+        #[must_use]
+        pub fn mul_add(self, a: Self, b: Self) -> Self {
+            Self {
+                c0: self.c0.mul_add(a.c0, b.c0) - self.c1 * a.c1,
+                c1: self.c0.mul_add(a.c1, self.c1.mul_add(a.c0, b.c1)),
+            }
+        }
+
+        /// Returns a primitive `n`-th root of unity for `n | 16`
+        pub fn root_of_unity(n: usize) -> Self {
+            assert!(n.is_power_of_two() && n <= MAX_NTT_SIZE);
+            Self::ROOT_OF_UNITY.pow((MAX_NTT_SIZE / n) as u64)
+        }
+
+        /// Subgroup of order `n` generated by [`Fp2::root_of_unity`]
+        pub fn domain(n: usize) -> Domain<Self> {
+            Self::coset(n, Self::ONE)
+        }
+
+        /// Coset `shift * <w>` of the subgroup of order `n`
+        pub fn coset(n: usize, shift: Self) -> Domain<Self> {
+            let inverse = |x: Self| x.pow(Self::order() - 2);
+            let size = Self::from(n as u32);
+            Domain::new(
+                n,
+                Self::root_of_unity(n),
+                shift,
+                inverse(shift),
+                inverse(size),
+            )
+        }
+    }
+
+    /// `c0 + c1 i` with `i^2 = -1`
+    impl Display for Fp2 {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{} + {}i", self.c0, self.c1)
+        }
+    }
+
+    impl Add for Fp2 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            Self {
+                c0: self.c0 + rhs.c0,
+                c1: self.c1 + rhs.c1,
+            }
+        }
+    }
+
+    impl Sub for Fp2 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            Self {
+                c0: self.c0 - rhs.c0,
+                c1: self.c1 - rhs.c1,
+            }
+        }
+    }
+
+    impl Neg for Fp2 {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            Self {
+                c0: -self.c0,
+                c1: -self.c1,
+            }
+        }
+    }
+
+    impl Mul for Fp2 {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self {
+            Self {
+                c0: self.c0 * rhs.c0 - self.c1 * rhs.c1,
+                c1: self.c0 * rhs.c1 + self.c1 * rhs.c0,
+            }
+        }
+    }
+
+    impl_assign_ops!(
+        Fp2;
+        AddAssign::add_assign => +,
+        SubAssign::sub_assign => -,
+        MulAssign::mul_assign => *,
+    );
+
+    impl_sum_product!(Fp2);
+
+    impl_num_traits!(Fp2);
+
+    impl_hash!(Fp2);
+
+    /// Serialized as the pair of canonical coefficients `[c0, c1]`
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for Fp2 {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            [self.c0, self.c1].serialize(serializer)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for Fp2 {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            <[Fp; 2]>::deserialize(deserializer).map(Self::from)
+        }
+    }
+
+    impl From<Fp> for Fp2 {
+        #[inline]
+        fn from(c0: Fp) -> Self {
+            Self { c0, c1: Fp::new(0) }
+        }
+    }
+
+    impl From<[Fp; 2]> for Fp2 {
+        #[inline]
+        fn from([c0, c1]: [Fp; 2]) -> Self {
+            Self { c0, c1 }
+        }
+    }
+
+    impl From<Fp2> for [Fp; 2] {
+        #[inline]
+        fn from(value: Fp2) -> Self {
+            [value.c0, value.c1]
+        }
+    }
+
+    impl From<u32> for Fp2 {
+        #[inline]
+        fn from(value: u32) -> Self {
+            Fp::from(value).into()
+        }
+    }
+
+    impl Field for Fp2 {
+        const ZERO: Self = Self::ZERO;
+        const ONE: Self = Self::ONE;
+
+        const ORDER: u64 = MODULUS as u64 * MODULUS as u64;
+
+        fn from_canonical_u32(v: u32) -> Self {
+            // every u32 is below p^2
+            Self::new(v % MODULUS, v / MODULUS)
+        }
+
+        fn to_canonical_u64(self) -> u64 {
+            u64::from(u32::from(self.c0)) + MODULUS as u64 * u64::from(u32::from(self.c1))
+        }
+    }
+
+    impl MulAdd for Fp2 {
+        type Output = Self;
+
+        #[inline]
+        fn mul_add(self, a: Self, b: Self) -> Self {
+            Fp2::mul_add(self, a, b)
+        }
+    }
+
+    impl Distribution<Fp2> for Standard {
+        fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Fp2 {
+            Fp2 {
+                c0: self.sample(rng),
+                c1: self.sample(rng),
+            }
+        }
+    }
+
+    /// Packs pairs of base field elements `[a0, a1, ...]` as `a0 + a1 * i`.
+    /// Odd lengths are padded with zero.
+    pub fn pack(values: &[Fp]) -> Vec<Fp2> {
+        values
+            .chunks(2)
+            .map(|pair| Fp2 {
+                c0: pair[0],
+                c1: pair.get(1).copied().unwrap_or(Fp::new(0)),
+            })
+            .collect()
+    }
+
+    /// Inverse of [`pack`] (including any zero padding)
+    pub fn unpack(values: &[Fp2]) -> Vec<Fp> {
+        values.iter().flat_map(|v| [v.c0, v.c1]).collect()
+    }
+
+    /// In-place radix-2 NTT evaluating the polynomial with coefficients
+    /// `values` over `domain`: `values[k] = sum_j values[j] * (shift * w^k)^j`
+    pub fn ntt(values: &mut [Fp2], domain: &Domain<Fp2>) {
+        assert_eq!(domain.size(), values.len(), "domain size mismatch");
+        if domain.shift() != Fp2::ONE {
+            scale_by_powers(values, domain.shift(), Fp2::ONE);
+        }
+        transform(values, domain.twiddles());
+    }
+
+    /// Inverse of [`ntt`] over the same `domain`
+    pub fn intt(values: &mut [Fp2], domain: &Domain<Fp2>) {
+        assert_eq!(domain.size(), values.len(), "domain size mismatch");
+        transform(values, domain.inverse_twiddles());
+        scale_by_powers(values, domain.shift_inv(), domain.size_inv());
+    }
+
+    /// `values[j] *= scale * base^j`
+    fn scale_by_powers(values: &mut [Fp2], base: Fp2, scale: Fp2) {
+        let mut factor = scale;
+        for v in values {
+            *v *= factor;
+            factor *= base;
+        }
+    }
+
+    /// Radix-2 Cooley-Tukey transform where `twiddles` holds the first
+    /// `n / 2` powers of the root
+    fn transform(values: &mut [Fp2], twiddles: &[Fp2]) {
+        let n = values.len();
+        if n == 1 {
+            return;
+        }
+
+        let log_n = n.trailing_zeros();
+        for i in 0..n {
+            let j = i.reverse_bits() >> (usize::BITS - log_n);
+            if i < j {
+                values.swap(i, j);
+            }
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let stride = n / len;
+            for chunk in values.chunks_mut(len) {
+                let (lo, hi) = chunk.split_at_mut(len / 2);
+                for (j, (u, v)) in lo.iter_mut().zip(hi).enumerate() {
+                    let t = *v * twiddles[j * stride];
+                    *v = *u - t;
+                    *u += t;
+                }
+            }
+            len *= 2;
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use rand::rngs::StdRng;
+        use rand::Rng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn root_of_unity_order() {
+            assert_eq!(16, Fp2::ROOT_OF_UNITY.multiplicative_order());
+            for n in [1, 2, 4, 8, 16] {
+                assert_eq!(n as u64, Fp2::root_of_unity(n).multiplicative_order());
+            }
+            // i has order 4
+            assert_eq!(4, Fp2::new(0, 1).multiplicative_order());
+            assert_eq!(Fp2::ZERO, Fp2::new(0, 1) * Fp2::new(0, 1) + Fp2::ONE);
+        }
+
+        #[test]
+        fn fused_mul_add() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1 << 12 {
+                let (a, b, c): (Fp2, Fp2, Fp2) = rng.gen();
+                assert_eq!(a * b + c, a.mul_add(b, c));
+            }
+        }
+
+        #[test]
+        fn ntt_matches_naive_evaluation() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            for n in [1, 2, 4, 8, 16] {
+                for domain in [Fp2::domain(n), Fp2::coset(n, rng.gen())] {
+                    let values = (0..n).map(|_| rng.gen()).collect::<Vec<Fp2>>();
+                    let expected = domain
+                        .elements()
+                        .map(|x| values.iter().rev().fold(Fp2::ZERO, |acc, &v| acc * x + v))
+                        .collect::<Vec<Fp2>>();
+
+                    let mut actual = values.clone();
+                    ntt(&mut actual, &domain);
+                    assert_eq!(expected, actual, "mismatch for {domain:?}");
+
+                    intt(&mut actual, &domain);
+                    assert_eq!(values, actual, "round trip mismatch for {domain:?}");
+                }
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "domain size mismatch")]
+        fn ntt_rejects_mismatched_domain() {
+            ntt(&mut [Fp2::ONE; 4], &Fp2::domain(8));
+        }
+
+        #[test]
+        #[should_panic(expected = "generator must have order 8")]
+        fn domain_rejects_wrong_generator() {
+            Domain::new(
+                8,
+                Fp2::root_of_unity(4),
+                Fp2::ONE,
+                Fp2::ONE,
+                Fp2::from(8).pow(Fp2::order() - 2),
+            );
+        }
+
+        #[test]
+        fn pack_unpack() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let values = (0..32).map(|_| rng.gen()).collect::<Vec<Fp>>();
+            assert_eq!(values, unpack(&pack(&values)));
+
+            let odd = &values[..7];
+            let packed = pack(odd);
+            assert_eq!(4, packed.len());
+            assert_eq!(Fp::new(0), packed[3].c1);
+            assert_eq!(odd, &unpack(&packed)[..7]);
+        }
     }
 }
@@ -0,0 +1,299 @@
+//! Prime fields over f16 with the modulus as a const parameter, the smallest
+//! end of the precision spectrum next to [`crate::fp_f32`] and
+//! [`crate::fp_f64`]:
+//!
+//! ```
+//! use ark_experimentation::fp_f16::FpF16;
+//!
+//! type F = FpF16<251>;
+//! assert_eq!(F::new(1), F::new(250) * F::new(250));
+//! ```
+//!
+//! Moduli at or above [`MAX_MODULUS`] are rejected at compile time:
+//!
+//! ```compile_fail
+//! use ark_experimentation::fp_f16::FpF16;
+//!
+//! let _ = FpF16::<257>::new(1);
+//! ```
+use crate::arithmetic::half_precision::mad_f16;
+use crate::field::Field;
+use crate::macros::impl_assign_ops;
+use crate::macros::impl_hash;
+use crate::macros::impl_le_bytes;
+use crate::macros::impl_num_traits;
+use crate::macros::impl_pod;
+use crate::macros::impl_serde;
+use crate::macros::impl_sum_product;
+use crate::macros::impl_word;
+use half::f16;
+use num_traits::Float;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use std::fmt::Display;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+
+/// Exclusive bound on the modulus. Products stay below `255^2 < 65504` (the
+/// largest finite f16) and the FMA low part is at most `16`. The f16
+/// reciprocal only has 11 bits so the quotient isn't within one of the exact
+/// one by a general bound, instead [`mul_mod`] is checked exhaustively for
+/// every modulus below this.
+pub const MAX_MODULUS: u16 = 256;
+
+/// Element of `Z/MZ` stored as a canonical integer valued f16. `M` should be
+/// prime for [`FpF16::inverse`] and division to be meaningful.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct FpF16<const M: u16>(f16);
+
+impl<const M: u16> FpF16<M> {
+    const VALID: () = assert!(
+        M >= 2 && M < MAX_MODULUS,
+        "modulus must be in [2, MAX_MODULUS)"
+    );
+
+    pub const MODULUS: u16 = {
+        let () = Self::VALID;
+        M
+    };
+    const MODULUS_F16: f16 = f16::from_f32_const(Self::MODULUS as f32);
+    const MODULUS_INV: f16 = reciprocal(Self::MODULUS);
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(1);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(v: u16) -> Self {
+        debug_assert!(v < Self::MODULUS);
+        Self(f16::from_f32_const(v as f32))
+    }
+
+    /// Computes `self^exp` by square-and-multiply
+    pub fn pow(self, exp: u64) -> Self {
+        Field::pow(self, exp)
+    }
+
+    /// Computes `self^-1` as `self^(p - 2)` by Fermat's little theorem.
+    /// Returns `None` for zero.
+    pub fn inverse(self) -> Option<Self> {
+        (self != Self::ZERO).then(|| self.pow(M as u64 - 2))
+    }
+}
+
+/// `m^-1` rounded to f16
+const fn reciprocal(m: u16) -> f16 {
+    f16::from_f32_const(1.0 / m as f32)
+}
+
+/// Computes `a * b mod m` for canonical `a, b` with the FMA reduction and a
+/// truncated quotient
+#[inline]
+fn mul_mod(a: f16, b: f16, m: f16, m_inv: f16) -> f16 {
+    let h = a * b;
+    let l = mad_f16(a, b, -h);
+    let c = (h * m_inv).trunc();
+    let e = mad_f16(-c, m, h) + l;
+    if e >= m {
+        e - m
+    } else if e < f16::ZERO {
+        e + m
+    } else {
+        e
+    }
+}
+
+impl<const M: u16> Display for FpF16<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        u16::from(*self).fmt(f)
+    }
+}
+
+impl<const M: u16> Add for FpF16<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let a = self.0 + rhs.0;
+        Self(if a >= Self::MODULUS_F16 {
+            a - Self::MODULUS_F16
+        } else {
+            a
+        })
+    }
+}
+
+impl<const M: u16> Sub for FpF16<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let a = self.0 - rhs.0;
+        Self(if a < f16::ZERO {
+            a + Self::MODULUS_F16
+        } else {
+            a
+        })
+    }
+}
+
+impl<const M: u16> Neg for FpF16<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(if self.0 == f16::ZERO {
+            f16::ZERO
+        } else {
+            Self::MODULUS_F16 - self.0
+        })
+    }
+}
+
+impl<const M: u16> Div for FpF16<M> {
+    type Output = Self;
+
+    /// Panics if `rhs` is zero
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse().expect("division by zero")
+    }
+}
+
+impl<const M: u16> Mul for FpF16<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(mul_mod(self.0, rhs.0, Self::MODULUS_F16, Self::MODULUS_INV))
+    }
+}
+
+impl_assign_ops!(
+    [const M: u16] FpF16<M>;
+    AddAssign::add_assign => +,
+    SubAssign::sub_assign => -,
+    MulAssign::mul_assign => *,
+);
+
+impl_sum_product!([const M: u16] FpF16<M>);
+
+impl_num_traits!([const M: u16] FpF16<M>);
+
+impl_hash!([const M: u16] FpF16<M>);
+
+impl_serde!([const M: u16] FpF16<M>);
+
+impl_le_bytes!([const M: u16] FpF16<M>, u16);
+
+impl_pod!([const M: u16] FpF16<M>);
+impl_word!([const M: u16] FpF16<M>, f16);
+
+impl<const M: u16> From<u32> for FpF16<M> {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self::new((value % Self::MODULUS as u32) as u16)
+    }
+}
+
+impl<const M: u16> From<FpF16<M>> for u16 {
+    #[inline]
+    fn from(value: FpF16<M>) -> Self {
+        value.0.to_f32() as u16
+    }
+}
+
+impl<const M: u16> From<FpF16<M>> for u32 {
+    #[inline]
+    fn from(value: FpF16<M>) -> Self {
+        u16::from(value).into()
+    }
+}
+
+impl<const M: u16> Field for FpF16<M> {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    const ORDER: u64 = Self::MODULUS as u64;
+
+    fn from_canonical_u32(v: u32) -> Self {
+        debug_assert!(v < M as u32);
+        Self::new(v as u16)
+    }
+
+    fn to_canonical_u64(self) -> u64 {
+        u16::from(self).into()
+    }
+}
+
+impl<const M: u16> Distribution<FpF16<M>> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> FpF16<M> {
+        FpF16::new(rng.gen_range(0..FpF16::<M>::MODULUS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every pair of elements against the integer reference
+    fn exhaustive<const M: u16>() {
+        let m = M as u32;
+        for a in 0..M {
+            let x = FpF16::<M>::new(a);
+            for b in 0..M {
+                let y = FpF16::<M>::new(b);
+                let (a, b) = (a as u32, b as u32);
+                assert_eq!(a * b % m, u32::from(x * y), "mismatch: `{a} * {b}`");
+                assert_eq!((a + b) % m, u32::from(x + y), "mismatch: `{a} + {b}`");
+                assert_eq!((a + m - b) % m, u32::from(x - y), "mismatch: `{a} - {b}`");
+            }
+            assert_eq!((m - a as u32) % m, u32::from(-x), "mismatch: `-{a}`");
+            if a != 0 {
+                assert_eq!(FpF16::ONE, x * x.inverse().unwrap(), "mismatch: `{a}^-1`");
+            }
+        }
+        assert_eq!(None, FpF16::<M>::ZERO.inverse());
+    }
+
+    #[test]
+    fn exhaustive_251() {
+        exhaustive::<251>();
+    }
+
+    #[test]
+    fn exhaustive_small_primes() {
+        exhaustive::<2>();
+        exhaustive::<3>();
+        exhaustive::<13>();
+        exhaustive::<127>();
+        exhaustive::<241>();
+    }
+
+    #[test]
+    fn mul_mod_every_modulus() {
+        for m in 2..MAX_MODULUS {
+            let (m_f16, m_inv) = (f16::from_f32(m.into()), reciprocal(m));
+            for a in 0..m {
+                for b in 0..m {
+                    let expected = (a as u32 * b as u32 % m as u32) as f32;
+                    let actual = mul_mod(
+                        f16::from_f32(a.into()),
+                        f16::from_f32(b.into()),
+                        m_f16,
+                        m_inv,
+                    );
+                    assert_eq!(expected, actual.to_f32(), "mismatch: `{a} * {b} mod {m}`");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generator_and_display() {
+        type F = FpF16<251>;
+        // 6 generates the multiplicative group of F_251
+        assert_eq!(250, F::new(6).multiplicative_order());
+        assert_eq!("250", (-F::ONE).to_string());
+        assert_eq!(F::new(1), F::from(252));
+    }
+}
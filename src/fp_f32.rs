@@ -0,0 +1,342 @@
+//! Prime fields over f32 with the modulus as a const parameter.
+//!
+//! Same representation and multiplication as [`crate::fp20::single_precision`]
+//! so moduli can be swept without copying the module:
+//!
+//! ```
+//! use ark_experimentation::fp_f32::FpF32;
+//!
+//! type F = FpF32<1048573>;
+//! assert_eq!(F::new(1), F::new(1048572) * F::new(1048572));
+//! ```
+//!
+//! Moduli at or above [`MAX_MODULUS`] are rejected at compile time:
+//!
+//! ```compile_fail
+//! use ark_experimentation::fp_f32::FpF32;
+//!
+//! let _ = FpF32::<4194319>::new(1);
+//! ```
+use crate::field::Field;
+use crate::macros::impl_assign_ops;
+use crate::macros::impl_hash;
+use crate::macros::impl_le_bytes;
+use crate::macros::impl_num_traits;
+use crate::macros::impl_pod;
+use crate::macros::impl_serde;
+use crate::macros::impl_sum_product;
+use crate::macros::impl_word;
+use crate::reduction::Fma;
+use crate::reduction::Reducer;
+use crate::reduction::Reducible;
+use crate::rounding::Floor;
+use crate::rounding::Round;
+use num_traits::MulAdd;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use std::fmt::Display;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+
+/// Exclusive bound on the modulus. The quotient `h * p^-1` takes three
+/// roundings so it's within `3 * 2^-24 * p < 1` of the exact one, which keeps
+/// the remainder in `(-p, 2p)` and every intermediate below `2^24`.
+pub const MAX_MODULUS: u32 = 1 << 22;
+
+/// Rounding primitive used for the quotient in [`FpF32`] multiplication
+pub type Rounding = Floor;
+
+/// Element of `Z/MZ` stored as a canonical integer valued f32. `M` should be
+/// prime for [`FpF32::inverse`] and division to be meaningful.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct FpF32<const M: u32>(f32);
+
+impl<const M: u32> FpF32<M> {
+    const VALID: () = assert!(
+        M >= 2 && M < MAX_MODULUS,
+        "modulus must be in [2, MAX_MODULUS)"
+    );
+
+    pub const MODULUS: u32 = {
+        let () = Self::VALID;
+        M
+    };
+    const MODULUS_F32: f32 = Self::MODULUS as f32;
+    const MODULUS_INV: f32 = 1.0 / Self::MODULUS_F32;
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(1);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(v: u32) -> Self {
+        debug_assert!(v < Self::MODULUS);
+        Self(v as f32)
+    }
+
+    /// Computes `self^exp` by square-and-multiply
+    pub fn pow(self, exp: u64) -> Self {
+        Field::pow(self, exp)
+    }
+
+    /// Computes `self^-1` as `self^(p - 2)` by Fermat's little theorem.
+    /// Returns `None` for zero.
+    pub fn inverse(self) -> Option<Self> {
+        (self != Self::ZERO).then(|| self.pow(M as u64 - 2))
+    }
+
+    /// Multiplication with the quotient rounded by `R`
+    #[inline]
+    #[must_use]
+    pub fn mul_with<R: Round>(self, rhs: Self) -> Self {
+        self.mul_reduced::<Fma<R>>(rhs)
+    }
+
+    /// Multiplication with the remainder computed by `S`
+    #[inline]
+    #[must_use]
+    pub fn mul_reduced<S: Reducer<f32>>(self, rhs: Self) -> Self {
+        let h = self.0 * rhs.0;
+        let l = self.0.mul_add(rhs.0, -h);
+        let e = S::reduce(h, l, Self::MODULUS_F32, Self::MODULUS_INV);
+        Self(if e >= Self::MODULUS_F32 {
+            e - Self::MODULUS_F32
+        } else if e < 0.0 {
+            e + Self::MODULUS_F32
+        } else {
+            e
+        })
+    }
+
+    /// Computes `self * a + b` with a single reduction. `b` is folded into the
+    /// low part of the product (exact as both are integers below `2^23`) so
+    /// the remainder lands in `(-p, 3p)`.
+    #[inline]
+    #[must_use]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        let h = self.0 * a.0;
+        let l = self.0.mul_add(a.0, -h) + b.0;
+        let e = Fma::<Rounding>::reduce(h, l, Self::MODULUS_F32, Self::MODULUS_INV);
+        Self(if e >= 2.0 * Self::MODULUS_F32 {
+            e - 2.0 * Self::MODULUS_F32
+        } else if e >= Self::MODULUS_F32 {
+            e - Self::MODULUS_F32
+        } else if e < 0.0 {
+            e + Self::MODULUS_F32
+        } else {
+            e
+        })
+    }
+}
+
+impl<const M: u32> Display for FpF32<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        u32::from(*self).fmt(f)
+    }
+}
+
+impl<const M: u32> Add for FpF32<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let a = self.0 + rhs.0;
+        Self(if a >= Self::MODULUS_F32 {
+            a - Self::MODULUS_F32
+        } else {
+            a
+        })
+    }
+}
+
+impl<const M: u32> Sub for FpF32<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let a = self.0 - rhs.0;
+        Self(if a < 0.0 { a + Self::MODULUS_F32 } else { a })
+    }
+}
+
+impl<const M: u32> Neg for FpF32<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(if self.0 == 0.0 {
+            0.0
+        } else {
+            Self::MODULUS_F32 - self.0
+        })
+    }
+}
+
+impl<const M: u32> Div for FpF32<M> {
+    type Output = Self;
+
+    /// Panics if `rhs` is zero
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse().expect("division by zero")
+    }
+}
+
+impl<const M: u32> Mul for FpF32<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_with::<Rounding>(rhs)
+    }
+}
+
+impl_assign_ops!(
+    [const M: u32] FpF32<M>;
+    AddAssign::add_assign => +,
+    SubAssign::sub_assign => -,
+    MulAssign::mul_assign => *,
+);
+
+impl_sum_product!([const M: u32] FpF32<M>);
+
+impl_num_traits!([const M: u32] FpF32<M>);
+
+impl_hash!([const M: u32] FpF32<M>);
+
+impl_serde!([const M: u32] FpF32<M>);
+
+impl_le_bytes!([const M: u32] FpF32<M>, u32);
+
+impl_pod!([const M: u32] FpF32<M>);
+impl_word!([const M: u32] FpF32<M>, f32);
+
+impl<const M: u32> From<u32> for FpF32<M> {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self::new(value % Self::MODULUS)
+    }
+}
+
+impl<const M: u32> From<FpF32<M>> for u32 {
+    #[inline]
+    fn from(value: FpF32<M>) -> Self {
+        value.0 as u32
+    }
+}
+
+impl<const M: u32> Field for FpF32<M> {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    const ORDER: u64 = Self::MODULUS as u64;
+
+    fn from_canonical_u32(v: u32) -> Self {
+        Self::new(v)
+    }
+
+    fn to_canonical_u64(self) -> u64 {
+        u32::from(self).into()
+    }
+}
+
+impl<const M: u32> MulAdd for FpF32<M> {
+    type Output = Self;
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        FpF32::mul_add(self, a, b)
+    }
+}
+
+impl<const M: u32> Reducible for FpF32<M> {
+    type Float = f32;
+
+    fn mul_reduced<S: Reducer<f32>>(self, rhs: Self) -> Self {
+        FpF32::mul_reduced::<S>(self, rhs)
+    }
+}
+
+impl<const M: u32> Distribution<FpF32<M>> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> FpF32<M> {
+        FpF32::new(rng.gen_range(0..FpF32::<M>::MODULUS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp20;
+    use crate::reduction::Barrett;
+    use crate::rounding::Nearest;
+    use crate::rounding::Trunc;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    fn arithmetic<const M: u32>() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let m = M as u64;
+        let values = (0..512)
+            .map(|_| rng.gen_range(0..M))
+            .chain([0, 1, 2, M - 2, M - 1])
+            .collect::<Vec<u32>>();
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (FpF32::<M>::new(a), FpF32::<M>::new(b));
+                let product = (a as u64 * b as u64 % m) as u32;
+                assert_eq!(product, u32::from(x * y), "mismatch: `{a} * {b}`");
+                assert_eq!(product, u32::from(x.mul_with::<Trunc>(y)));
+                assert_eq!(product, u32::from(x.mul_with::<Nearest>(y)));
+                assert_eq!(product, u32::from(x.mul_reduced::<Barrett>(y)));
+                assert_eq!(((a as u64 + b as u64) % m) as u32, u32::from(x + y));
+                assert_eq!(((a as u64 + m - b as u64) % m) as u32, u32::from(x - y));
+                let z = FpF32::<M>::new((a ^ b) % M);
+                assert_eq!(x * y + z, x.mul_add(y, z), "mismatch: `{a} * {b} + {z}`");
+            }
+            let x = FpF32::<M>::new(a);
+            assert_eq!(FpF32::ZERO, x + -x);
+            if a != 0 {
+                assert_eq!(FpF32::ONE, x * x.inverse().unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic_18_bit() {
+        arithmetic::<262139>();
+    }
+
+    #[test]
+    fn arithmetic_19_bit() {
+        arithmetic::<524287>();
+    }
+
+    #[test]
+    fn arithmetic_20_bit() {
+        arithmetic::<1048573>();
+    }
+
+    #[test]
+    fn arithmetic_21_bit() {
+        arithmetic::<2097143>();
+    }
+
+    #[test]
+    fn arithmetic_max_modulus() {
+        arithmetic::<4194301>();
+    }
+
+    #[test]
+    fn matches_fp20() {
+        type F = FpF32<2097143>;
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..1 << 16 {
+            let (a, b): (fp20::single_precision::Fp, fp20::single_precision::Fp) = rng.gen();
+            let (x, y) = (F::new(a.into()), F::new(b.into()));
+            assert_eq!(u32::from(a * b), u32::from(x * y));
+            if u32::from(b) != 0 {
+                assert_eq!(u32::from(a / b), u32::from(x / y));
+            }
+        }
+    }
+}
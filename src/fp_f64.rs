@@ -0,0 +1,344 @@
+//! Prime fields over f64 with the modulus as a const parameter.
+//!
+//! The f64 counterpart of [`crate::fp_f32`]. Products of large moduli aren't
+//! exact in an f64 so, unlike [`crate::fp20::double_precision`], the FMA low
+//! part is carried into the reduction:
+//!
+//! ```
+//! use ark_experimentation::fp_f64::FpF64;
+//!
+//! type F = FpF64<1099511627689>;
+//! assert_eq!(F::new(1), F::new(1099511627688) * F::new(1099511627688));
+//! ```
+//!
+//! Moduli at or above [`MAX_MODULUS`] are rejected at compile time:
+//!
+//! ```compile_fail
+//! use ark_experimentation::fp_f64::FpF64;
+//!
+//! let _ = FpF64::<1125899906842679>::new(1);
+//! ```
+use crate::field::Field;
+use crate::macros::impl_assign_ops;
+use crate::macros::impl_hash;
+use crate::macros::impl_le_bytes;
+use crate::macros::impl_num_traits;
+use crate::macros::impl_pod;
+use crate::macros::impl_serde;
+use crate::macros::impl_sum_product;
+use crate::macros::impl_word;
+use crate::reduction::Fma;
+use crate::reduction::Reducer;
+use crate::reduction::Reducible;
+use crate::rounding::Round;
+use crate::rounding::Trunc;
+use num_traits::MulAdd;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use std::fmt::Display;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+
+/// Exclusive bound on the modulus. The quotient `h * p^-1` takes three
+/// roundings so it's within `3 * 2^-53 * p < 1` of the exact one, which keeps
+/// the remainder in `(-p, 2p)`. The remainder before adding the low part
+/// (`|l| <= 2^49`) then stays below `2^53`.
+pub const MAX_MODULUS: u64 = 1 << 50;
+
+/// Rounding primitive used for the quotient in [`FpF64`] multiplication
+pub type Rounding = Trunc;
+
+/// Element of `Z/MZ` stored as a canonical integer valued f64. `M` should be
+/// prime for [`FpF64::inverse`] and division to be meaningful.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct FpF64<const M: u64>(f64);
+
+impl<const M: u64> FpF64<M> {
+    const VALID: () = assert!(
+        M >= 2 && M < MAX_MODULUS,
+        "modulus must be in [2, MAX_MODULUS)"
+    );
+
+    pub const MODULUS: u64 = {
+        let () = Self::VALID;
+        M
+    };
+    const MODULUS_F64: f64 = Self::MODULUS as f64;
+    const MODULUS_INV: f64 = 1.0 / Self::MODULUS_F64;
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(1);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(v: u64) -> Self {
+        debug_assert!(v < Self::MODULUS);
+        Self(v as f64)
+    }
+
+    /// Computes `self^exp` by square-and-multiply
+    pub fn pow(self, exp: u64) -> Self {
+        Field::pow(self, exp)
+    }
+
+    /// Computes `self^-1` as `self^(p - 2)` by Fermat's little theorem.
+    /// Returns `None` for zero.
+    pub fn inverse(self) -> Option<Self> {
+        (self != Self::ZERO).then(|| self.pow(M - 2))
+    }
+
+    /// Multiplication with the quotient rounded by `R`
+    #[inline]
+    #[must_use]
+    pub fn mul_with<R: Round>(self, rhs: Self) -> Self {
+        self.mul_reduced::<Fma<R>>(rhs)
+    }
+
+    /// Multiplication with the remainder computed by `S`
+    #[inline]
+    #[must_use]
+    pub fn mul_reduced<S: Reducer<f64>>(self, rhs: Self) -> Self {
+        let h = self.0 * rhs.0;
+        let l = self.0.mul_add(rhs.0, -h);
+        let e = S::reduce(h, l, Self::MODULUS_F64, Self::MODULUS_INV);
+        Self(if e >= Self::MODULUS_F64 {
+            e - Self::MODULUS_F64
+        } else if e < 0.0 {
+            e + Self::MODULUS_F64
+        } else {
+            e
+        })
+    }
+
+    /// Computes `self * a + b` with a single reduction. `b` is folded into the
+    /// low part of the product (exact as both are integers below `2^51`) so
+    /// the remainder lands in `(-p, 3p)`.
+    #[inline]
+    #[must_use]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        let h = self.0 * a.0;
+        let l = self.0.mul_add(a.0, -h) + b.0;
+        let e = Fma::<Rounding>::reduce(h, l, Self::MODULUS_F64, Self::MODULUS_INV);
+        Self(if e >= 2.0 * Self::MODULUS_F64 {
+            e - 2.0 * Self::MODULUS_F64
+        } else if e >= Self::MODULUS_F64 {
+            e - Self::MODULUS_F64
+        } else if e < 0.0 {
+            e + Self::MODULUS_F64
+        } else {
+            e
+        })
+    }
+}
+
+impl<const M: u64> Display for FpF64<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        u64::from(*self).fmt(f)
+    }
+}
+
+impl<const M: u64> Add for FpF64<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let a = self.0 + rhs.0;
+        Self(if a >= Self::MODULUS_F64 {
+            a - Self::MODULUS_F64
+        } else {
+            a
+        })
+    }
+}
+
+impl<const M: u64> Sub for FpF64<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let a = self.0 - rhs.0;
+        Self(if a < 0.0 { a + Self::MODULUS_F64 } else { a })
+    }
+}
+
+impl<const M: u64> Neg for FpF64<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(if self.0 == 0.0 {
+            0.0
+        } else {
+            Self::MODULUS_F64 - self.0
+        })
+    }
+}
+
+impl<const M: u64> Div for FpF64<M> {
+    type Output = Self;
+
+    /// Panics if `rhs` is zero
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse().expect("division by zero")
+    }
+}
+
+impl<const M: u64> Mul for FpF64<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_with::<Rounding>(rhs)
+    }
+}
+
+impl_assign_ops!(
+    [const M: u64] FpF64<M>;
+    AddAssign::add_assign => +,
+    SubAssign::sub_assign => -,
+    MulAssign::mul_assign => *,
+);
+
+impl_sum_product!([const M: u64] FpF64<M>);
+
+impl_num_traits!([const M: u64] FpF64<M>);
+
+impl_hash!([const M: u64] FpF64<M>);
+
+impl_serde!([const M: u64] FpF64<M> => Self::new);
+
+impl_le_bytes!([const M: u64] FpF64<M>, u64 => Self::new);
+
+impl_pod!([const M: u64] FpF64<M>);
+impl_word!([const M: u64] FpF64<M>, f64);
+
+impl<const M: u64> From<u32> for FpF64<M> {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self::new(u64::from(value) % Self::MODULUS)
+    }
+}
+
+impl<const M: u64> From<FpF64<M>> for u64 {
+    #[inline]
+    fn from(value: FpF64<M>) -> Self {
+        value.0 as u64
+    }
+}
+
+impl<const M: u64> Field for FpF64<M> {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    const ORDER: u64 = Self::MODULUS;
+
+    fn from_canonical_u32(v: u32) -> Self {
+        Self::new(v.into())
+    }
+
+    fn to_canonical_u64(self) -> u64 {
+        self.into()
+    }
+}
+
+impl<const M: u64> MulAdd for FpF64<M> {
+    type Output = Self;
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        FpF64::mul_add(self, a, b)
+    }
+}
+
+impl<const M: u64> Reducible for FpF64<M> {
+    type Float = f64;
+
+    fn mul_reduced<S: Reducer<f64>>(self, rhs: Self) -> Self {
+        FpF64::mul_reduced::<S>(self, rhs)
+    }
+}
+
+impl<const M: u64> Distribution<FpF64<M>> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> FpF64<M> {
+        FpF64::new(rng.gen_range(0..FpF64::<M>::MODULUS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp20;
+    use crate::reduction::Barrett;
+    use crate::rounding::Floor;
+    use crate::rounding::Nearest;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    fn arithmetic<const M: u64>() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let m = M as u128;
+        let values = (0..512)
+            .map(|_| rng.gen_range(0..M))
+            .chain([0, 1, 2, M - 2, M - 1])
+            .collect::<Vec<u64>>();
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (FpF64::<M>::new(a), FpF64::<M>::new(b));
+                let product = (a as u128 * b as u128 % m) as u64;
+                assert_eq!(product, u64::from(x * y), "mismatch: `{a} * {b}`");
+                assert_eq!(product, u64::from(x.mul_with::<Floor>(y)));
+                assert_eq!(product, u64::from(x.mul_with::<Nearest>(y)));
+                assert_eq!(product, u64::from(x.mul_reduced::<Barrett>(y)));
+                assert_eq!(((a as u128 + b as u128) % m) as u64, u64::from(x + y));
+                assert_eq!(((a as u128 + m - b as u128) % m) as u64, u64::from(x - y));
+                let z = FpF64::<M>::new((a ^ b) % M);
+                assert_eq!(x * y + z, x.mul_add(y, z), "mismatch: `{a} * {b} + {z}`");
+            }
+            let x = FpF64::<M>::new(a);
+            assert_eq!(FpF64::ZERO, x + -x);
+            if a != 0 {
+                assert_eq!(FpF64::ONE, x * x.inverse().unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic_21_bit() {
+        arithmetic::<2097143>();
+    }
+
+    #[test]
+    fn arithmetic_31_bit() {
+        arithmetic::<2147483647>();
+    }
+
+    #[test]
+    fn arithmetic_40_bit() {
+        arithmetic::<1099511627689>();
+    }
+
+    #[test]
+    fn arithmetic_49_bit() {
+        arithmetic::<562949953421231>();
+    }
+
+    #[test]
+    fn arithmetic_max_modulus() {
+        arithmetic::<1125899906842597>();
+    }
+
+    #[test]
+    fn matches_fp20() {
+        type F = FpF64<2097143>;
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..1 << 16 {
+            let (a, b): (fp20::double_precision::Fp, fp20::double_precision::Fp) = rng.gen();
+            let (x, y) = (F::new(u32::from(a).into()), F::new(u32::from(b).into()));
+            assert_eq!(u64::from(u32::from(a * b)), u64::from(x * y));
+            if u32::from(b) != 0 {
+                assert_eq!(u64::from(u32::from(a / b)), u64::from(x / y));
+            }
+        }
+    }
+}
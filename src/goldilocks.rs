@@ -0,0 +1,348 @@
+//! Goldilocks (`p = 2^64 - 2^32 + 1`) over double-double f64, to see whether
+//! floats can do 64-bit fields at all.
+//!
+//! Elements don't fit the 53-bit significand of an f64 so they're stored as
+//! an unevaluated sum `hi + lo` with `hi = fl(x)` and `lo = x - hi` (an
+//! integer with `|lo| <= 2^10`). The 128-bit product of two elements is
+//! computed exactly as a sum of seven f64s with the error-free
+//! transformations in [`crate::eft`]. Each term is split exactly into signed
+//! 32-bit digits and the digit sums are reduced with `2^64 = 2^32 - 1` and
+//! `2^96 = -1`, so every step is exact and no quotient is estimated.
+use crate::eft::two_prod;
+use crate::eft::two_sum;
+use crate::field::Field;
+use crate::macros::impl_assign_ops;
+use crate::macros::impl_hash;
+use crate::macros::impl_le_bytes;
+use crate::macros::impl_num_traits;
+use crate::macros::impl_serde;
+use crate::macros::impl_sum_product;
+use crate::slice::Word;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use std::fmt::Display;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+
+pub const MODULUS: u64 = 0xFFFF_FFFF_0000_0001;
+
+/// Generator of the multiplicative group (the one Plonky3 uses)
+pub const GENERATOR: Fp = Fp::new(7);
+
+/// `p - 1 = 2^32 * (2^32 - 1)`
+pub const TWO_ADICITY: u32 = 32;
+
+/// The modulus as a normalized double-double: `2^64 - 2^32` and `1`
+const MODULUS_DD: (f64, f64) = ((MODULUS - 1) as f64, 1.0);
+const TWO_POW_32: f64 = (1u64 << 32) as f64;
+const TWO_POW_64: f64 = (1u128 << 64) as f64;
+const TWO_POW_96: f64 = (1u128 << 96) as f64;
+
+/// Element of the Goldilocks field stored as a canonical normalized
+/// double-double
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fp {
+    hi: f64,
+    lo: f64,
+}
+
+impl Fp {
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(1);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(v: u64) -> Self {
+        debug_assert!(v < MODULUS);
+        let hi = v as f64;
+        Self {
+            hi,
+            lo: (v as i128 - hi as i128) as f64,
+        }
+    }
+
+    /// Computes `self^exp` by square-and-multiply
+    pub fn pow(self, exp: u64) -> Self {
+        Field::pow(self, exp)
+    }
+
+    /// Computes `self^-1` as `self^(p - 2)` by Fermat's little theorem.
+    /// Returns `None` for zero.
+    pub fn inverse(self) -> Option<Self> {
+        (self != Self::ZERO).then(|| self.pow(MODULUS - 2))
+    }
+
+    /// Canonical element equal to the normalized double-double `v`. Only the
+    /// sign of `hi` is inspected, which is the sign of `v` as `hi = fl(v)`.
+    /// Takes at most two corrections for `|v| < 2p`.
+    #[inline]
+    fn canonical(mut v: (f64, f64)) -> Self {
+        while v.0 < 0.0 {
+            v = dd_add(v, MODULUS_DD);
+        }
+        loop {
+            let w = dd_add(v, (-MODULUS_DD.0, -MODULUS_DD.1));
+            if w.0 < 0.0 {
+                return Self { hi: v.0, lo: v.1 };
+            }
+            v = w;
+        }
+    }
+
+    /// Canonical element equal to `hi * 2^32 + lo` for integer valued
+    /// `|hi| < 2^36` and `|lo| < 2^37`
+    #[inline]
+    fn reduce(hi: f64, lo: f64) -> Self {
+        // |s| < 2^69 so `e` is an integer with |e| <= 2^16
+        let (s, e) = two_sum(hi * TWO_POW_32, lo);
+        let [s0, s1, s2, _] = digits(s);
+        // fold `s2 * 2^64 = s2 * 2^32 - s2` once more, |v| < 2^64 + 2^38
+        Self::canonical(two_sum((s1 + s2) * TWO_POW_32, s0 + e - s2))
+    }
+}
+
+/// Sum of normalized integer valued double-doubles. Exact as long as the low
+/// parts stay below `2^53`, i.e. for sums below `2^106`.
+#[inline]
+fn dd_add(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let (s, e) = two_sum(a.0, b.0);
+    two_sum(s, e + a.1 + b.1)
+}
+
+/// Splits an integer valued `v` with `|v| < 2^128` into digits `d` with
+/// `v = d[0] + d[1] 2^32 + d[2] 2^64 + d[3] 2^96`, `|d[i]| < 2^32` and the
+/// sign of `v`. Truncation keeps every remainder made of the low bits of `v`
+/// so each step is exact.
+#[inline]
+fn digits(v: f64) -> [f64; 4] {
+    let d3 = (v * TWO_POW_96.recip()).trunc();
+    let v = v - d3 * TWO_POW_96;
+    let d2 = (v * TWO_POW_64.recip()).trunc();
+    let v = v - d2 * TWO_POW_64;
+    let d1 = (v * TWO_POW_32.recip()).trunc();
+    [v - d1 * TWO_POW_32, d1, d2, d3]
+}
+
+impl Display for Fp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        u64::from(*self).fmt(f)
+    }
+}
+
+impl Add for Fp {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::canonical(dd_add((self.hi, self.lo), (rhs.hi, rhs.lo)))
+    }
+}
+
+impl Sub for Fp {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::canonical(dd_add((self.hi, self.lo), (-rhs.hi, -rhs.lo)))
+    }
+}
+
+impl Neg for Fp {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl Div for Fp {
+    type Output = Self;
+
+    /// Panics if `rhs` is zero
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse().expect("division by zero")
+    }
+}
+
+impl Mul for Fp {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // (a_hi + a_lo)(b_hi + b_lo) as seven exact terms. |lo| <= 2^10 so
+        // `a_lo * b_lo` needs no error term.
+        let (p0, e0) = two_prod(self.hi, rhs.hi);
+        let (p1, e1) = two_prod(self.hi, rhs.lo);
+        let (p2, e2) = two_prod(self.lo, rhs.hi);
+        let p3 = self.lo * rhs.lo;
+        // digit sums stay below 7 * 2^32 so they're exact
+        let mut d = [0.0; 4];
+        for t in [p0, e0, p1, e1, p2, e2, p3] {
+            for (d, t) in d.iter_mut().zip(digits(t)) {
+                *d += t;
+            }
+        }
+        // 2^64 = 2^32 - 1 and 2^96 = -1
+        Self::reduce(d[1] + d[2], d[0] - d[2] - d[3])
+    }
+}
+
+impl_assign_ops!(
+    Fp;
+    AddAssign::add_assign => +,
+    SubAssign::sub_assign => -,
+    MulAssign::mul_assign => *,
+);
+
+impl_sum_product!(Fp);
+
+impl_num_traits!(Fp);
+
+impl_hash!(Fp);
+
+impl_serde!(Fp => Fp::new);
+
+impl_le_bytes!(Fp, u64 => Fp::new);
+
+impl Word for Fp {
+    type Bits = u128;
+
+    #[inline]
+    fn to_bits(self) -> u128 {
+        u128::from(self.hi.to_bits()) | u128::from(self.lo.to_bits()) << 64
+    }
+
+    #[inline]
+    fn from_bits(bits: u128) -> Self {
+        Self {
+            hi: f64::from_bits(bits as u64),
+            lo: f64::from_bits((bits >> 64) as u64),
+        }
+    }
+}
+
+impl From<u32> for Fp {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self::new(u64::from(value))
+    }
+}
+
+impl From<Fp> for u64 {
+    #[inline]
+    fn from(value: Fp) -> Self {
+        (value.hi as i128 + value.lo as i128) as u64
+    }
+}
+
+impl Field for Fp {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    const ORDER: u64 = MODULUS;
+
+    fn from_canonical_u32(v: u32) -> Self {
+        Self::new(v.into())
+    }
+
+    fn to_canonical_u64(self) -> u64 {
+        self.into()
+    }
+}
+
+impl Distribution<Fp> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Fp {
+        Fp::new(rng.gen_range(0..MODULUS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    const P: u128 = MODULUS as u128;
+
+    /// Values around the powers of two where `hi` and `lo` change shape
+    fn edge_values() -> Vec<u64> {
+        let mut values = vec![0, 1, 2, MODULUS - 2, MODULUS - 1];
+        for k in [10, 11, 31, 32, 33, 52, 53, 54, 63] {
+            values.extend([(1 << k) - 1, 1 << k, (1 << k) + 1]);
+        }
+        values.extend([MODULUS - (1 << 32), MODULUS - (1 << 11) - 1]);
+        values
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for v in (0..1 << 16)
+            .map(|_| rng.gen_range(0..MODULUS))
+            .chain(edge_values())
+        {
+            let x = Fp::new(v);
+            assert_eq!(v, u64::from(x));
+            assert_eq!(x.hi, v as f64);
+            assert!(x.lo.abs() <= 1024.0);
+        }
+    }
+
+    #[test]
+    fn matches_u128_reference() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let edges = edge_values();
+        let random = (0..1 << 16).map(|_| (rng.gen_range(0..MODULUS), rng.gen_range(0..MODULUS)));
+        let pairs = edges
+            .iter()
+            .flat_map(|&a| edges.iter().map(move |&b| (a, b)));
+        for (a, b) in random.chain(pairs) {
+            let (x, y) = (Fp::new(a), Fp::new(b));
+            let (a_i, b_i) = (a as u128, b as u128);
+            assert_eq!(
+                a_i * b_i % P,
+                u64::from(x * y) as u128,
+                "mismatch: `{a} * {b}`"
+            );
+            assert_eq!(
+                (a_i + b_i) % P,
+                u64::from(x + y) as u128,
+                "mismatch: `{a} + {b}`"
+            );
+            assert_eq!(
+                (a_i + P - b_i) % P,
+                u64::from(x - y) as u128,
+                "mismatch: `{a} - {b}`"
+            );
+            assert_eq!((P - a_i) % P, u64::from(-x) as u128, "mismatch: `-{a}`");
+            // results are canonical so equality of the representation works
+            assert_eq!(x * y, Fp::new(u64::from(x * y)));
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for a in (0..256)
+            .map(|_| rng.gen_range(1..MODULUS))
+            .chain([1, 2, MODULUS - 1])
+        {
+            let x = Fp::new(a);
+            assert_eq!(Fp::ONE, x * x.inverse().unwrap(), "mismatch: `{a}^-1`");
+            assert_eq!(Fp::ONE, x / x);
+        }
+        assert_eq!(None, Fp::ZERO.inverse());
+    }
+
+    #[test]
+    fn generator_and_two_adicity() {
+        let p = MODULUS;
+        assert_eq!(p - 1, GENERATOR.multiplicative_order());
+        let root = GENERATOR.pow((p - 1) >> TWO_ADICITY);
+        assert_eq!(1 << TWO_ADICITY, root.multiplicative_order());
+        // 2^96 = -1
+        assert_eq!(-Fp::ONE, Fp::from(2).pow(96));
+    }
+}
@@ -0,0 +1,398 @@
+//! Conversions between the crate's fields and other libraries' field
+//! elements, for differential tests and mixed pipelines.
+
+/// arkworks (`ark-ff`) counterparts of the crate's fields
+pub mod ark {
+    use crate::fp20;
+    use ark_ff::BigInt;
+    use ark_ff::Fp64;
+    use ark_ff::MontBackend;
+    use ark_ff::MontConfig;
+    use ark_ff::PrimeField;
+    pub use config::Fp20Config;
+
+    // the ark-ff 0.4 derive expands to impls nested in a function
+    #[allow(non_local_definitions)]
+    mod config {
+        use ark_ff::MontConfig;
+
+        #[derive(MontConfig)]
+        #[modulus = "2097143"]
+        #[generator = "5"]
+        pub struct Fp20Config;
+    }
+
+    /// fp20 (`p = 2^21 - 9`) as an arkworks Montgomery field
+    pub type Fp20 = Fp64<MontBackend<Fp20Config, 1>>;
+
+    const _: () = assert!(Fp20Config::MODULUS.0[0] == fp20::integer::MODULUS as u64);
+
+    /// Converts any fp20 representation into an arkworks element
+    #[inline]
+    pub fn to_ark<F: Into<u32>>(value: F) -> Fp20 {
+        Fp20::from_bigint(BigInt([value.into() as u64])).unwrap()
+    }
+
+    /// Converts an arkworks element into any fp20 representation
+    #[inline]
+    pub fn from_ark<F: From<u32>>(value: Fp20) -> F {
+        F::from(value.into_bigint().0[0] as u32)
+    }
+
+    pub fn slice_to_ark<F: Copy + Into<u32>>(values: &[F]) -> Vec<Fp20> {
+        values.iter().map(|&v| to_ark(v)).collect()
+    }
+
+    pub fn slice_from_ark<F: From<u32>>(values: &[Fp20]) -> Vec<F> {
+        values.iter().map(|&v| from_ark(v)).collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use rand::rngs::StdRng;
+        use rand::Rng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn round_trip() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let values = (0..1024)
+                .map(|_| rng.gen())
+                .chain([0, 1, fp20::integer::MODULUS - 1].map(fp20::integer::Fp::new))
+                .collect::<Vec<fp20::integer::Fp>>();
+            let ark = slice_to_ark(&values);
+            assert_eq!(values, slice_from_ark::<fp20::integer::Fp>(&ark));
+            let single = slice_from_ark::<fp20::single_precision::Fp>(&ark);
+            assert_eq!(ark, slice_to_ark(&single));
+        }
+
+        #[test]
+        fn arithmetic_matches_ark() {
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..1 << 16 {
+                let a: fp20::double_precision::Fp = rng.gen();
+                let b: fp20::double_precision::Fp = rng.gen();
+                assert_eq!(
+                    to_ark(a) * to_ark(b),
+                    to_ark(a * b),
+                    "mismatch: `{a} * {b}`"
+                );
+                assert_eq!(
+                    to_ark(a) + to_ark(b),
+                    to_ark(a + b),
+                    "mismatch: `{a} + {b}`"
+                );
+            }
+        }
+    }
+}
+
+/// Plonky3 (`p3-field`) field traits for the crate's fields, so the float
+/// backends can be dropped into Plonky3 NTTs and permutations directly.
+///
+/// Written against Plonky3 v0.1.0 (the `FieldAlgebra` API). Plonky3 requires
+/// a few std traits the fields don't otherwise implement (`Default`, `Ord`);
+/// they're provided here over the canonical value, serde support comes with
+/// the `serde` feature. `Packing` is the scalar itself.
+///
+/// Plonky3's own `Mersenne31` and `BabyBear` convert to and from [`m31::Fp`]
+/// and [`baby_bear::Fp`] for differential tests against them.
+#[cfg(feature = "p3")]
+pub mod p3 {
+    use crate::baby_bear;
+    use crate::f65537;
+    use crate::field::Field;
+    use crate::fp20;
+    use crate::goldilocks;
+    use crate::m31;
+    use num_bigint::BigUint;
+    use p3_baby_bear::BabyBear;
+    use p3_field::FieldAlgebra;
+    use p3_field::Packable;
+    use p3_field::PrimeField;
+    use p3_field::PrimeField32;
+    use p3_field::PrimeField64;
+    use p3_mersenne_31::Mersenne31;
+    use std::cmp::Ordering;
+
+    macro_rules! impl_p3_field {
+        (@common $t:ty, $order:expr, $generator:expr, $new:path) => {
+            impl Default for $t {
+                fn default() -> Self {
+                    <$t as Field>::ZERO
+                }
+            }
+
+            impl Packable for $t {}
+
+            impl FieldAlgebra for $t {
+                type F = Self;
+
+                const ZERO: Self = $new(0);
+                const ONE: Self = $new(1);
+                const TWO: Self = $new(2);
+                const NEG_ONE: Self = $new(($order - 1) as _);
+
+                fn from_f(f: Self) -> Self {
+                    f
+                }
+
+                fn from_bool(b: bool) -> Self {
+                    $new(b.into())
+                }
+
+                fn from_canonical_u8(n: u8) -> Self {
+                    $new(n.into())
+                }
+
+                fn from_canonical_u16(n: u16) -> Self {
+                    $new(n.into())
+                }
+
+                fn from_canonical_u32(n: u32) -> Self {
+                    debug_assert!(u64::from(n) < $order);
+                    $new(n as _)
+                }
+
+                fn from_canonical_u64(n: u64) -> Self {
+                    debug_assert!(n < $order);
+                    $new(n as _)
+                }
+
+                fn from_canonical_usize(n: usize) -> Self {
+                    Self::from_canonical_u64(n as u64)
+                }
+
+                fn from_wrapped_u32(n: u32) -> Self {
+                    $new((u64::from(n) % $order) as _)
+                }
+
+                fn from_wrapped_u64(n: u64) -> Self {
+                    $new((n % $order) as _)
+                }
+            }
+
+            impl p3_field::Field for $t {
+                type Packing = Self;
+
+                const GENERATOR: Self = $generator;
+
+                fn try_inverse(&self) -> Option<Self> {
+                    Field::inverse(*self)
+                }
+
+                fn order() -> BigUint {
+                    $order.into()
+                }
+            }
+
+            impl PrimeField for $t {
+                fn as_canonical_biguint(&self) -> BigUint {
+                    self.to_canonical_u64().into()
+                }
+            }
+
+            impl PrimeField64 for $t {
+                const ORDER_U64: u64 = $order;
+
+                fn as_canonical_u64(&self) -> u64 {
+                    self.to_canonical_u64()
+                }
+            }
+        };
+        // fields that already derive `PartialOrd`, which agrees with the
+        // canonical order as elements are stored canonically
+        (@ord_only $t:ty) => {
+            #[allow(clippy::derive_ord_xor_partial_ord)]
+            impl Ord for $t {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.to_canonical_u64().cmp(&other.to_canonical_u64())
+                }
+            }
+        };
+        (@ord $t:ty) => {
+            impl_p3_field!(@ord_only $t);
+
+            impl PartialOrd for $t {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+        };
+        (@prime32 $t:ty, $order:expr) => {
+            impl PrimeField32 for $t {
+                const ORDER_U32: u32 = $order;
+
+                fn as_canonical_u32(&self) -> u32 {
+                    self.to_canonical_u32()
+                }
+            }
+        };
+    }
+
+    impl_p3_field!(
+        @common fp20::integer::Fp,
+        fp20::integer::MODULUS as u64,
+        fp20::integer::Fp::new(5),
+        fp20::integer::Fp::new
+    );
+    impl_p3_field!(@ord_only fp20::integer::Fp);
+    impl_p3_field!(@prime32 fp20::integer::Fp, fp20::integer::MODULUS);
+
+    impl_p3_field!(
+        @common fp20::single_precision::Fp,
+        fp20::integer::MODULUS as u64,
+        fp20::single_precision::Fp::new(5),
+        fp20::single_precision::Fp::new
+    );
+    impl_p3_field!(@ord_only fp20::single_precision::Fp);
+    impl_p3_field!(@prime32 fp20::single_precision::Fp, fp20::integer::MODULUS);
+
+    impl_p3_field!(
+        @common fp20::double_precision::Fp,
+        fp20::integer::MODULUS as u64,
+        fp20::double_precision::Fp::new(5),
+        fp20::double_precision::Fp::new
+    );
+    impl_p3_field!(@ord_only fp20::double_precision::Fp);
+    impl_p3_field!(@prime32 fp20::double_precision::Fp, fp20::integer::MODULUS);
+
+    impl_p3_field!(@common f65537::Fp, f65537::MODULUS as u64, f65537::GENERATOR, f65537::Fp::new);
+    impl_p3_field!(@ord_only f65537::Fp);
+    impl_p3_field!(@prime32 f65537::Fp, f65537::MODULUS);
+
+    impl_p3_field!(@common m31::Fp, m31::MODULUS as u64, m31::GENERATOR, m31::Fp::new);
+    impl_p3_field!(@ord_only m31::Fp);
+    impl_p3_field!(@prime32 m31::Fp, m31::MODULUS);
+
+    impl_p3_field!(
+        @common baby_bear::Fp,
+        baby_bear::MODULUS as u64,
+        baby_bear::GENERATOR,
+        baby_bear::Fp::new
+    );
+    impl_p3_field!(@ord_only baby_bear::Fp);
+    impl_p3_field!(@prime32 baby_bear::Fp, baby_bear::MODULUS);
+
+    impl_p3_field!(
+        @common goldilocks::Fp,
+        goldilocks::MODULUS,
+        goldilocks::GENERATOR,
+        goldilocks::Fp::new
+    );
+    impl_p3_field!(@ord goldilocks::Fp);
+
+    #[inline]
+    pub fn to_mersenne_31(value: m31::Fp) -> Mersenne31 {
+        Mersenne31::from_canonical_u32(value.to_canonical_u32())
+    }
+
+    #[inline]
+    pub fn from_mersenne_31(value: Mersenne31) -> m31::Fp {
+        <m31::Fp as Field>::from_canonical_u32(value.as_canonical_u32())
+    }
+
+    pub fn slice_to_mersenne_31(values: &[m31::Fp]) -> Vec<Mersenne31> {
+        values.iter().map(|&v| to_mersenne_31(v)).collect()
+    }
+
+    pub fn slice_from_mersenne_31(values: &[Mersenne31]) -> Vec<m31::Fp> {
+        values.iter().map(|&v| from_mersenne_31(v)).collect()
+    }
+
+    #[inline]
+    pub fn to_baby_bear(value: baby_bear::Fp) -> BabyBear {
+        BabyBear::from_canonical_u32(value.to_canonical_u32())
+    }
+
+    #[inline]
+    pub fn from_baby_bear(value: BabyBear) -> baby_bear::Fp {
+        <baby_bear::Fp as Field>::from_canonical_u32(value.as_canonical_u32())
+    }
+
+    pub fn slice_to_baby_bear(values: &[baby_bear::Fp]) -> Vec<BabyBear> {
+        values.iter().map(|&v| to_baby_bear(v)).collect()
+    }
+
+    pub fn slice_from_baby_bear(values: &[BabyBear]) -> Vec<baby_bear::Fp> {
+        values.iter().map(|&v| from_baby_bear(v)).collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use rand::rngs::StdRng;
+        use rand::Rng;
+        use rand::SeedableRng;
+
+        fn check<F: PrimeField64>()
+        where
+            rand::distributions::Standard: rand::prelude::Distribution<F>,
+        {
+            let p = F::ORDER_U64;
+            assert_eq!(F::ONE, F::GENERATOR.exp_u64(p - 1));
+            assert_eq!(F::NEG_ONE, F::GENERATOR.exp_u64((p - 1) / 2));
+            assert_eq!(
+                F::from_canonical_u64(u64::MAX % p),
+                F::from_wrapped_u64(u64::MAX)
+            );
+            assert_eq!(None, F::ZERO.try_inverse());
+            let mut rng = StdRng::from_seed([1; 32]);
+            for _ in 0..256 {
+                let x: F = rng.gen();
+                assert_eq!(x, F::from_canonical_u64(x.as_canonical_u64()));
+                if !x.is_zero() {
+                    assert_eq!(F::ONE, x * x.inverse());
+                }
+                let json = serde_json::to_string(&x).unwrap();
+                assert_eq!(x, serde_json::from_str(&json).unwrap());
+            }
+        }
+
+        #[test]
+        fn fields() {
+            check::<fp20::integer::Fp>();
+            check::<fp20::single_precision::Fp>();
+            check::<fp20::double_precision::Fp>();
+            check::<f65537::Fp>();
+            check::<m31::Fp>();
+            check::<baby_bear::Fp>();
+            check::<goldilocks::Fp>();
+        }
+
+        /// Random elements plus the edge values `0`, `1` and `p - 1`
+        fn values<F: Field>() -> Vec<F>
+        where
+            rand::distributions::Standard: rand::prelude::Distribution<F>,
+        {
+            let mut rng = StdRng::from_seed([1; 32]);
+            (0..1024)
+                .map(|_| rng.gen())
+                .chain([F::ZERO, F::ONE, -F::ONE])
+                .collect()
+        }
+
+        #[test]
+        fn mersenne_31_round_trip() {
+            let values = values::<m31::Fp>();
+            let p3 = slice_to_mersenne_31(&values);
+            assert_eq!(values, slice_from_mersenne_31(&p3));
+            for (&a, &b) in values.iter().zip(values.iter().rev()) {
+                assert_eq!(to_mersenne_31(a) * to_mersenne_31(b), to_mersenne_31(a * b));
+                assert_eq!(to_mersenne_31(a) + to_mersenne_31(b), to_mersenne_31(a + b));
+            }
+        }
+
+        #[test]
+        fn baby_bear_round_trip() {
+            let values = values::<baby_bear::Fp>();
+            let p3 = slice_to_baby_bear(&values);
+            assert_eq!(values, slice_from_baby_bear(&p3));
+            for (&a, &b) in values.iter().zip(values.iter().rev()) {
+                assert_eq!(to_baby_bear(a) * to_baby_bear(b), to_baby_bear(a * b));
+                assert_eq!(to_baby_bear(a) + to_baby_bear(b), to_baby_bear(a + b));
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Dense linear algebra over the crate's fields (scalar reference
+//! implementations).
+//!
+//! Matrices are row-major slices of `n * n` elements. Over a field every
+//! nonzero element is a valid pivot so there is no magnitude based pivoting.
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Sub;
+
+/// Solves `a * x = b` by Gauss-Jordan elimination. Returns `None` if `a` is
+/// singular.
+pub fn solve<F>(a: &[F], b: &[F]) -> Option<Vec<F>>
+where
+    F: Copy + PartialEq + Sub<Output = F> + Mul<Output = F> + Div<Output = F> + From<u32>,
+{
+    let n = b.len();
+    assert_eq!(n * n, a.len(), "expected a {n}x{n} matrix");
+    let zero = F::from(0);
+    let mut m = a.to_vec();
+    let mut x = b.to_vec();
+    for col in 0..n {
+        let pivot = (col..n).find(|&row| m[row * n + col] != zero)?;
+        if pivot != col {
+            for j in col..n {
+                m.swap(pivot * n + j, col * n + j);
+            }
+            x.swap(pivot, col);
+        }
+        let pivot_inv = F::from(1) / m[col * n + col];
+        for row in (0..n).filter(|&row| row != col) {
+            let factor = m[row * n + col] * pivot_inv;
+            if factor == zero {
+                continue;
+            }
+            for j in col..n {
+                m[row * n + j] = m[row * n + j] - factor * m[col * n + j];
+            }
+            x[row] = x[row] - factor * x[col];
+        }
+    }
+    for (i, x) in x.iter_mut().enumerate() {
+        *x = *x / m[i * n + i];
+    }
+    Some(x)
+}
+
+/// Returns the rank of the row-major matrix `a` with `cols` columns
+pub fn rank<F>(a: &[F], cols: usize) -> usize
+where
+    F: Copy + PartialEq + Sub<Output = F> + Mul<Output = F> + Div<Output = F> + From<u32>,
+{
+    let rows = a.len().checked_div(cols).unwrap_or(0);
+    assert_eq!(rows * cols, a.len(), "expected rows of {cols} elements");
+    row_echelon(&mut a.to_vec(), cols).0
+}
+
+/// Returns the determinant of the `n x n` matrix `a` as the signed product of
+/// the pivots of its row echelon form
+pub fn determinant<F>(a: &[F], n: usize) -> F
+where
+    F: Copy + PartialEq + Sub<Output = F> + Mul<Output = F> + Div<Output = F> + From<u32>,
+{
+    assert_eq!(n * n, a.len(), "expected a {n}x{n} matrix");
+    let mut m = a.to_vec();
+    let (rank, odd_swaps) = row_echelon(&mut m, n);
+    if rank < n {
+        return F::from(0);
+    }
+    let det = (0..n).fold(F::from(1), |acc, i| acc * m[i * n + i]);
+    if odd_swaps {
+        F::from(0) - det
+    } else {
+        det
+    }
+}
+
+/// Reduces `m` to row echelon form in place. Returns the rank and whether an
+/// odd number of row swaps was made.
+fn row_echelon<F>(m: &mut [F], cols: usize) -> (usize, bool)
+where
+    F: Copy + PartialEq + Sub<Output = F> + Mul<Output = F> + Div<Output = F> + From<u32>,
+{
+    // a matrix without columns has no rows either
+    let rows = m.len().checked_div(cols).unwrap_or(0);
+    let zero = F::from(0);
+    let mut rank = 0;
+    let mut odd_swaps = false;
+    for col in 0..cols {
+        if rank == rows {
+            break;
+        }
+        let Some(pivot) = (rank..rows).find(|&row| m[row * cols + col] != zero) else {
+            continue;
+        };
+        if pivot != rank {
+            for j in col..cols {
+                m.swap(pivot * cols + j, rank * cols + j);
+            }
+            odd_swaps = !odd_swaps;
+        }
+        let pivot_inv = F::from(1) / m[rank * cols + col];
+        for row in rank + 1..rows {
+            let factor = m[row * cols + col] * pivot_inv;
+            if factor == zero {
+                continue;
+            }
+            for j in col..cols {
+                m[row * cols + j] = m[row * cols + j] - factor * m[rank * cols + j];
+            }
+        }
+        rank += 1;
+    }
+    (rank, odd_swaps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp20;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    type Fp = fp20::integer::Fp;
+
+    fn mat_vec_mul(a: &[Fp], x: &[Fp]) -> Vec<Fp> {
+        a.chunks_exact(x.len())
+            .map(|row| {
+                row.iter()
+                    .zip(x)
+                    .fold(Fp::new(0), |acc, (&a, &x)| acc + a * x)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn solve_random_systems() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for n in [1, 2, 3, 8, 32] {
+            let a = (0..n * n).map(|_| rng.gen()).collect::<Vec<Fp>>();
+            let x = (0..n).map(|_| rng.gen()).collect::<Vec<Fp>>();
+            let b = mat_vec_mul(&a, &x);
+            assert_eq!(Some(x.clone()), solve(&a, &b), "n = {n}");
+
+            let single = |values: &[Fp]| {
+                values
+                    .iter()
+                    .map(|&v| fp20::single_precision::Fp::from(v))
+                    .collect::<Vec<_>>()
+            };
+            let actual = solve(&single(&a), &single(&b)).unwrap();
+            assert_eq!(single(&x), actual, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn solve_requires_row_swap() {
+        let a = [0, 1, 1, 0].map(Fp::new);
+        let b = [2, 3].map(Fp::new);
+        assert_eq!(Some(vec![Fp::new(3), Fp::new(2)]), solve(&a, &b));
+    }
+
+    #[test]
+    fn vandermonde_determinant() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for n in [1, 2, 5, 16] {
+            let xs = (0..n).map(|_| rng.gen()).collect::<Vec<Fp>>();
+            let a = xs
+                .iter()
+                .flat_map(|&x| (0..n as u64).map(move |k| x.pow(k)))
+                .collect::<Vec<Fp>>();
+            let mut expected = Fp::new(1);
+            for i in 0..n {
+                for j in i + 1..n {
+                    expected *= xs[j] - xs[i];
+                }
+            }
+            assert_eq!(expected, determinant(&a, n), "n = {n}");
+            assert_eq!(n, rank(&a, n), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn determinant_sign_and_singular() {
+        let identity = [1, 0, 0, 0, 1, 0, 0, 0, 1].map(Fp::new);
+        assert_eq!(Fp::new(1), determinant(&identity, 3));
+        let swapped = [0, 1, 0, 1, 0, 0, 0, 0, 1].map(Fp::new);
+        assert_eq!(-Fp::new(1), determinant(&swapped, 3));
+        let singular = [1, 2, 3, 2, 4, 6, 0, 0, 1].map(Fp::new);
+        assert_eq!(Fp::new(0), determinant(&singular, 3));
+        assert_eq!(2, rank(&singular, 3));
+    }
+
+    #[test]
+    fn empty_matrices() {
+        assert_eq!(0, rank::<Fp>(&[], 0));
+        assert_eq!(0, rank::<Fp>(&[], 3));
+        assert_eq!(Fp::new(1), determinant::<Fp>(&[], 0));
+        assert_eq!(Some(vec![]), solve::<Fp>(&[], &[]));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected rows of 0 elements")]
+    fn rank_rejects_zero_columns_with_elements() {
+        rank(&[Fp::new(1)], 0);
+    }
+
+    #[test]
+    fn rank_of_rectangular_matrices() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let (rows, cols) = (4, 7);
+        let mut a = (0..rows * cols).map(|_| rng.gen()).collect::<Vec<Fp>>();
+        assert_eq!(rows, rank(&a, cols));
+        assert_eq!(rows, rank(&a, rows));
+        // row 3 = 2 * row 0 - row 1
+        for j in 0..cols {
+            a[3 * cols + j] = Fp::new(2) * a[j] - a[cols + j];
+        }
+        assert_eq!(rows - 1, rank(&a, cols));
+        assert_eq!(0, rank(&[Fp::new(0); 12], 3));
+    }
+
+    #[test]
+    fn solve_singular() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let n = 8;
+        let mut a = (0..n * n).map(|_| rng.gen()).collect::<Vec<Fp>>();
+        // row 5 = row 1 + row 2
+        for j in 0..n {
+            a[5 * n + j] = a[n + j] + a[2 * n + j];
+        }
+        let b = (0..n).map(|_| rng.gen()).collect::<Vec<Fp>>();
+        assert_eq!(None, solve(&a, &b));
+    }
+}
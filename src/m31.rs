@@ -0,0 +1,47 @@
+//! Mersenne31 (`p = 2^31 - 1`) over f64 with the FMA reduction, to compare
+//! the float approach head-to-head with `p3_mersenne_31::Mersenne31` in the
+//! benches.
+//!
+//! Products of two elements reach `2^62` so unlike fp20 they aren't exact in
+//! an f64 and the FMA low part is carried into the reduction, which is what
+//! [`FpF64`] does for any modulus below [`crate::fp_f64::MAX_MODULUS`].
+use crate::fp_f64::FpF64;
+
+pub const MODULUS: u32 = (1 << 31) - 1;
+
+pub type Fp = FpF64<{ MODULUS as u64 }>;
+
+/// Generator of the multiplicative group (the one Plonky3 uses)
+pub const GENERATOR: Fp = Fp::new(7);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::Field;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn matches_u64_reference() {
+        let p = MODULUS as u64;
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..1 << 16 {
+            let (a, b) = (rng.gen_range(0..p), rng.gen_range(0..p));
+            let (x, y) = (Fp::new(a), Fp::new(b));
+            assert_eq!(a * b % p, u64::from(x * y), "mismatch: `{a} * {b}`");
+            assert_eq!((a + b) % p, u64::from(x + y), "mismatch: `{a} + {b}`");
+            assert_eq!((a + p - b) % p, u64::from(x - y), "mismatch: `{a} - {b}`");
+        }
+    }
+
+    #[test]
+    fn mersenne_identities() {
+        // 2^31 = 1
+        assert_eq!(Fp::from(1), Fp::from(2).pow(31));
+        assert_eq!(Fp::from(0), Fp::from(MODULUS));
+        let minus_one = Fp::new(MODULUS as u64 - 1);
+        assert_eq!(Fp::from(1), minus_one * minus_one);
+        assert_eq!(MODULUS as u64 - 1, GENERATOR.multiplicative_order());
+    }
+}
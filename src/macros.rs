@@ -0,0 +1,413 @@
+/// Builds a `[$field; N]` table from integer literals at compile time.
+/// `$field` must implement [`crate::field::Field`] and have a
+/// `const fn new(u32) -> Self`. Each literal is checked against
+/// [`Field::ORDER`](crate::field::Field::ORDER) in a `const` block, so an out
+/// of range value fails to compile in every build:
+///
+/// ```
+/// use ark_experimentation::field_consts;
+/// use ark_experimentation::fp20::single_precision::Fp;
+///
+/// const ROUND_CONSTANTS: [Fp; 3] = field_consts!(Fp; 7, 11, 2097142);
+/// ```
+///
+/// ```compile_fail
+/// use ark_experimentation::field_consts;
+/// use ark_experimentation::fp20::single_precision::Fp;
+///
+/// const ROUND_CONSTANTS: [Fp; 1] = field_consts!(Fp; 2097143);
+/// ```
+#[macro_export]
+macro_rules! field_consts {
+    ($field:ty; $($value:expr),* $(,)?) => {
+        [$({
+            const {
+                assert!(
+                    ($value as u64) < <$field as $crate::field::Field>::ORDER,
+                    "field constant out of range"
+                )
+            };
+            <$field>::new($value)
+        }),*]
+    };
+}
+
+/// Implements compound assignment operators in terms of the binary ones:
+/// `impl_assign_ops!(Fp; AddAssign::add_assign => +, MulAssign::mul_assign =>
+/// *)`. Generic types list their parameters first: `impl_assign_ops!([const
+/// M: u32] FpF32<M>; ...)`, and the other `impl_*` macros in this module take
+/// them the same way.
+macro_rules! impl_assign_ops {
+    (@impl [$($generics:tt)*] $t:ty; $trait:ident::$method:ident => $op:tt) => {
+        impl<$($generics)*> std::ops::$trait for $t {
+            #[inline]
+            fn $method(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+    ($generics:tt $t:ty; $($trait:ident::$method:ident => $op:tt),* $(,)?) => {
+        $(impl_assign_ops!(@impl $generics $t; $trait::$method => $op);)*
+    };
+    ($t:ty; $($trait:ident::$method:ident => $op:tt),* $(,)?) => {
+        impl_assign_ops!([] $t; $($trait::$method => $op),*);
+    };
+}
+
+pub(crate) use impl_assign_ops;
+
+/// Implements `Sum` and `Product` over values and references in terms of
+/// `Add`, `Mul` and `From<u32>`.
+macro_rules! impl_sum_product {
+    ([$($generics:tt)*] $t:ty) => {
+        impl<$($generics)*> std::iter::Sum for $t {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::from(0), |acc, v| acc + v)
+            }
+        }
+
+        impl<'a, $($generics)*> std::iter::Sum<&'a $t> for $t {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        impl<$($generics)*> std::iter::Product for $t {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::from(1), |acc, v| acc * v)
+            }
+        }
+
+        impl<'a, $($generics)*> std::iter::Product<&'a $t> for $t {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+    };
+    ($t:ty) => {
+        impl_sum_product!([] $t);
+    };
+}
+
+pub(crate) use impl_sum_product;
+
+/// Implements `num_traits::{Zero, One, Pow<u64>, Inv}` in terms of
+/// [`crate::field::Field`]. `Inv` panics on zero like `Div`.
+macro_rules! impl_num_traits {
+    ([$($generics:tt)*] $t:ty) => {
+        impl<$($generics)*> num_traits::Zero for $t {
+            #[inline]
+            fn zero() -> Self {
+                <Self as $crate::field::Field>::ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == <Self as $crate::field::Field>::ZERO
+            }
+        }
+
+        impl<$($generics)*> num_traits::One for $t {
+            #[inline]
+            fn one() -> Self {
+                <Self as $crate::field::Field>::ONE
+            }
+        }
+
+        impl<$($generics)*> num_traits::Pow<u64> for $t {
+            type Output = Self;
+
+            #[inline]
+            fn pow(self, exp: u64) -> Self {
+                $crate::field::Field::pow(self, exp)
+            }
+        }
+
+        impl<$($generics)*> num_traits::Inv for $t {
+            type Output = Self;
+
+            fn inv(self) -> Self {
+                $crate::field::Field::inverse(self).expect("inverse of zero")
+            }
+        }
+    };
+    ($t:ty) => {
+        impl_num_traits!([] $t);
+    };
+}
+
+pub(crate) use impl_num_traits;
+
+/// Implements `Eq` and `Hash` over the canonical value rather than the float
+/// bits, so elements can key hash maps. `Eq` holds as elements are canonical
+/// (never NaN).
+macro_rules! impl_hash {
+    ([$($generics:tt)*] $t:ty) => {
+        impl<$($generics)*> Eq for $t {}
+
+        impl<$($generics)*> std::hash::Hash for $t {
+            #[inline]
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                $crate::field::Field::to_canonical_u64(*self).hash(state);
+            }
+        }
+    };
+    ($t:ty) => {
+        impl_hash!([] $t);
+    };
+}
+
+pub(crate) use impl_hash;
+
+/// Implements serde's `Serialize` and `Deserialize` (with the `serde` feature)
+/// over the canonical value as a `u64`, so documents don't depend on the
+/// backend. Non-canonical values are rejected. `$new` builds an element from
+/// a canonical `u64` and defaults to
+/// [`crate::field::Field::from_canonical_u32`].
+macro_rules! impl_serde {
+    ([$($generics:tt)*] $t:ty => $new:expr) => {
+        #[cfg(feature = "serde")]
+        impl<$($generics)*> serde::Serialize for $t {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u64($crate::field::Field::to_canonical_u64(*self))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $($generics)*> serde::Deserialize<'de> for $t {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let v = <u64 as serde::Deserialize>::deserialize(deserializer)?;
+                if v >= <Self as $crate::field::Field>::order() {
+                    return Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Unsigned(v),
+                        &"a canonical field element",
+                    ));
+                }
+                Ok($new(v))
+            }
+        }
+    };
+    ([$($generics:tt)*] $t:ty) => {
+        impl_serde!(
+            [$($generics)*] $t => |v| <Self as $crate::field::Field>::from_canonical_u32(v as u32)
+        );
+    };
+    ($t:ty $(=> $new:expr)?) => {
+        impl_serde!([] $t $(=> $new)?);
+    };
+}
+
+pub(crate) use impl_serde;
+
+/// Implements `to_le_bytes` and `from_le_bytes` over the canonical value as a
+/// little-endian `$repr`, so every backend of a field has the same encoding.
+/// `$new` builds an element from a canonical `u64` like in [`impl_serde`].
+macro_rules! impl_le_bytes {
+    ([$($generics:tt)*] $t:ty, $repr:ty => $new:expr) => {
+        impl<$($generics)*> $t {
+            /// Little-endian bytes of the canonical value
+            #[inline]
+            pub fn to_le_bytes(self) -> [u8; std::mem::size_of::<$repr>()] {
+                let v = $crate::field::Field::to_canonical_u64(self);
+                (v as $repr).to_le_bytes()
+            }
+
+            /// Element from the little-endian bytes of its canonical value.
+            /// Returns `None` for non-canonical values.
+            #[inline]
+            pub fn from_le_bytes(bytes: [u8; std::mem::size_of::<$repr>()]) -> Option<Self> {
+                let v = u64::from(<$repr>::from_le_bytes(bytes));
+                (v < <Self as $crate::field::Field>::order()).then(|| $new(v))
+            }
+        }
+    };
+    ([$($generics:tt)*] $t:ty, $repr:ty) => {
+        impl_le_bytes!(
+            [$($generics)*] $t, $repr => |v| <Self as $crate::field::Field>::from_canonical_u32(v as u32)
+        );
+    };
+    ($t:ty, $repr:ty $(=> $new:expr)?) => {
+        impl_le_bytes!([] $t, $repr $(=> $new)?);
+    };
+}
+
+pub(crate) use impl_le_bytes;
+
+/// Implements bytemuck's `Pod` and `Zeroable` (with the `bytemuck` feature) for
+/// a `#[repr(transparent)]` wrapper of floats (or of arrays of such wrappers,
+/// like the simulated integers' limbs), so slices of elements can be
+/// reinterpreted as slices of the floats without copies. All zero bits are the
+/// zero element. Casting the other way is only meaningful for canonical values.
+macro_rules! impl_pod {
+    ([$($generics:tt)*] $t:ty) => {
+        // SAFETY: `#[repr(transparent)]` over a `Pod` float, wrapper or array
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<$($generics)*> bytemuck::Zeroable for $t {}
+
+        // SAFETY: as above
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<$($generics)*> bytemuck::Pod for $t {}
+    };
+    ($t:ty) => {
+        impl_pod!([] $t);
+    };
+}
+
+pub(crate) use impl_pod;
+
+/// Implements [`crate::slice::Word`] for a `#[repr(transparent)]` wrapper of
+/// another `Word`, e.g. `impl_word!(Fp, f32)`, so [`crate::slice::select`] can
+/// blend its bits.
+macro_rules! impl_word {
+    ([$($generics:tt)*] $t:ty, $inner:ty) => {
+        impl<$($generics)*> $crate::slice::Word for $t {
+            type Bits = <$inner as $crate::slice::Word>::Bits;
+
+            #[inline]
+            fn to_bits(self) -> Self::Bits {
+                $crate::slice::Word::to_bits(self.0)
+            }
+
+            #[inline]
+            fn from_bits(bits: Self::Bits) -> Self {
+                Self($crate::slice::Word::from_bits(bits))
+            }
+        }
+    };
+    ($t:ty, $inner:ty) => {
+        impl_word!([] $t, $inner);
+    };
+}
+
+pub(crate) use impl_word;
+
+#[cfg(test)]
+mod tests {
+    use crate::arithmetic::half_precision;
+    use crate::arithmetic::single_precision;
+    use crate::fp20;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    const VALUES: [u32; 4] = [0, 1, 1 << 20, 2097142];
+
+    #[test]
+    fn field_consts_match_runtime_conversion() {
+        const INTEGER: [fp20::integer::Fp; 4] =
+            field_consts!(fp20::integer::Fp; 0, 1, 1 << 20, 2097142);
+        const SINGLE: [fp20::single_precision::Fp; 4] =
+            field_consts!(fp20::single_precision::Fp; 0, 1, 1 << 20, 2097142);
+        const DOUBLE: [fp20::double_precision::Fp; 4] =
+            field_consts!(fp20::double_precision::Fp; 0, 1, 1 << 20, 2097142,);
+
+        assert_eq!(VALUES.map(fp20::integer::Fp::from), INTEGER);
+        assert_eq!(VALUES.map(fp20::single_precision::Fp::from), SINGLE);
+        assert_eq!(VALUES.map(fp20::double_precision::Fp::from), DOUBLE);
+    }
+
+    #[test]
+    fn field_consts_empty() {
+        const EMPTY: [fp20::integer::Fp; 0] = field_consts!(fp20::integer::Fp;);
+        assert!(EMPTY.is_empty());
+    }
+
+    #[test]
+    fn assign_ops_match_binary_ops() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for _ in 0..1024 {
+            let a: fp20::integer::Fp = rng.gen();
+            let b: fp20::integer::Fp = rng.gen();
+            let mut single = fp20::single_precision::Fp::from(a);
+            single += b.into();
+            assert_eq!(a + b, single.into());
+            single -= b.into();
+            assert_eq!(a, single.into());
+            single *= b.into();
+            assert_eq!(a * b, single.into());
+
+            let mut x = fp20::extension::Fp2 { c0: a, c1: b };
+            let y = fp20::extension::Fp2 { c0: b, c1: a };
+            x *= y;
+            x -= y;
+            x += y;
+            assert_eq!(fp20::extension::Fp2 { c0: a, c1: b } * y, x);
+
+            let (a, b) = (rng.gen::<u16>(), rng.gen::<u16>());
+            let mut x = single_precision::U16::new(a);
+            x += single_precision::U16::new(b);
+            x *= single_precision::U16::new(b);
+            assert_eq!(
+                single_precision::U16::new(a.wrapping_add(b).wrapping_mul(b)),
+                x
+            );
+
+            let (a, b) = (rng.gen::<u8>(), rng.gen::<u8>());
+            let mut x = half_precision::U8::new(a);
+            x -= half_precision::U8::new(b);
+            assert_eq!(half_precision::U8::new(a.wrapping_sub(b)), x);
+        }
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let values = (0..1000)
+            .map(|_| rng.gen())
+            .collect::<Vec<fp20::integer::Fp>>();
+        let p = fp20::integer::MODULUS as u64;
+        let (sum, product) = values.iter().fold((0, 1), |(sum, product), &v| {
+            let v = u32::from(v) as u64;
+            ((sum + v) % p, product * v % p)
+        });
+        let (sum, product) = (
+            fp20::integer::Fp::new(sum as u32),
+            fp20::integer::Fp::new(product as u32),
+        );
+        assert_eq!(sum, values.iter().sum());
+        assert_eq!(product, values.iter().copied().product());
+
+        let single = values
+            .iter()
+            .map(|&v| fp20::single_precision::Fp::from(v))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sum,
+            single
+                .iter()
+                .copied()
+                .sum::<fp20::single_precision::Fp>()
+                .into()
+        );
+        assert_eq!(
+            product,
+            single.iter().product::<fp20::single_precision::Fp>().into()
+        );
+
+        let double = values
+            .iter()
+            .map(|&v| fp20::double_precision::Fp::from(v))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sum,
+            double.iter().sum::<fp20::double_precision::Fp>().into()
+        );
+        assert_eq!(
+            product,
+            double
+                .iter()
+                .copied()
+                .product::<fp20::double_precision::Fp>()
+                .into()
+        );
+
+        let ext = values
+            .iter()
+            .map(|&v| fp20::extension::Fp2::from(v))
+            .collect::<Vec<_>>();
+        assert_eq!(fp20::extension::Fp2::from(sum), ext.iter().sum());
+        assert_eq!(fp20::extension::Fp2::from(product), ext.iter().product());
+        assert_eq!(fp20::integer::Fp::new(0), [].iter().sum());
+        assert_eq!(fp20::integer::Fp::new(1), [].iter().product());
+    }
+}
@@ -0,0 +1,104 @@
+//! Co-scheduling two fields in one loop: an fp20 column over f64 and a 65537
+//! column over f32. The reductions of both are latency bound chains of FMAs;
+//! interleaving independent chains lets the out-of-order core fill each
+//! other's stalls. Whether that beats two separate passes is the experiment,
+//! see the `mixed_pipeline` benches.
+//!
+//! Each iteration handles four elements of each column, i.e. two f64x2 and
+//! one f32x4 register's worth, so the compiler can vectorise both halves.
+use crate::f65537::mul_mod_fma;
+use crate::fp20::double_precision::Fp;
+
+/// Elements of each column per iteration
+pub const LANES: usize = 4;
+
+/// Computes the Hadamard products `fp20[i] *= fp20_rhs[i]` and
+/// `f65537[i] *= f65537_rhs[i] mod 65537` in the same loop. All four
+/// columns must have the same length.
+pub fn hadamard(fp20: &mut [Fp], fp20_rhs: &[Fp], f65537: &mut [f32], f65537_rhs: &[f32]) {
+    let n = fp20.len();
+    assert_eq!(n, fp20_rhs.len());
+    assert_eq!(n, f65537.len());
+    assert_eq!(n, f65537_rhs.len());
+
+    let mut fp20_chunks = fp20.chunks_exact_mut(LANES);
+    let mut f65537_chunks = f65537.chunks_exact_mut(LANES);
+    let chunks = (&mut fp20_chunks)
+        .zip(fp20_rhs.chunks_exact(LANES))
+        .zip((&mut f65537_chunks).zip(f65537_rhs.chunks_exact(LANES)));
+    for ((a, b), (x, y)) in chunks {
+        let a: &mut [Fp; LANES] = a.try_into().unwrap();
+        let b: &[Fp; LANES] = b.try_into().unwrap();
+        let x: &mut [f32; LANES] = x.try_into().unwrap();
+        let y: &[f32; LANES] = y.try_into().unwrap();
+        for i in 0..LANES {
+            a[i] *= b[i];
+            x[i] = mul_mod_fma(x[i], y[i]);
+        }
+    }
+
+    let tail = n - n % LANES;
+    for (a, &b) in fp20_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(&fp20_rhs[tail..])
+    {
+        *a *= b;
+    }
+    for (x, &y) in f65537_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(&f65537_rhs[tail..])
+    {
+        *x = mul_mod_fma(*x, y);
+    }
+}
+
+/// [`hadamard`] as two separate passes, the baseline for the benches
+pub fn hadamard_separate(fp20: &mut [Fp], fp20_rhs: &[Fp], f65537: &mut [f32], f65537_rhs: &[f32]) {
+    crate::slice::hadamard(fp20, fp20_rhs);
+    assert_eq!(f65537.len(), f65537_rhs.len());
+    for (x, &y) in f65537.iter_mut().zip(f65537_rhs) {
+        *x = mul_mod_fma(*x, y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn matches_separate_passes() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for n in [0, 1, 3, 4, 5, 1023] {
+            let fp20 = (0..n).map(|_| rng.gen()).collect::<Vec<Fp>>();
+            let fp20_rhs = (0..n).map(|_| rng.gen()).collect::<Vec<Fp>>();
+            let f65537 = (0..n)
+                .map(|_| rng.gen_range(0..65537) as f32)
+                .collect::<Vec<f32>>();
+            let f65537_rhs = (0..n)
+                .map(|_| rng.gen_range(0..65537) as f32)
+                .collect::<Vec<f32>>();
+
+            let (mut expected_fp20, mut expected_f65537) = (fp20.clone(), f65537.clone());
+            hadamard_separate(
+                &mut expected_fp20,
+                &fp20_rhs,
+                &mut expected_f65537,
+                &f65537_rhs,
+            );
+            let (mut actual_fp20, mut actual_f65537) = (fp20.clone(), f65537.clone());
+            hadamard(&mut actual_fp20, &fp20_rhs, &mut actual_f65537, &f65537_rhs);
+            assert_eq!(expected_fp20, actual_fp20, "n = {n}");
+            assert_eq!(expected_f65537, actual_f65537, "n = {n}");
+
+            for (i, (&x, &y)) in f65537.iter().zip(&f65537_rhs).enumerate() {
+                let expected = x as u64 * y as u64 % 65537;
+                assert_eq!(expected, actual_f65537[i] as u64, "`{x} * {y}`");
+            }
+        }
+    }
+}
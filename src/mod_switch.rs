@@ -0,0 +1,276 @@
+//! Conversion of residue vectors between the crate's moduli.
+//!
+//! Lifting is exact: the canonical representative mod `q1` is reinterpreted
+//! mod `q2` (either the non-negative one or the centered one). Switching is
+//! the lattice style rescaling `x -> round(x * q2 / q1) mod q2` which
+//! introduces an error of at most `1/2` before the final reduction. All
+//! computations are done on integers so the result doesn't depend on the
+//! backend the residues came from.
+//!
+//! Compression is switching to a power of two modulus `2^d` as used for
+//! (R)LWE ciphertexts. It is lossy: decompressing returns a value within
+//! `round(q / 2^(d + 1))` of the original (cyclically mod `q`).
+use crate::f65537::MODULUS as MODULUS_65537;
+use crate::fp20;
+
+/// Modulus of the fp20 fields
+const MODULUS_FP20: u32 = fp20::integer::MODULUS;
+
+/// Reinterprets canonical residues mod `q1` as residues mod `q2`.
+/// Exact when `q1 <= q2`.
+pub fn lift(values: &[u32], q1: u32, q2: u32) -> Vec<u32> {
+    assert!(q1 <= q2, "lifting requires q1 <= q2");
+    debug_assert!(values.iter().all(|&x| x < q1));
+    values.to_vec()
+}
+
+/// Reinterprets canonical residues mod `q1` as residues mod `q2` using the
+/// centered representative `(-q1/2, q1/2]` i.e. negative values map to
+/// `q2 - |x|`. Exact when `q1 <= q2`.
+pub fn lift_centered(values: &[u32], q1: u32, q2: u32) -> Vec<u32> {
+    assert!(q1 <= q2, "lifting requires q1 <= q2");
+    values
+        .iter()
+        .map(|&x| {
+            debug_assert!(x < q1);
+            if x > q1 / 2 {
+                q2 - (q1 - x)
+            } else {
+                x
+            }
+        })
+        .collect()
+}
+
+/// Computes `round(x * q2 / q1) mod q2` for each canonical residue `x` mod
+/// `q1` with ties rounded up. Any `u32` moduli are accepted, the product is
+/// formed in `u128` so `x * q2` can't overflow.
+pub fn switch(values: &[u32], q1: u32, q2: u32) -> Vec<u32> {
+    let (q1, q2) = (q1 as u128, q2 as u128);
+    values
+        .iter()
+        .map(|&x| {
+            debug_assert!((x as u128) < q1);
+            let v = (2 * x as u128 * q2 + q1) / (2 * q1);
+            (v % q2) as u32
+        })
+        .collect()
+}
+
+/// Lifts canonical residues mod 65537 (as returned by the f32 reductions)
+/// into the fp20 field. Exact.
+pub fn lift_65537_to_fp20(values: &[f32]) -> Vec<fp20::integer::Fp> {
+    let values = values.iter().map(|&v| v as u32).collect::<Vec<u32>>();
+    lift(&values, MODULUS_65537, MODULUS_FP20)
+        .into_iter()
+        .map(fp20::integer::Fp::new)
+        .collect()
+}
+
+/// Rescales residues mod 65537 to the fp20 field with [`switch`]
+pub fn switch_65537_to_fp20(values: &[f32]) -> Vec<fp20::integer::Fp> {
+    let values = values.iter().map(|&v| v as u32).collect::<Vec<u32>>();
+    switch(&values, MODULUS_65537, MODULUS_FP20)
+        .into_iter()
+        .map(fp20::integer::Fp::new)
+        .collect()
+}
+
+/// Rescales fp20 elements to residues mod 65537 with [`switch`]
+pub fn switch_fp20_to_65537(values: &[fp20::integer::Fp]) -> Vec<f32> {
+    let values = values.iter().map(|&v| u32::from(v)).collect::<Vec<u32>>();
+    switch(&values, MODULUS_FP20, MODULUS_65537)
+        .into_iter()
+        .map(|v| v as f32)
+        .collect()
+}
+
+/// Compresses field elements mod `q` to `d <= 16` bits with [`switch`]
+pub fn compress_to_u16<F: Copy + Into<u32>>(values: &[F], q: u32, d: u32) -> Vec<u16> {
+    assert!(d <= u16::BITS, "compression to more than 16 bits");
+    compress(values, q, d).map(|v| v as u16).collect()
+}
+
+/// Compresses field elements mod `q` to `d <= 8` bits with [`switch`]
+pub fn compress_to_u8<F: Copy + Into<u32>>(values: &[F], q: u32, d: u32) -> Vec<u8> {
+    assert!(d <= u8::BITS, "compression to more than 8 bits");
+    compress(values, q, d).map(|v| v as u8).collect()
+}
+
+fn compress<F: Copy + Into<u32>>(values: &[F], q: u32, d: u32) -> impl Iterator<Item = u32> {
+    let values = values.iter().map(|&v| v.into()).collect::<Vec<u32>>();
+    switch(&values, q, 1 << d).into_iter()
+}
+
+/// Maps `d` bit values produced by [`compress_to_u16`] or [`compress_to_u8`]
+/// back to field elements mod `q`
+pub fn decompress<T: Copy + Into<u32>, F: From<u32>>(values: &[T], q: u32, d: u32) -> Vec<F> {
+    assert!(d <= u16::BITS, "decompression from more than 16 bits");
+    let values = values
+        .iter()
+        .map(|&v| {
+            let v = v.into();
+            debug_assert!(v < 1 << d);
+            v
+        })
+        .collect::<Vec<u32>>();
+    switch(&values, 1 << d, q)
+        .into_iter()
+        .map(F::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    const Q1: u32 = MODULUS_65537;
+    const Q2: u32 = MODULUS_FP20;
+
+    #[test]
+    fn lift_is_exact() {
+        let values = (0..Q1).collect::<Vec<u32>>();
+        assert_eq!(values, lift(&values, Q1, Q2));
+
+        let lifted = lift_65537_to_fp20(&values.iter().map(|&v| v as f32).collect::<Vec<f32>>());
+        let lifted = lifted.into_iter().map(u32::from).collect::<Vec<u32>>();
+        assert_eq!(values, lifted);
+    }
+
+    #[test]
+    fn lift_centered_preserves_signed_value() {
+        let values = (0..Q1).collect::<Vec<u32>>();
+        for (&x, y) in values.iter().zip(lift_centered(&values, Q1, Q2)) {
+            let signed = if x > Q1 / 2 {
+                x as i64 - Q1 as i64
+            } else {
+                x as i64
+            };
+            assert_eq!(signed.rem_euclid(Q2 as i64) as u32, y, "mismatch: `{x}`");
+        }
+    }
+
+    #[test]
+    fn switch_same_modulus_is_identity() {
+        let values = (0..Q1).collect::<Vec<u32>>();
+        assert_eq!(values, switch(&values, Q1, Q1));
+    }
+
+    #[test]
+    fn switch_rounding_error() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let values = (0..1 << 16)
+            .map(|_| rng.gen::<u32>() % Q2)
+            .chain([0, 1, Q2 / 2, Q2 - 1])
+            .collect::<Vec<u32>>();
+        let switched = switch(&values, Q2, Q1);
+        for (&x, &y) in values.iter().zip(&switched) {
+            // |y * q2 - x * q1| <= q2 / 2 (modulo wraparound of the top value)
+            let diff = (y as i64 * Q2 as i64 - x as i64 * Q1 as i64).abs();
+            let wrapped = (diff - Q1 as i64 * Q2 as i64).abs();
+            assert!(diff.min(wrapped) * 2 <= Q2 as i64, "mismatch: `{x}`");
+        }
+    }
+
+    #[test]
+    fn switch_between_large_moduli() {
+        const Q3: u32 = u32::MAX;
+        const Q4: u32 = u32::MAX - 4;
+        let mut rng = StdRng::from_seed([1; 32]);
+        let values = (0..1 << 12)
+            .map(|_| rng.gen::<u32>() % Q4)
+            .chain([0, 1, Q4 / 2, Q4 - 1])
+            .collect::<Vec<u32>>();
+        assert_eq!(values, switch(&values, Q4, Q4));
+        for (q1, q2) in [(Q4, Q3), (Q3, Q4)] {
+            for (&x, &y) in values.iter().zip(&switch(&values, q1, q2)) {
+                // |y * q1 - x * q2| <= q1 / 2 (modulo wraparound of the top value)
+                let diff = (y as i128 * q1 as i128 - x as i128 * q2 as i128).abs();
+                let wrapped = (diff - q1 as i128 * q2 as i128).abs();
+                assert!(diff.min(wrapped) * 2 <= q1 as i128, "mismatch: `{x}`");
+            }
+        }
+    }
+
+    #[test]
+    fn switch_down_and_up_round_trip() {
+        let values = (0..Q1).collect::<Vec<u32>>();
+        let up = switch(&values, Q1, Q2);
+        assert_eq!(values, switch(&up, Q2, Q1));
+
+        let floats = values.iter().map(|&v| v as f32).collect::<Vec<f32>>();
+        assert_eq!(floats, switch_fp20_to_65537(&switch_65537_to_fp20(&floats)));
+    }
+
+    fn assert_compression_error<F: Copy + Into<u32> + From<u32>>(values: &[F], d: u32) {
+        let compressed = compress_to_u16(values, Q2, d);
+        let decompressed: Vec<F> = decompress(&compressed, Q2, d);
+        let bound = (Q2 as i64 + (1 << d)) >> (d + 1);
+        for (&x, y) in values.iter().zip(decompressed) {
+            let (x, y) = (x.into() as i64, y.into() as i64);
+            let diff = (x - y).rem_euclid(Q2 as i64);
+            assert!(
+                diff.min(Q2 as i64 - diff) <= bound,
+                "mismatch: `{x}` (d = {d})"
+            );
+        }
+    }
+
+    #[test]
+    fn compression_error_is_bounded() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let values = (0..1 << 12)
+            .map(|_| rng.gen::<u32>() % Q2)
+            .chain([0, 1, Q2 / 2, Q2 - 1])
+            .collect::<Vec<u32>>();
+        for d in [1, 4, 8, 11, 16] {
+            let integer = values
+                .iter()
+                .map(|&v| fp20::integer::Fp::new(v))
+                .collect::<Vec<_>>();
+            let single = values
+                .iter()
+                .map(|&v| fp20::single_precision::Fp::new(v))
+                .collect::<Vec<_>>();
+            let double = values
+                .iter()
+                .map(|&v| fp20::double_precision::Fp::new(v))
+                .collect::<Vec<_>>();
+            assert_compression_error(&integer, d);
+            assert_compression_error(&single, d);
+            assert_compression_error(&double, d);
+
+            let expected = compress_to_u16(&integer, Q2, d);
+            assert_eq!(expected, compress_to_u16(&single, Q2, d));
+            assert_eq!(expected, compress_to_u16(&double, Q2, d));
+        }
+    }
+
+    #[test]
+    fn compression_wraps_top_values_to_zero() {
+        let values = [fp20::integer::Fp::new(Q2 - 1)];
+        assert_eq!(vec![0], compress_to_u8(&values, Q2, 1));
+        assert_eq!(vec![0], compress_to_u16(&values, Q2, 16));
+    }
+
+    #[test]
+    #[should_panic(expected = "decompression from more than 16 bits")]
+    fn decompress_rejects_wide_values() {
+        let _: Vec<fp20::integer::Fp> = decompress(&[0u32], Q2, 32);
+    }
+
+    #[test]
+    fn decompress_then_compress_is_identity() {
+        for d in [1, 8] {
+            let values = (0..1u16 << d).collect::<Vec<u16>>();
+            let decompressed: Vec<fp20::single_precision::Fp> = decompress(&values, Q2, d);
+            assert_eq!(values, compress_to_u16(&decompressed, Q2, d));
+        }
+        let values = (0..=u8::MAX).collect::<Vec<u8>>();
+        let decompressed: Vec<fp20::double_precision::Fp> = decompress(&values, Q2, 8);
+        assert_eq!(values, compress_to_u8(&decompressed, Q2, 8));
+    }
+}
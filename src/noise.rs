@@ -0,0 +1,153 @@
+//! Small noise samplers for the lattice experiments.
+//!
+//! Samples are signed integers and are mapped to field elements mod `q` by
+//! taking `x mod q`, i.e. negative values land just below `q`. This exercises
+//! the top and bottom of the residue range together, unlike uniform sampling.
+use rand::prelude::Distribution;
+use rand::Rng;
+
+/// Centered binomial distribution `sum(a_i - b_i)` over `eta` pairs of coin
+/// flips. Support is `[-eta, eta]` and variance `eta / 2`.
+#[derive(Clone, Copy, Debug)]
+pub struct CenteredBinomial {
+    eta: u32,
+}
+
+impl CenteredBinomial {
+    pub fn new(eta: u32) -> Self {
+        assert!(eta <= 32, "eta must fit in a single u32 of coin flips");
+        Self { eta }
+    }
+}
+
+impl Distribution<i32> for CenteredBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> i32 {
+        let mask = (1u64 << self.eta) - 1;
+        let a = rng.gen::<u32>() as u64 & mask;
+        let b = rng.gen::<u32>() as u64 & mask;
+        a.count_ones() as i32 - b.count_ones() as i32
+    }
+}
+
+/// Discrete Gaussian with standard deviation `sigma` truncated to
+/// `[-tail * sigma, tail * sigma]`. Uses rejection sampling so it's only
+/// suitable for small `sigma` and is not constant time.
+#[derive(Clone, Copy, Debug)]
+pub struct DiscreteGaussian {
+    sigma: f64,
+    bound: i32,
+}
+
+impl DiscreteGaussian {
+    pub fn new(sigma: f64, tail: f64) -> Self {
+        assert!(sigma > 0.0 && tail > 0.0);
+        let bound = (sigma * tail).ceil() as i32;
+        Self { sigma, bound }
+    }
+}
+
+impl Distribution<i32> for DiscreteGaussian {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> i32 {
+        let denominator = -2.0 * self.sigma * self.sigma;
+        loop {
+            let x = rng.gen_range(-self.bound..=self.bound);
+            let p = (x as f64 * x as f64 / denominator).exp();
+            if rng.gen::<f64>() < p {
+                return x;
+            }
+        }
+    }
+}
+
+/// Maps a signed sample to its residue mod `q`
+#[inline]
+pub fn to_residue(x: i32, q: u32) -> u32 {
+    (x as i64).rem_euclid(q as i64) as u32
+}
+
+/// Draws `n` samples from `distribution` as field elements mod `q`
+pub fn sample_vec<F: From<u32>, R: Rng + ?Sized>(
+    rng: &mut R,
+    distribution: &impl Distribution<i32>,
+    q: u32,
+    n: usize,
+) -> Vec<F> {
+    (0..n)
+        .map(|_| F::from(to_residue(distribution.sample(rng), q)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp20;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const Q: u32 = fp20::integer::MODULUS;
+    const N: usize = 1 << 16;
+
+    fn mean_and_variance(samples: &[i32]) -> (f64, f64) {
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(|&x| x as f64).sum::<f64>() / n;
+        let variance = samples
+            .iter()
+            .map(|&x| (x as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        (mean, variance)
+    }
+
+    #[test]
+    fn centered_binomial_moments() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for eta in [1, 2, 3, 16] {
+            let distribution = CenteredBinomial::new(eta);
+            let samples = (0..N)
+                .map(|_| distribution.sample(&mut rng))
+                .collect::<Vec<i32>>();
+            assert!(samples.iter().all(|x| x.unsigned_abs() <= eta));
+            let (mean, variance) = mean_and_variance(&samples);
+            let expected_variance = eta as f64 / 2.0;
+            assert!(mean.abs() < 0.05, "eta = {eta}, mean = {mean}");
+            assert!(
+                (variance - expected_variance).abs() < 0.05 * expected_variance,
+                "eta = {eta}, variance = {variance}"
+            );
+        }
+    }
+
+    #[test]
+    fn discrete_gaussian_moments() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        for sigma in [1.0, 3.2, 8.0] {
+            let distribution = DiscreteGaussian::new(sigma, 6.0);
+            let samples = (0..N)
+                .map(|_| distribution.sample(&mut rng))
+                .collect::<Vec<i32>>();
+            assert!(samples.iter().all(|&x| x.abs() <= distribution.bound));
+            let (mean, variance) = mean_and_variance(&samples);
+            assert!(mean.abs() < 0.1, "sigma = {sigma}, mean = {mean}");
+            assert!(
+                (variance.sqrt() - sigma).abs() < 0.05 * sigma,
+                "sigma = {sigma}, variance = {variance}"
+            );
+        }
+    }
+
+    #[test]
+    fn field_samples_are_small_residues() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let distribution = CenteredBinomial::new(2);
+        let integer: Vec<fp20::integer::Fp> = sample_vec(&mut rng, &distribution, Q, N);
+        let mut rng = StdRng::from_seed([1; 32]);
+        let single: Vec<fp20::single_precision::Fp> = sample_vec(&mut rng, &distribution, Q, N);
+        for (a, b) in integer.into_iter().zip(single) {
+            let a = u32::from(a);
+            assert_eq!(a, u32::from(b));
+            assert!(a <= 2 || a >= Q - 2, "mismatch: `{a}`");
+        }
+        assert_eq!(Q - 1, to_residue(-1, Q));
+        assert_eq!(0, to_residue(0, Q));
+    }
+}
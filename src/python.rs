@@ -0,0 +1,255 @@
+//! Python extension module over the fp20 reference backend, so analysis
+//! notebooks check against the Rust implementation instead of a port of it.
+//!
+//! ```python
+//! from ark_experimentation import Fp20Vector, intt, ntt
+//!
+//! a = Fp20Vector([1, 2, 3])
+//! assert (a * a + a).to_list() == [2, 6, 12]
+//! evals = ntt([(1, 0), (2, 0)])
+//! assert intt(evals) == [(1, 0), (2, 0)]
+//! ```
+//!
+//! Values cross the boundary as canonical residues (`int`) and [`Fp2`]
+//! elements as `(c0, c1)` tuples. Integers outside `[0, MODULUS)`, including
+//! negative ones and ones too large for `u32`, raise `ValueError`; values
+//! that aren't integers raise `TypeError`.
+use crate::fp20::extension;
+use crate::fp20::extension::Fp2;
+use crate::fp20::integer::Fp;
+use crate::fp20::integer::MODULUS;
+use pyo3::exceptions::PyOverflowError;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+fn fp(value: u32) -> PyResult<Fp> {
+    if value < MODULUS {
+        Ok(Fp::new(value))
+    } else {
+        Err(non_canonical(value))
+    }
+}
+
+fn non_canonical(value: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(format!("{value} is not a canonical element of F_{MODULUS}"))
+}
+
+/// Canonical residue taken from a Python `int`. pyo3 raises `OverflowError`
+/// for negative and oversized ints, those become `ValueError` like any
+/// other non-canonical value.
+struct Residue(Fp);
+
+impl<'py> FromPyObject<'py> for Residue {
+    fn extract_bound(value: &Bound<'py, PyAny>) -> PyResult<Self> {
+        match value.extract::<u32>() {
+            Ok(v) => fp(v).map(Residue),
+            Err(e) if e.is_instance_of::<PyOverflowError>(value.py()) => Err(non_canonical(value)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn fp2((c0, c1): (Residue, Residue)) -> Fp2 {
+    Fp2 { c0: c0.0, c1: c1.0 }
+}
+
+fn pair(value: Fp2) -> (u32, u32) {
+    (value.c0.into(), value.c1.into())
+}
+
+/// Vector of fp20 elements with element-wise arithmetic
+#[pyclass(module = "ark_experimentation", eq)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fp20Vector(Vec<Fp>);
+
+impl Fp20Vector {
+    fn zip_with(&self, other: &Self, op: impl Fn(Fp, Fp) -> Fp) -> PyResult<Self> {
+        if self.0.len() != other.0.len() {
+            return Err(PyValueError::new_err(format!(
+                "length mismatch: {} != {}",
+                self.0.len(),
+                other.0.len()
+            )));
+        }
+        Ok(Self(
+            self.0
+                .iter()
+                .zip(&other.0)
+                .map(|(&a, &b)| op(a, b))
+                .collect(),
+        ))
+    }
+}
+
+#[pymethods]
+impl Fp20Vector {
+    #[new]
+    fn new(values: Vec<Residue>) -> Self {
+        Self(values.into_iter().map(|v| v.0).collect())
+    }
+
+    fn to_list(&self) -> Vec<u32> {
+        self.0.iter().map(|&v| v.into()).collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Fp20Vector({:?})", self.to_list())
+    }
+
+    fn __add__(&self, other: &Self) -> PyResult<Self> {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    fn __sub__(&self, other: &Self) -> PyResult<Self> {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    fn __mul__(&self, other: &Self) -> PyResult<Self> {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    fn __neg__(&self) -> Self {
+        Self(self.0.iter().map(|&v| -v).collect())
+    }
+}
+
+/// Validates the arguments of [`ntt`] and [`intt`] and builds the domain
+fn domain(len: usize, shift: Option<(Residue, Residue)>) -> PyResult<crate::domain::Domain<Fp2>> {
+    if !len.is_power_of_two() || len > extension::MAX_NTT_SIZE {
+        return Err(PyValueError::new_err(format!(
+            "length must be a power of two up to {}, got {len}",
+            extension::MAX_NTT_SIZE
+        )));
+    }
+    match shift.map(fp2) {
+        None => Ok(Fp2::domain(len)),
+        Some(shift) if shift == Fp2::from(0) => {
+            Err(PyValueError::new_err("coset shift must be non-zero"))
+        }
+        Some(shift) => Ok(Fp2::coset(len, shift)),
+    }
+}
+
+/// Evaluates the polynomial with coefficients `values` over the subgroup of
+/// order `len(values)`, or its coset by `shift`
+#[pyfunction]
+#[pyo3(signature = (values, shift = None))]
+fn ntt(
+    values: Vec<(Residue, Residue)>,
+    shift: Option<(Residue, Residue)>,
+) -> PyResult<Vec<(u32, u32)>> {
+    let domain = domain(values.len(), shift)?;
+    let mut values = values.into_iter().map(fp2).collect::<Vec<_>>();
+    extension::ntt(&mut values, &domain);
+    Ok(values.into_iter().map(pair).collect())
+}
+
+/// Inverse of [`ntt`] over the same domain
+#[pyfunction]
+#[pyo3(signature = (values, shift = None))]
+fn intt(
+    values: Vec<(Residue, Residue)>,
+    shift: Option<(Residue, Residue)>,
+) -> PyResult<Vec<(u32, u32)>> {
+    let domain = domain(values.len(), shift)?;
+    let mut values = values.into_iter().map(fp2).collect::<Vec<_>>();
+    extension::intt(&mut values, &domain);
+    Ok(values.into_iter().map(pair).collect())
+}
+
+#[pymodule]
+fn ark_experimentation(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("MODULUS", MODULUS)?;
+    m.add("MAX_NTT_SIZE", extension::MAX_NTT_SIZE)?;
+    m.add_class::<Fp20Vector>()?;
+    m.add_function(wrap_pyfunction!(ntt, m)?)?;
+    m.add_function(wrap_pyfunction!(intt, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::exceptions::PyTypeError;
+    use std::ffi::CString;
+
+    fn vector(values: &[u32]) -> Fp20Vector {
+        Fp20Vector::new(values.iter().map(|&v| Residue(Fp::new(v))).collect())
+    }
+
+    fn element((c0, c1): (u32, u32)) -> (Residue, Residue) {
+        (Residue(Fp::new(c0)), Residue(Fp::new(c1)))
+    }
+
+    fn elements(values: &[(u32, u32)]) -> Vec<(Residue, Residue)> {
+        values.iter().map(|&v| element(v)).collect()
+    }
+
+    #[test]
+    fn elementwise_ops() {
+        let a = vector(&[1, 2, MODULUS - 1]);
+        let b = vector(&[3, 4, 2]);
+        assert_eq!(vec![4, 6, 1], a.__add__(&b).unwrap().to_list());
+        assert_eq!(
+            vec![MODULUS - 2, MODULUS - 2, MODULUS - 3],
+            a.__sub__(&b).unwrap().to_list()
+        );
+        assert_eq!(vec![3, 8, MODULUS - 2], a.__mul__(&b).unwrap().to_list());
+        assert_eq!(vec![MODULUS - 1, MODULUS - 2, 1], a.__neg__().to_list());
+
+        assert!(a.__add__(&vector(&[1])).is_err());
+    }
+
+    #[test]
+    fn ntt_round_trip() {
+        let coeffs = (0..8).map(|i| (i, 2 * i)).collect::<Vec<_>>();
+        for shift in [None, Some((3, 1))] {
+            let evals = ntt(elements(&coeffs), shift.map(element)).unwrap();
+            let domain = domain(8, shift.map(element)).unwrap();
+            for (x, &eval) in domain.elements().zip(&evals) {
+                let expected = coeffs
+                    .iter()
+                    .rev()
+                    .fold(Fp2::from(0), |acc, &c| acc * x + fp2(element(c)));
+                assert_eq!(pair(expected), eval);
+            }
+            assert_eq!(coeffs, intt(elements(&evals), shift.map(element)).unwrap());
+        }
+
+        assert!(ntt(elements(&[(0, 0); 3]), None).is_err());
+        assert!(ntt(elements(&[(0, 0); 32]), None).is_err());
+        assert!(ntt(elements(&[(0, 0); 2]), Some(element((0, 0)))).is_err());
+    }
+
+    #[test]
+    fn residue_extraction() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let extract = |code: &str| {
+                let code = CString::new(code).unwrap();
+                py.eval(&code, None, None).unwrap().extract::<Residue>()
+            };
+            assert_eq!(
+                Fp::new(MODULUS - 1),
+                extract(&(MODULUS - 1).to_string()).unwrap().0
+            );
+            for code in [
+                MODULUS.to_string(),
+                "-1".into(),
+                "2 ** 32".into(),
+                "2 ** 100".into(),
+            ] {
+                let err = extract(&code).err().unwrap();
+                assert!(err.is_instance_of::<PyValueError>(py), "{code}: {err}");
+            }
+            assert!(extract("'1'")
+                .err()
+                .unwrap()
+                .is_instance_of::<PyTypeError>(py));
+        });
+    }
+}
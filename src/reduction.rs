@@ -0,0 +1,237 @@
+//! Reduction strategies for the float fields' multiplication.
+//!
+//! A [`Reducer`] takes the exact product `h + l` (`h = a * b` rounded and
+//! `l = fma(a, b, -h)` its rounding error) to a remainder in `(-p, 2p)`. The
+//! field applies the final correction and records [`crate::stats`] so every
+//! strategy shares them. Fields expose `mul_reduced::<S>` to pick a strategy
+//! per call, so new ideas can be tested and benched without touching the
+//! operator impls.
+use crate::rounding::Floor;
+use crate::rounding::Nearest;
+use crate::rounding::Round;
+use crate::rounding::Trunc;
+use std::marker::PhantomData;
+
+pub trait Reducer<T> {
+    /// Returns `r ≡ h + l mod p` with `r ∈ (-p, 2p)`
+    fn reduce(h: T, l: T, modulus: T, modulus_inv: T) -> T;
+}
+
+/// Quotient `c = round(h * p^-1)` rounded by `R` and remainder `h - c * p`
+/// computed with an FMA. This is what the fields use by default.
+#[derive(Clone, Copy, Debug)]
+pub struct Fma<R>(PhantomData<R>);
+
+pub type TruncFma = Fma<Trunc>;
+pub type FloorFma = Fma<Floor>;
+pub type NearestFma = Fma<Nearest>;
+
+impl<R: Round> Reducer<f32> for Fma<R> {
+    #[inline]
+    fn reduce(h: f32, l: f32, modulus: f32, modulus_inv: f32) -> f32 {
+        let c = R::round_f32(h * modulus_inv);
+        let d = (-c).mul_add(modulus, h);
+        d + l
+    }
+}
+
+impl<R: Round> Reducer<f64> for Fma<R> {
+    #[inline]
+    fn reduce(h: f64, l: f64, modulus: f64, modulus_inv: f64) -> f64 {
+        let c = R::round_f64(h * modulus_inv);
+        let d = (-c).mul_add(modulus, h);
+        d + l
+    }
+}
+
+/// Barrett reduction without a fused remainder: the floored quotient's
+/// product `c * p` is recovered exactly with Dekker's two-product, for
+/// targets where an FMA is unavailable or slow
+#[derive(Clone, Copy, Debug)]
+pub struct Barrett;
+
+/// Dekker's splitting constant `2^ceil(MANTISSA_DIGITS / 2) + 1`
+trait Split: Sized {
+    const SPLITTER: Self;
+}
+
+impl Split for f32 {
+    const SPLITTER: f32 = 4097.0;
+}
+
+impl Split for f64 {
+    const SPLITTER: f64 = 134217729.0;
+}
+
+macro_rules! impl_barrett {
+    ($t:ty) => {
+        impl Reducer<$t> for Barrett {
+            #[inline]
+            fn reduce(h: $t, l: $t, modulus: $t, modulus_inv: $t) -> $t {
+                let split = |v: $t| {
+                    let t = v * <$t>::SPLITTER;
+                    let hi = t - (t - v);
+                    (hi, v - hi)
+                };
+                let c = (h * modulus_inv).floor();
+                // c * p = p_hi + p_lo exactly
+                let p_hi = c * modulus;
+                let ((c_hi, c_lo), (m_hi, m_lo)) = (split(c), split(modulus));
+                let p_lo = ((c_hi * m_hi - p_hi) + c_hi * m_lo + c_lo * m_hi) + c_lo * m_lo;
+                // `h - p_hi` is exact (Sterbenz) and every term is an integer
+                // below the mantissa width
+                (h - p_hi) - p_lo + l
+            }
+        }
+    };
+}
+
+impl_barrett!(f32);
+impl_barrett!(f64);
+
+/// Shift-and-add folding for pseudo-Mersenne moduli `p = 2^K - C`:
+/// `hi * 2^K + lo ≡ hi * C + lo`. Each fold is only exact while `hi * C + lo`
+/// fits the mantissa so this is limited to f64 and exact products (`l = 0`).
+#[derive(Clone, Copy, Debug)]
+pub struct Shift<const K: u32, const C: u32>;
+
+impl<const K: u32, const C: u32> Reducer<f64> for Shift<K, C> {
+    #[inline]
+    fn reduce(h: f64, l: f64, modulus: f64, _modulus_inv: f64) -> f64 {
+        let radix = (1u64 << K) as f64;
+        debug_assert_eq!(radix - C as f64, modulus);
+        debug_assert_eq!(0.0, l, "the product must be exact");
+        let mut r = h;
+        while r >= radix {
+            let hi = (r * (1.0 / radix)).floor();
+            r = hi.mul_add(C as f64, (-hi).mul_add(radix, r));
+        }
+        r
+    }
+}
+
+/// Fields whose multiplication takes any [`Reducer`] over their float type
+pub trait Reducible: Copy {
+    type Float: Backend;
+
+    fn mul_reduced<S: Reducer<Self::Float>>(self, rhs: Self) -> Self;
+
+    /// Visits every reducer valid for this field. Defaults to the reducers of
+    /// the float backend; fields add the ones specific to their modulus.
+    fn for_each_reducer(visitor: &mut impl ReducerVisitor<Self::Float>) {
+        Self::Float::for_each_reducer(visitor);
+    }
+}
+
+/// Callback for [`Backend::for_each_reducer`] and
+/// [`Reducible::for_each_reducer`]
+pub trait ReducerVisitor<T> {
+    fn visit<S: Reducer<T>>(&mut self, name: &'static str);
+}
+
+/// Float types and the reducers that are exact for any modulus over them.
+/// Anything iterating reducers (e.g. the bench matrix) picks up new ones from
+/// here.
+pub trait Backend: Sized {
+    fn for_each_reducer(visitor: &mut impl ReducerVisitor<Self>);
+}
+
+impl Backend for f32 {
+    fn for_each_reducer(visitor: &mut impl ReducerVisitor<Self>) {
+        visitor.visit::<TruncFma>("trunc_fma");
+        visitor.visit::<FloorFma>("floor_fma");
+        visitor.visit::<NearestFma>("nearest_fma");
+        visitor.visit::<Barrett>("barrett");
+    }
+}
+
+impl Backend for f64 {
+    fn for_each_reducer(visitor: &mut impl ReducerVisitor<Self>) {
+        visitor.visit::<TruncFma>("trunc_fma");
+        visitor.visit::<FloorFma>("floor_fma");
+        visitor.visit::<NearestFma>("nearest_fma");
+        visitor.visit::<Barrett>("barrett");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp20;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+
+    const P: u64 = 2097143;
+
+    fn reduces_f32<S: Reducer<f32>>() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let (m, m_inv) = (P as f32, 1.0 / P as f32);
+        for _ in 0..1 << 16 {
+            let (a, b) = (rng.gen_range(0..P), rng.gen_range(0..P));
+            let (x, y) = (a as f32, b as f32);
+            let h = x * y;
+            let r = S::reduce(h, x.mul_add(y, -h), m, m_inv);
+            assert!(-m < r && r < 2.0 * m, "out of range: `{a} * {b}` -> {r}");
+            assert_eq!(0.0, r.fract(), "inexact: `{a} * {b}` -> {r}");
+            assert_eq!(a * b % P, (r as i64).rem_euclid(P as i64) as u64);
+        }
+    }
+
+    fn reduces_f64<S: Reducer<f64>>() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let (m, m_inv) = (P as f64, 1.0 / P as f64);
+        for _ in 0..1 << 16 {
+            let (a, b) = (rng.gen_range(0..P), rng.gen_range(0..P));
+            let r = S::reduce(a as f64 * b as f64, 0.0, m, m_inv);
+            assert!(-m < r && r < 2.0 * m, "out of range: `{a} * {b}` -> {r}");
+            assert_eq!(a * b % P, (r as i64).rem_euclid(P as i64) as u64);
+        }
+    }
+
+    #[test]
+    fn fma_reducers() {
+        reduces_f32::<TruncFma>();
+        reduces_f32::<FloorFma>();
+        reduces_f32::<NearestFma>();
+        reduces_f64::<TruncFma>();
+        reduces_f64::<FloorFma>();
+        reduces_f64::<NearestFma>();
+    }
+
+    #[test]
+    fn barrett() {
+        reduces_f32::<Barrett>();
+        reduces_f64::<Barrett>();
+    }
+
+    /// Checks every visited reducer
+    struct Exact;
+
+    impl ReducerVisitor<f32> for Exact {
+        fn visit<S: Reducer<f32>>(&mut self, _name: &'static str) {
+            reduces_f32::<S>();
+        }
+    }
+
+    impl ReducerVisitor<f64> for Exact {
+        fn visit<S: Reducer<f64>>(&mut self, _name: &'static str) {
+            reduces_f64::<S>();
+        }
+    }
+
+    #[test]
+    fn listed_reducers_are_exact() {
+        fp20::single_precision::Fp::for_each_reducer(&mut Exact);
+        fp20::double_precision::Fp::for_each_reducer(&mut Exact);
+    }
+
+    #[test]
+    fn shift() {
+        reduces_f64::<Shift<21, 9>>();
+        assert_eq!(
+            81.0,
+            Shift::<21, 9>::reduce((1u64 << 42) as f64, 0.0, P as f64, 0.0)
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Rounding primitives used to compute the quotient `c = round(h * p^-1)` in
+//! the float reductions.
+//!
+//! The quotient is estimated from the rounded high part `h` of the product
+//! and an approximate `p^-1`, so every primitive can be off by one in either
+//! direction and the reductions correct the remainder by `p` both ways:
+//!
+//! - [`Trunc`] and [`Floor`] agree on the non-negative products the reductions
+//!   see. They undershoot by one when `h * p^-1` falls just below an integer
+//!   (remainder in `[p, 2p)`), and overshoot by one when `h` is above the exact
+//!   product, i.e. the FMA low part `l` is negative, and a multiple of `p` lies
+//!   between them (remainder in `[-p, 0)`).
+//! - [`Nearest`] lands within one of the quotient the same way (remainder in
+//!   `(-p, 2p)`).
+
+pub trait Round {
+    fn round_f32(v: f32) -> f32;
+
+    fn round_f64(v: f64) -> f64;
+}
+
+/// Round towards zero. The quotient can be one too small or, with a negative
+/// FMA low part, one too large.
+#[derive(Clone, Copy, Debug)]
+pub struct Trunc;
+
+impl Round for Trunc {
+    #[inline]
+    fn round_f32(v: f32) -> f32 {
+        v.trunc()
+    }
+
+    #[inline]
+    fn round_f64(v: f64) -> f64 {
+        v.trunc()
+    }
+}
+
+/// Round towards negative infinity. Same as [`Trunc`] on non-negative
+/// values, including the ±1 error of the quotient.
+#[derive(Clone, Copy, Debug)]
+pub struct Floor;
+
+impl Round for Floor {
+    #[inline]
+    fn round_f32(v: f32) -> f32 {
+        v.floor()
+    }
+
+    #[inline]
+    fn round_f64(v: f64) -> f64 {
+        v.floor()
+    }
+}
+
+/// Round to nearest, ties away from zero
+#[derive(Clone, Copy, Debug)]
+pub struct Nearest;
+
+impl Round for Nearest {
+    #[inline]
+    fn round_f32(v: f32) -> f32 {
+        v.round()
+    }
+
+    #[inline]
+    fn round_f64(v: f64) -> f64 {
+        v.round()
+    }
+}
@@ -0,0 +1,611 @@
+//! Element-wise kernels over slices of field elements (scalar reference
+//! implementations)
+
+use half::f16;
+use num_traits::MulAdd;
+use num_traits::WrappingNeg;
+use std::ops::Add;
+use std::ops::BitAnd;
+use std::ops::BitOr;
+use std::ops::Mul;
+use std::ops::Not;
+
+/// Computes the sum in place: `dst[i] = dst[i] + src[i]`
+pub fn add<F: Copy + Add<Output = F>>(dst: &mut [F], src: &[F]) {
+    assert_eq!(dst.len(), src.len());
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = *d + s;
+    }
+}
+
+/// Computes the Hadamard product in place: `dst[i] = dst[i] * src[i]`
+pub fn hadamard<F: Copy + Mul<Output = F>>(dst: &mut [F], src: &[F]) {
+    assert_eq!(dst.len(), src.len());
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = *d * s;
+    }
+}
+
+/// Accumulates the Hadamard product: `dst[i] = dst[i] + a[i] * b[i]`
+pub fn hadamard_acc<F: Copy + Add<Output = F> + Mul<Output = F>>(dst: &mut [F], a: &[F], b: &[F]) {
+    assert_eq!(dst.len(), a.len());
+    assert_eq!(dst.len(), b.len());
+    for ((d, &a), &b) in dst.iter_mut().zip(a).zip(b) {
+        *d = *d + a * b;
+    }
+}
+
+/// Accumulates a scaled column: `dst[i] = dst[i] + k * src[i]`. This is the
+/// inner step of random linear combinations, FRI folds and Gaussian
+/// elimination.
+pub fn acc_scaled<F: Copy + Add<Output = F> + Mul<Output = F>>(dst: &mut [F], src: &[F], k: F) {
+    assert_eq!(dst.len(), src.len());
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = *d + k * s;
+    }
+}
+
+/// [`acc_scaled`] with one reduction per element: `dst[i] = k.mul_add(src[i],
+/// dst[i])`. The float fields fold `dst[i]` into the low part of the product
+/// before reducing, so the sum never gets its own correction, and the
+/// integer fp20 field reduces `k * src[i] + dst[i]` once in `u64`.
+pub fn acc_scaled_fused<F: Copy + MulAdd<Output = F>>(dst: &mut [F], src: &[F], k: F) {
+    assert_eq!(dst.len(), src.len());
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = k.mul_add(s, *d);
+    }
+}
+
+/// Rows per block in [`acc_linear_combination`]. The block of `dst` stays in
+/// cache while every column is streamed over it.
+pub const LINEAR_COMBINATION_BLOCK: usize = 1024;
+
+/// Accumulates a linear combination of columns in one pass over `dst`:
+/// `dst[i] = dst[i] + sum_j k_j * col_j[i]`. Unlike repeated [`acc_scaled`]
+/// calls, each block of `dst` is loaded and stored once for all terms.
+pub fn acc_linear_combination<F: Copy + Add<Output = F> + Mul<Output = F>>(
+    dst: &mut [F],
+    terms: &[(F, &[F])],
+) {
+    for (_, column) in terms {
+        assert_eq!(dst.len(), column.len());
+    }
+    for (i, block) in dst.chunks_mut(LINEAR_COMBINATION_BLOCK).enumerate() {
+        let offset = i * LINEAR_COMBINATION_BLOCK;
+        for &(k, column) in terms {
+            let column = &column[offset..offset + block.len()];
+            for (d, &c) in block.iter_mut().zip(column) {
+                *d = *d + k * c;
+            }
+        }
+    }
+}
+
+/// Types stored as a fixed-width bit pattern, so [`select`] can blend two
+/// elements with a mask instead of branching. Field types implement it with
+/// `impl_word!` over the word they wrap.
+pub trait Word: Copy {
+    type Bits: Copy
+        + From<u8>
+        + WrappingNeg
+        + BitAnd<Output = Self::Bits>
+        + BitOr<Output = Self::Bits>
+        + Not<Output = Self::Bits>;
+
+    fn to_bits(self) -> Self::Bits;
+
+    fn from_bits(bits: Self::Bits) -> Self;
+}
+
+macro_rules! impl_word_for_float {
+    ($($float:ty => $bits:ty),*) => {
+        $(
+            impl Word for $float {
+                type Bits = $bits;
+
+                #[inline]
+                fn to_bits(self) -> $bits {
+                    <$float>::to_bits(self)
+                }
+
+                #[inline]
+                fn from_bits(bits: $bits) -> Self {
+                    <$float>::from_bits(bits)
+                }
+            }
+        )*
+    };
+}
+
+impl_word_for_float!(f16 => u16, f32 => u32, f64 => u64);
+
+impl Word for u32 {
+    type Bits = u32;
+
+    #[inline]
+    fn to_bits(self) -> u32 {
+        self
+    }
+
+    #[inline]
+    fn from_bits(bits: u32) -> Self {
+        bits
+    }
+}
+
+impl Word for [f32; 2] {
+    type Bits = u64;
+
+    #[inline]
+    fn to_bits(self) -> u64 {
+        u64::from(self[0].to_bits()) | u64::from(self[1].to_bits()) << 32
+    }
+
+    #[inline]
+    fn from_bits(bits: u64) -> Self {
+        [
+            f32::from_bits(bits as u32),
+            f32::from_bits((bits >> 32) as u32),
+        ]
+    }
+}
+
+/// Selects element-wise: `dst[i] = if mask[i] != 0 { a[i] } else { b[i] }`.
+/// The mask is widened to all ones or all zeros and blends the bit patterns,
+/// so the loop has no branches and vectorizes.
+pub fn select<F: Word>(dst: &mut [F], mask: &[u8], a: &[F], b: &[F]) {
+    assert_eq!(dst.len(), mask.len());
+    assert_eq!(dst.len(), a.len());
+    assert_eq!(dst.len(), b.len());
+    for (((d, &m), &a), &b) in dst.iter_mut().zip(mask).zip(a).zip(b) {
+        let m = F::Bits::from(u8::from(m != 0)).wrapping_neg();
+        *d = F::from_bits(a.to_bits() & m | b.to_bits() & !m);
+    }
+}
+
+/// Computes `dst[i] = (a[i] == b[i]) as u8`. Elements must be canonical,
+/// which all of the crate's field types guarantee after every operation.
+pub fn eq_mask<F: Copy + PartialEq>(dst: &mut [u8], a: &[F], b: &[F]) {
+    assert_eq!(dst.len(), a.len());
+    assert_eq!(dst.len(), b.len());
+    for ((d, a), b) in dst.iter_mut().zip(a).zip(b) {
+        *d = u8::from(a == b);
+    }
+}
+
+/// Computes `dst[i] = (values[i] == 0) as u8`
+pub fn is_zero_mask<F: Copy + PartialEq + From<u32>>(dst: &mut [u8], values: &[F]) {
+    assert_eq!(dst.len(), values.len());
+    let zero = F::from(0);
+    for (d, v) in dst.iter_mut().zip(values) {
+        *d = u8::from(*v == zero);
+    }
+}
+
+/// Evaluates `values` as a polynomial at `challenge` with Horner's rule:
+/// `v[0] * c^(n-1) + v[1] * c^(n-2) + ... + v[n-1]`. Two different columns
+/// collide with probability at most `(n - 1) / p` over a random challenge.
+pub fn fingerprint<F: Copy + Add<Output = F> + Mul<Output = F> + From<u32>>(
+    values: &[F],
+    challenge: F,
+) -> F {
+    let mut fingerprint = Fingerprint::new(challenge);
+    fingerprint.update(values);
+    fingerprint.finish()
+}
+
+/// Streaming version of [`fingerprint`] for columns that arrive in chunks
+#[derive(Clone, Copy, Debug)]
+pub struct Fingerprint<F> {
+    challenge: F,
+    acc: F,
+}
+
+impl<F: Copy + Add<Output = F> + Mul<Output = F> + From<u32>> Fingerprint<F> {
+    pub fn new(challenge: F) -> Self {
+        Self {
+            challenge,
+            acc: F::from(0),
+        }
+    }
+
+    pub fn update(&mut self, values: &[F]) {
+        for &v in values {
+            self.acc = self.acc * self.challenge + v;
+        }
+    }
+
+    pub fn finish(self) -> F {
+        self.acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::Field;
+    use crate::fp20;
+    use rand::distributions::Standard;
+    use rand::prelude::Distribution;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+    use rand::SeedableRng;
+    use sha2::Digest;
+    use sha2::Sha256;
+    use std::fmt::Debug;
+
+    fn random_vec(rng: &mut StdRng, n: usize) -> Vec<fp20::integer::Fp> {
+        (0..n).map(|_| rng.gen()).collect()
+    }
+
+    #[test]
+    fn hadamard_matches_integer_reference() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let a = random_vec(&mut rng, 1024);
+        let b = random_vec(&mut rng, 1024);
+        let expected = a.iter().zip(&b).map(|(&a, &b)| a * b).collect::<Vec<_>>();
+
+        let mut actual = a.clone();
+        hadamard(&mut actual, &b);
+        assert_eq!(expected, actual);
+
+        let mut actual = a
+            .iter()
+            .map(|&v| fp20::single_precision::Fp::from(v))
+            .collect::<Vec<_>>();
+        let b_single = b
+            .iter()
+            .map(|&v| fp20::single_precision::Fp::from(v))
+            .collect::<Vec<_>>();
+        hadamard(&mut actual, &b_single);
+        let actual = actual
+            .into_iter()
+            .map(fp20::integer::Fp::from)
+            .collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn hadamard_acc_matches_integer_reference() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let acc = random_vec(&mut rng, 1024);
+        let a = random_vec(&mut rng, 1024);
+        let b = random_vec(&mut rng, 1024);
+        let expected = acc
+            .iter()
+            .zip(&a)
+            .zip(&b)
+            .map(|((&acc, &a), &b)| acc + a * b)
+            .collect::<Vec<_>>();
+
+        let mut actual = acc
+            .iter()
+            .map(|&v| fp20::double_precision::Fp::from(v))
+            .collect::<Vec<_>>();
+        let a = a
+            .iter()
+            .map(|&v| fp20::double_precision::Fp::from(v))
+            .collect::<Vec<_>>();
+        let b = b
+            .iter()
+            .map(|&v| fp20::double_precision::Fp::from(v))
+            .collect::<Vec<_>>();
+        hadamard_acc(&mut actual, &a, &b);
+        let actual = actual
+            .into_iter()
+            .map(fp20::integer::Fp::from)
+            .collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn acc_scaled_matches_integer_reference() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let acc = random_vec(&mut rng, 1024);
+        let src = random_vec(&mut rng, 1024);
+        let k: fp20::integer::Fp = rng.gen();
+        let expected = acc
+            .iter()
+            .zip(&src)
+            .map(|(&acc, &s)| acc + k * s)
+            .collect::<Vec<_>>();
+
+        let mut actual = acc.clone();
+        acc_scaled(&mut actual, &src, k);
+        assert_eq!(expected, actual);
+
+        let mut actual = acc
+            .iter()
+            .map(|&v| fp20::single_precision::Fp::from(v))
+            .collect::<Vec<_>>();
+        let src = src
+            .iter()
+            .map(|&v| fp20::single_precision::Fp::from(v))
+            .collect::<Vec<_>>();
+        acc_scaled(&mut actual, &src, k.into());
+        let actual = actual
+            .into_iter()
+            .map(fp20::integer::Fp::from)
+            .collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn acc_scaled_fused_matches_acc_scaled() {
+        fn check<F>(from: fn(fp20::integer::Fp) -> F)
+        where
+            F: Copy + Add<Output = F> + Mul<Output = F> + MulAdd<Output = F> + PartialEq + Debug,
+        {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let convert = |values: Vec<fp20::integer::Fp>| values.into_iter().map(from).collect();
+            let acc: Vec<F> = convert(random_vec(&mut rng, 1024));
+            let src: Vec<F> = convert(random_vec(&mut rng, 1024));
+            // `p - 1` maximizes both the product and the folded accumulator
+            let max = from(fp20::integer::Fp::new(fp20::integer::MODULUS - 1));
+            for k in [from(rng.gen()), max] {
+                let mut expected = acc.clone();
+                acc_scaled(&mut expected, &src, k);
+                let mut actual = acc.clone();
+                acc_scaled_fused(&mut actual, &src, k);
+                assert_eq!(expected, actual);
+
+                let mut actual = vec![max; src.len()];
+                acc_scaled_fused(&mut actual, &vec![max; src.len()], k);
+                assert_eq!(vec![max + k * max; src.len()], actual);
+            }
+        }
+
+        check::<fp20::integer::Fp>(|v| v);
+        check::<fp20::single_precision::Fp>(From::from);
+        check::<fp20::double_precision::Fp>(From::from);
+        check::<crate::fp_f32::FpF32<2097143>>(|v| u32::from(v).into());
+        check::<crate::fp_f64::FpF64<2097143>>(|v| u32::from(v).into());
+    }
+
+    #[test]
+    fn acc_scaled_fused_65537() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let acc = (0..1024)
+            .map(|_| rng.gen())
+            .collect::<Vec<crate::f65537::Fp>>();
+        let src = (0..1024)
+            .map(|_| rng.gen())
+            .collect::<Vec<crate::f65537::Fp>>();
+        let max = crate::f65537::Fp::new(crate::f65537::MODULUS - 1);
+        for k in [rng.gen(), max] {
+            let mut expected = acc.clone();
+            acc_scaled(&mut expected, &src, k);
+            let mut actual = acc.clone();
+            acc_scaled_fused(&mut actual, &src, k);
+            assert_eq!(expected, actual);
+        }
+        let mut actual = vec![max; 4];
+        acc_scaled_fused(&mut actual, &[max; 4], max);
+        assert_eq!(vec![max + max * max; 4], actual);
+    }
+
+    #[test]
+    fn acc_linear_combination_matches_acc_scaled() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        // not a multiple of the block size
+        let n = 2 * LINEAR_COMBINATION_BLOCK + 123;
+        let acc = random_vec(&mut rng, n);
+        let columns = (0..40).map(|_| random_vec(&mut rng, n)).collect::<Vec<_>>();
+        let scalars = random_vec(&mut rng, columns.len());
+        let mut expected = acc.clone();
+        for (&k, column) in scalars.iter().zip(&columns) {
+            acc_scaled(&mut expected, column, k);
+        }
+
+        let terms = scalars
+            .iter()
+            .zip(&columns)
+            .map(|(&k, column)| (k, column.as_slice()))
+            .collect::<Vec<_>>();
+        let mut actual = acc.clone();
+        acc_linear_combination(&mut actual, &terms);
+        assert_eq!(expected, actual);
+
+        let mut actual = acc.clone();
+        fp20::integer::acc_linear_combination(&mut actual, &terms);
+        assert_eq!(expected, actual);
+
+        let single = |values: &[fp20::integer::Fp]| {
+            values
+                .iter()
+                .map(|&v| fp20::single_precision::Fp::from(v))
+                .collect::<Vec<_>>()
+        };
+        let columns = columns.iter().map(|c| single(c)).collect::<Vec<_>>();
+        let terms = scalars
+            .iter()
+            .zip(&columns)
+            .map(|(&k, column)| (k.into(), column.as_slice()))
+            .collect::<Vec<_>>();
+        let mut actual = single(&acc);
+        acc_linear_combination(&mut actual, &terms);
+        assert_eq!(single(&expected), actual);
+
+        let mut actual = acc.clone();
+        acc_linear_combination(&mut actual, &[]);
+        fp20::integer::acc_linear_combination(&mut actual, &[]);
+        assert_eq!(acc, actual);
+    }
+
+    #[test]
+    fn fingerprint_matches_across_representations() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let values = random_vec(&mut rng, 1 << 16);
+        let challenge: fp20::integer::Fp = rng.gen();
+        let expected = fingerprint(&values, challenge);
+
+        let mut streaming = Fingerprint::new(challenge);
+        for chunk in values.chunks(1000) {
+            streaming.update(chunk);
+        }
+        assert_eq!(expected, streaming.finish());
+
+        let single = values
+            .iter()
+            .map(|&v| fp20::single_precision::Fp::from(v))
+            .collect::<Vec<_>>();
+        let actual = fingerprint(&single, challenge.into());
+        assert_eq!(expected, actual.into());
+
+        let double = values
+            .iter()
+            .map(|&v| fp20::double_precision::Fp::from(v))
+            .collect::<Vec<_>>();
+        let actual = fingerprint(&double, challenge.into());
+        assert_eq!(expected, actual.into());
+    }
+
+    #[test]
+    fn fingerprint_detects_single_change() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let mut values = random_vec(&mut rng, 1024);
+        let challenge: fp20::integer::Fp = rng.gen();
+        let expected = fingerprint(&values, challenge);
+        values[517] += fp20::integer::Fp::new(1);
+        assert_ne!(expected, fingerprint(&values, challenge));
+        assert_eq!(fp20::integer::Fp::new(0), fingerprint(&[], challenge));
+    }
+
+    #[test]
+    fn masks_and_select() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let a = random_vec(&mut rng, 1024);
+        let mut b = random_vec(&mut rng, 1024);
+        for i in (0..1024).step_by(3) {
+            b[i] = a[i];
+        }
+        b[1] = fp20::integer::Fp::new(0);
+
+        let single = |values: &[fp20::integer::Fp]| {
+            values
+                .iter()
+                .map(|&v| fp20::single_precision::Fp::from(v))
+                .collect::<Vec<_>>()
+        };
+        let (a_single, b_single) = (single(&a), single(&b));
+
+        let mut mask = vec![0; 1024];
+        eq_mask(&mut mask, &a_single, &b_single);
+        for (i, &m) in mask.iter().enumerate() {
+            assert_eq!(u8::from(a[i] == b[i]), m, "mismatch at {i}");
+        }
+
+        let mut dst = vec![fp20::single_precision::Fp::new(0); 1024];
+        select(&mut dst, &mask, &a_single, &b_single);
+        assert_eq!(b_single, dst);
+
+        // any non-zero byte selects `a`
+        let mask = (0..1024)
+            .map(|i| (i % 2 == 0) as u8 * 0x80)
+            .collect::<Vec<u8>>();
+        select(&mut dst, &mask, &a_single, &b_single);
+        for (i, &v) in dst.iter().enumerate() {
+            assert_eq!(
+                if i % 2 == 0 { a[i] } else { b[i] },
+                v.into(),
+                "mismatch at {i}"
+            );
+        }
+        let mut dst = vec![fp20::integer::Fp::new(0); 1024];
+        select(&mut dst, &mask, &a, &b);
+        for (i, &v) in dst.iter().enumerate() {
+            assert_eq!(if i % 2 == 0 { a[i] } else { b[i] }, v, "mismatch at {i}");
+        }
+
+        let mut mask = vec![1; 1024];
+        is_zero_mask(&mut mask, &b_single);
+        assert_eq!(
+            b.iter()
+                .map(|&v| u8::from(u32::from(v) == 0))
+                .collect::<Vec<u8>>(),
+            mask
+        );
+        assert_eq!(1, mask[1]);
+    }
+
+    #[test]
+    fn select_over_every_word() {
+        fn check<F: Field + Word>()
+        where
+            Standard: Distribution<F>,
+        {
+            let mut rng = StdRng::from_seed([1; 32]);
+            let a = (0..256).map(|_| rng.gen()).collect::<Vec<F>>();
+            let b = (0..256).map(|_| rng.gen()).collect::<Vec<F>>();
+            let mask = (0..256).map(|_| rng.gen_range(0..2)).collect::<Vec<u8>>();
+            let mut dst = vec![F::ZERO; 256];
+            select(&mut dst, &mask, &a, &b);
+            for i in 0..256 {
+                assert_eq!(if mask[i] == 1 { a[i] } else { b[i] }, dst[i]);
+            }
+        }
+
+        check::<fp20::integer::Fp>();
+        check::<fp20::single_precision::Fp>();
+        check::<fp20::double_precision::Fp>();
+        check::<crate::f65537::Fp>();
+        check::<crate::fp_f16::FpF16<251>>();
+        check::<crate::fp_f32::FpF32<1048573>>();
+        check::<crate::m31::Fp>();
+        check::<crate::goldilocks::Fp>();
+        check::<crate::field::numeric::single_precision::Fp>();
+        check::<crate::field::numeric::double_precision::Fp>();
+    }
+
+    /// Pinned SHA-256 digest of `hadamard_acc` over seeded fp20 inputs. Every
+    /// backend must reproduce it.
+    const HADAMARD_ACC_GOLDEN: &str =
+        "fa76a02a4552d4068fd69fc31851bdac6479d76bc2ca11738d3c5c48e67a8fb0";
+
+    fn digest(values: impl IntoIterator<Item = u32>) -> String {
+        let mut hasher = Sha256::new();
+        for v in values {
+            hasher.update(v.to_le_bytes());
+        }
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    fn hadamard_acc_digest<F>(
+        acc: &[fp20::integer::Fp],
+        a: &[fp20::integer::Fp],
+        b: &[fp20::integer::Fp],
+    ) -> String
+    where
+        F: Copy + Add<Output = F> + Mul<Output = F> + From<fp20::integer::Fp> + Into<u32>,
+    {
+        let convert =
+            |values: &[fp20::integer::Fp]| values.iter().map(|&v| F::from(v)).collect::<Vec<F>>();
+        let mut dst = convert(acc);
+        hadamard_acc(&mut dst, &convert(a), &convert(b));
+        digest(dst.into_iter().map(Into::into))
+    }
+
+    #[test]
+    fn hadamard_acc_golden() {
+        let mut rng = StdRng::from_seed([1; 32]);
+        let acc = random_vec(&mut rng, 1 << 12);
+        let a = random_vec(&mut rng, 1 << 12);
+        let b = random_vec(&mut rng, 1 << 12);
+        assert_eq!(
+            HADAMARD_ACC_GOLDEN,
+            hadamard_acc_digest::<fp20::integer::Fp>(&acc, &a, &b)
+        );
+        assert_eq!(
+            HADAMARD_ACC_GOLDEN,
+            hadamard_acc_digest::<fp20::single_precision::Fp>(&acc, &a, &b)
+        );
+        assert_eq!(
+            HADAMARD_ACC_GOLDEN,
+            hadamard_acc_digest::<fp20::double_precision::Fp>(&acc, &a, &b)
+        );
+    }
+}
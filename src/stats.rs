@@ -0,0 +1,174 @@
+//! Counts how often each correction branch of the reductions fires.
+//!
+//! Recording is compiled out unless the `stats` feature is enabled, in which
+//! case every instrumented reduction bumps a thread local counter. Use
+//! [`reset`] before a workload and [`snapshot`] after it on the same thread:
+//!
+//! ```ignore
+//! stats::reset();
+//! run_workload();
+//! println!("{}", stats::snapshot());
+//! ```
+use std::fmt::Display;
+
+/// Reduction instrumented with [`record`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Site {
+    /// `f65537::mul_mod_fma`
+    Mul65537,
+    /// `f65537::reduce_numeric_half`
+    Reduce65537,
+    Fp20SingleMul,
+    Fp20SingleAdd,
+    Fp20DoubleMul,
+    Fp20DoubleAdd,
+}
+
+impl Site {
+    pub const ALL: [Self; 6] = [
+        Self::Mul65537,
+        Self::Reduce65537,
+        Self::Fp20SingleMul,
+        Self::Fp20SingleAdd,
+        Self::Fp20DoubleMul,
+        Self::Fp20DoubleAdd,
+    ];
+}
+
+/// Correction applied to a value before it was returned
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Branch {
+    /// Already canonical
+    None,
+    /// Modulus subtracted
+    Subtract,
+    /// Modulus added
+    Add,
+}
+
+impl Branch {
+    pub const ALL: [Self; 3] = [Self::None, Self::Subtract, Self::Add];
+
+    /// Classifies the correction needed to bring `v` into `[0, modulus)`
+    #[inline]
+    pub fn of(v: f64, modulus: f64) -> Self {
+        if v >= modulus {
+            Self::Subtract
+        } else if v < 0.0 {
+            Self::Add
+        } else {
+            Self::None
+        }
+    }
+}
+
+/// Per site and branch counts
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Histogram([[u64; Branch::ALL.len()]; Site::ALL.len()]);
+
+impl Histogram {
+    pub fn get(&self, site: Site, branch: Branch) -> u64 {
+        self.0[site as usize][branch as usize]
+    }
+
+    pub fn total(&self, site: Site) -> u64 {
+        self.0[site as usize].iter().sum()
+    }
+}
+
+impl Display for Histogram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for site in Site::ALL {
+            let total = self.total(site);
+            if total == 0 {
+                continue;
+            }
+            write!(f, "{site:?}:")?;
+            for branch in Branch::ALL {
+                let count = self.get(site, branch);
+                let percent = 100.0 * count as f64 / total as f64;
+                write!(f, " {branch:?}={count} ({percent:.2}%)")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "stats")]
+mod counters {
+    use super::Branch;
+    use super::Histogram;
+    use super::Site;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static COUNTS: RefCell<Histogram> = RefCell::default();
+    }
+
+    #[inline]
+    pub fn record(site: Site, branch: Branch) {
+        COUNTS.with_borrow_mut(|counts| counts.0[site as usize][branch as usize] += 1);
+    }
+
+    pub fn snapshot() -> Histogram {
+        COUNTS.with_borrow(Clone::clone)
+    }
+
+    pub fn reset() {
+        COUNTS.take();
+    }
+}
+
+#[cfg(not(feature = "stats"))]
+mod counters {
+    use super::Branch;
+    use super::Histogram;
+    use super::Site;
+
+    #[inline(always)]
+    pub fn record(_site: Site, _branch: Branch) {}
+
+    pub fn snapshot() -> Histogram {
+        Histogram::default()
+    }
+
+    pub fn reset() {}
+}
+
+/// Records that the reduction at `site` is about to correct `v` into
+/// `[0, modulus)`. A no-op without the `stats` feature.
+#[inline(always)]
+pub fn record(site: Site, v: f64, modulus: f64) {
+    counters::record(site, Branch::of(v, modulus));
+}
+
+/// Returns the counts recorded on this thread since the last [`reset`].
+/// Always empty without the `stats` feature.
+pub fn snapshot() -> Histogram {
+    counters::snapshot()
+}
+
+/// Clears the counts recorded on this thread, other threads keep theirs. A
+/// no-op without the `stats` feature.
+pub fn reset() {
+    counters::reset();
+}
+
+#[cfg(all(test, feature = "stats"))]
+mod tests {
+    use super::*;
+    use crate::fp20;
+
+    #[test]
+    fn counts_fp20_corrections() {
+        reset();
+        let a = fp20::double_precision::Fp::new(2097142);
+        let _ = a + a;
+        let _ = a + fp20::double_precision::Fp::new(0);
+        let histogram = snapshot();
+        assert_eq!(1, histogram.get(Site::Fp20DoubleAdd, Branch::Subtract));
+        assert_eq!(1, histogram.get(Site::Fp20DoubleAdd, Branch::None));
+        assert!(histogram.to_string().starts_with("Fp20DoubleAdd:"));
+    }
+}
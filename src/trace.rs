@@ -0,0 +1,50 @@
+//! Intermediates of the FMA based modular multiplication for debugging.
+//!
+//! The reduction computes `h = a * b`, `l = fma(a, b, -h)` (the rounding error
+//! of `h`), `b = h * p^-1`, `c = round(b)`, `d = fma(-c, p, h)` and
+//! `e = d + l` before the final correction into `[0, p)`. If any of `h`, `l`,
+//! `d` or `e` isn't an integer exactness was lost at that step.
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MulTrace<T> {
+    pub lhs: T,
+    pub rhs: T,
+    pub modulus: T,
+    /// Rounded product
+    pub h: T,
+    /// Rounding error of `h` (zero when the product is exact)
+    pub l: T,
+    /// Approximate quotient `h / p`
+    pub b: T,
+    /// Rounded quotient
+    pub c: T,
+    /// `h - c * p`
+    pub d: T,
+    /// `d + l` before the final correction
+    pub e: T,
+    pub result: T,
+}
+
+impl<T: Display + Copy + Into<f64>> Display for MulTrace<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} * {} mod {}", self.lhs, self.rhs, self.modulus)?;
+        for (name, v) in [
+            ("h", self.h),
+            ("l", self.l),
+            ("b", self.b),
+            ("c", self.c),
+            ("d", self.d),
+            ("e", self.e),
+            ("result", self.result),
+        ] {
+            let exact = if matches!(name, "b") || v.into().fract() == 0.0 {
+                ""
+            } else {
+                " (not an integer)"
+            };
+            writeln!(f, "  {name:>6} = {v}{exact}")?;
+        }
+        Ok(())
+    }
+}
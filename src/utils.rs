@@ -8,3 +8,51 @@ const fn xgcd(a: u64, b: u64) -> (u64, u64, u64) {
         (gcd, y - (b / a) * x, x)
     }
 }
+
+/// Returns the prime factorization of `n` as `(prime, exponent)` pairs
+/// using trial division
+pub(crate) fn factor(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    let mut d = 2;
+    // `d * d <= n` without overflowing for `n` near `u64::MAX`
+    while d <= n / d {
+        let mut exponent = 0;
+        while n.is_multiple_of(d) {
+            n /= d;
+            exponent += 1;
+        }
+        if exponent != 0 {
+            factors.push((d, exponent));
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factorization() {
+        assert_eq!(Vec::<(u64, u32)>::new(), factor(1));
+        assert_eq!(vec![(2, 16)], factor(65536));
+        assert_eq!(vec![(2, 1), (1048571, 1)], factor(2097142));
+        assert_eq!(vec![(2, 3), (3, 2), (5, 1)], factor(360));
+        assert_eq!(
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6700417, 1)
+            ],
+            factor(u64::MAX)
+        );
+    }
+}